@@ -34,7 +34,12 @@ describe('access configs', () => {
             ],
             permissions: [
                 ALLOW_WRITE_PERMISSION_ID
-            ]
+            ],
+            query_delegation_expiry: [],
+            cycles_limits: {max_per_call: [], max_per_program: []},
+            validity: {not_before: [], not_after: [], recurring_windows: []},
+            quota: {per_principal: [], max_total_executions: [], min_interval: []},
+            multisig: [],
         };
 
         await expectThrowsAsync(outsideUser.wallet.actor.create_access_config(createAccessConfigRequest), "Should throw for outside user");
@@ -55,7 +60,12 @@ describe('access configs', () => {
             ],
             permissions: [
                 ALLOW_WRITE_PERMISSION_ID
-            ]
+            ],
+            query_delegation_expiry: [],
+            cycles_limits: {max_per_call: [], max_per_program: []},
+            validity: {not_before: [], not_after: [], recurring_windows: []},
+            quota: {per_principal: [], max_total_executions: [], min_interval: []},
+            multisig: [],
         };
 
         // profile not exists
@@ -68,7 +78,12 @@ describe('access configs', () => {
             new_name: ["Updated name"],
             new_description: ["Updated description"],
             new_allowees: [],
-            new_permissions: []
+            new_permissions: [],
+            new_query_delegation_expiry: [],
+            new_cycles_limits: [],
+            new_validity: [],
+            new_quota: [],
+            new_multisig: [],
         });
 
         // creating 3 more access configs to check if pagination filters work
@@ -81,7 +96,12 @@ describe('access configs', () => {
             ],
             permissions: [
                 ALLOW_WRITE_PERMISSION_ID
-            ]
+            ],
+            query_delegation_expiry: [],
+            cycles_limits: {max_per_call: [], max_per_program: []},
+            validity: {not_before: [], not_after: [], recurring_windows: []},
+            quota: {per_principal: [], max_total_executions: [], min_interval: []},
+            multisig: [],
         };
 
         const {id: acId1} = await walletCreator.wallet.actor.create_access_config(createAccessConfigRequest1);
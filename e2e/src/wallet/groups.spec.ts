@@ -27,6 +27,7 @@ describe("groups", () => {
         description: "Test",
         private: false,
         transferable: true,
+        parent: [],
       }
     );
 
@@ -24,6 +24,7 @@ describe('nested votings', () => {
             description: 'test',
             transferable: true,
             private: false,
+            parent: [],
         });
 
         // create an access config for group1 to read voting info
@@ -31,7 +32,12 @@ describe('nested votings', () => {
             name: 'group1 can read voting info',
             description: '',
             allowees: [{Group : {id: wallet1GroupId, min_shares: 1n}}],
-            permissions: [ALLOW_VOTE_PERMISSION_ID]
+            permissions: [ALLOW_VOTE_PERMISSION_ID],
+            query_delegation_expiry: [],
+            cycles_limits: {max_per_call: [], max_per_program: []},
+            validity: {not_before: [], not_after: [], recurring_windows: []},
+            quota: {per_principal: [], max_total_executions: [], min_interval: []},
+            multisig: [],
         });
 
         // create a voting config for group1
@@ -74,7 +80,10 @@ describe('nested votings', () => {
                     fraction: '0.2',
                     target: { Group : wallet1GroupId  }
                 }
-            }
+            },
+            allow_abstain: false,
+            proposal_bond: [],
+            proposer_limits: {max_active_votings: [], rate_limit: []},
         });
 
         // group1 contains wallet2 with 1000 shares
@@ -99,6 +108,7 @@ describe('nested votings', () => {
             description: 'test',
             transferable: true,
             private: false,
+            parent: [],
         });
 
         // group2 contains three random voters with 1000 shares each
@@ -128,7 +138,8 @@ describe('nested votings', () => {
             description: '',
             targets: [
                 {Endpoint : { canister_id: user1.wallet.canisterId, method_name: 'list_votings' }},
-            ]
+            ],
+            cycles_limits: {max_per_call: [], max_per_program: []},
         });
 
         // create an access config for group2 (so they can read remote votings and create and read local votings)
@@ -136,7 +147,12 @@ describe('nested votings', () => {
             permissions: [wallet2PermissionId, ALLOW_VOTE_PERMISSION_ID],
             name: 'group2 can read union1 votings',
             description: '',
-            allowees: [{Group : { id: wallet2GroupId, min_shares: 1n} }]
+            allowees: [{Group : { id: wallet2GroupId, min_shares: 1n} }],
+            query_delegation_expiry: [],
+            cycles_limits: {max_per_call: [], max_per_program: []},
+            validity: {not_before: [], not_after: [], recurring_windows: []},
+            quota: {per_principal: [], max_total_executions: [], min_interval: []},
+            multisig: [],
         });
 
         voter21.walletAgent.setCurrentAccessConfig(wallet2AccessConfigId);
@@ -158,7 +174,9 @@ describe('nested votings', () => {
             voting_config_id: wallet1VotingConfigId,
             name: 'test',
             description: 'test',
-            winners_need: 1
+            winners_need: 1,
+            // votings are only proposed on behalf of the program initiator
+            proposer: [await user1.agent.getPrincipal()],
         });
 
         // add choices
@@ -189,22 +207,15 @@ describe('nested votings', () => {
             query_delegation_proof_opt: [],
         });
 
-        // get shares info proof for voter11
-        const {shares_info: [voter11SharesInfo]} = await voter11.walletPersonal.actor.get_my_shares_info_at({
-            group_id: wallet1GroupId,
-            at: voting.created_at
-        });
-
-        assert(voter11SharesInfo);
-
         // approve voting by voter11 (500 is enough to approve)
         await voter11.walletPersonal.actor.cast_my_vote({
             id: wallet1VotingId,
             vote: {
                 Approval: {
-                    shares_info: voter11SharesInfo!,
+                    group_id: wallet1GroupId,
                 }
-            }
+            },
+            rationale: [],
         });
 
         // wait for one minute for voting to start a new round
@@ -212,20 +223,24 @@ describe('nested votings', () => {
 
         // voter21 wants to check new votings of wallet1
         // he needs a query delegation proof in order to do that
-        const {proof} = await voter21.walletPersonal.actor.get_my_query_delegation_proof({
+        const {proof: issuedProof} = await voter21.walletPersonal.actor.issue_my_query_delegation_proof({
             requested_targets: [
                 {
                     Endpoint: { canister_id: user1.wallet.canisterId, method_name: 'list_votings' },
                 },
-            ]
+            ],
+            expires_in_opt: [],
         });
 
+        // only query calls return the proof with a certificate
+        const {proof} = await voter21.walletPersonal.actor.get_my_query_delegation_proof({id: issuedProof.id});
+
         const voter21Wallet1 = await connectSetup(voter21Identity, user1.wallet.canisterId, user1.historyLedger.canisterId);
         const {page} = await voter21Wallet1.walletPersonal.actor.list_votings({
             page_req: {
                 page_size: 100,
                 page_index: 0,
-                filter: null,
+                filter: {status: [], name: [], created_at: [], proposer: [], voting_config: []},
                 sort: null
             },
             query_delegation_proof_opt: [proof]
@@ -242,24 +257,27 @@ describe('nested votings', () => {
             local_nested_voting_config_id: wallet2NestedVotingConfigId
         });
 
-        // group2 voters get their shares info proofs
-        const {shares_info: [voter21SharesInfo]} = await voter21.walletPersonal.actor.get_my_shares_info_at({
+        // group2 voters get their certified shares info from the history ledger (only query calls are certified)
+        const {info_opt: [voter21SharesInfo]} = await voter21.historyLedger.actor.get_shares_info_of_at({
             group_id: wallet2GroupId,
+            of: await voter21.agent.getPrincipal(),
             at: voting1.created_at
         });
-        assert(voter21SharesInfo);
+        assert(voter21SharesInfo && voter21SharesInfo.signature.length == 1);
 
-        const {shares_info: [voter22SharesInfo]} = await voter22.walletPersonal.actor.get_my_shares_info_at({
+        const {info_opt: [voter22SharesInfo]} = await voter22.historyLedger.actor.get_shares_info_of_at({
             group_id: wallet2GroupId,
+            of: await voter22.agent.getPrincipal(),
             at: voting1.created_at
         });
-        assert(voter22SharesInfo);
+        assert(voter22SharesInfo && voter22SharesInfo.signature.length == 1);
 
-        const {shares_info: [voter23SharesInfo]} = await voter23.walletPersonal.actor.get_my_shares_info_at({
+        const {info_opt: [voter23SharesInfo]} = await voter23.historyLedger.actor.get_shares_info_of_at({
             group_id: wallet2GroupId,
+            of: await voter23.agent.getPrincipal(),
             at: voting1.created_at
         });
-        assert(voter23SharesInfo);
+        assert(voter23SharesInfo && voter23SharesInfo.signature.length == 1);
 
         const {nested_voting} = await voter21.wallet.actor.get_nested_voting({
             id: wallet2NestedVotingId,
@@ -298,10 +316,11 @@ describe('nested votings', () => {
             id: wallet1VotingId,
             vote: {
                 Common: {
-                    shares_info: voter11SharesInfo!,
+                    group_id: wallet1GroupId,
                     vote: [[voting1.choices[0], '1.0']]
                 }
-            }
+            },
+            rationale: [],
         });
 
         // waiting for round to finish
@@ -22,7 +22,8 @@ describe('query delegations', () => {
       const {id: acceptMyGroupSharesPermission} = await user2.wallet.actor.create_permission({
          targets: [{Endpoint : { canister_id: user1.wallet.canisterId, method_name: 'accept_my_group_shares' }}],
          name: 'Can accept tokens of this union in another union',
-         description: 'test'
+         description: 'test',
+         cycles_limits: {max_per_call: [], max_per_program: []},
       });
 
       // create an access config with this permission
@@ -30,7 +31,12 @@ describe('query delegations', () => {
          permissions: [acceptMyGroupSharesPermission],
          allowees: [{Profile : user2Identity.getPrincipal()}],
          name: 'Test',
-         description: 'test'
+         description: 'test',
+         query_delegation_expiry: [],
+         cycles_limits: {max_per_call: [], max_per_program: []},
+         validity: {not_before: [], not_after: [], recurring_windows: []},
+         quota: {per_principal: [], max_total_executions: [], min_interval: []},
+         multisig: [],
       });
 
       // accept HAS_PROFILE group tokens of wallet1 by wallet2
@@ -59,7 +65,8 @@ describe('query delegations', () => {
       const {id: listVotingsPermissionId} = await user2.wallet.actor.create_permission({
          targets: [{Endpoint : { canister_id: user1.wallet.canisterId, method_name: 'list_votings' }}],
          name: 'Can read votings of another union',
-         description: 'test'
+         description: 'test',
+         cycles_limits: {max_per_call: [], max_per_program: []},
       });
 
       // update the access config we created earlier
@@ -68,18 +75,26 @@ describe('query delegations', () => {
          new_permissions: [[acceptMyGroupSharesPermission, listVotingsPermissionId]],
          new_allowees: [],
          new_name: [],
-         new_description: []
+         new_description: [],
+         new_query_delegation_expiry: [],
+         new_cycles_limits: [],
+         new_validity: [],
+         new_quota: [],
+         new_multisig: [],
       });
 
       // fetch a query delegation proof
-      const {proof} = await user2.walletPersonal.actor.get_my_query_delegation_proof({requested_targets: [{Endpoint : {canister_id: user1.wallet.canisterId, method_name: 'list_votings'}}]});
+      const {proof: issuedProof} = await user2.walletPersonal.actor.issue_my_query_delegation_proof({requested_targets: [{Endpoint : {canister_id: user1.wallet.canisterId, method_name: 'list_votings'}}], expires_in_opt: []});
+
+      // fetch it again with a query call, so it comes with a certificate
+      const {proof} = await user2.walletPersonal.actor.get_my_query_delegation_proof({id: issuedProof.id});
 
       // validate that user2 can't read anything in wallet1 without the proof
       await expectThrowsAsync(user2Wallet1.walletPersonal.actor.list_votings({
          page_req: {
             page_index: 0,
             page_size: 1,
-            filter: null,
+            filter: {status: [], name: [], created_at: [], proposer: [], voting_config: []},
             sort: null,
          },
          query_delegation_proof_opt: []
@@ -90,7 +105,7 @@ describe('query delegations', () => {
          page_req: {
             page_index: 0,
             page_size: 1,
-            filter: null,
+            filter: {status: [], name: [], created_at: [], proposer: [], voting_config: []},
             sort: null,
          },
          query_delegation_proof_opt: [proof]
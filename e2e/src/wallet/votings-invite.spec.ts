@@ -10,7 +10,6 @@ import {
 import { Ed25519KeyIdentity } from "@dfinity/identity";
 import {
   CreateProfileRequest,
-  SharesInfo,
   UpdateAccessConfigRequest,
 } from "dfx-type/wallet/wallet";
 import { assert } from "chai";
@@ -56,6 +55,11 @@ describe("votings", () => {
           { Profile: await user1.agent.getPrincipal() },
         ],
       ],
+      new_query_delegation_expiry: [],
+      new_cycles_limits: [],
+      new_validity: [],
+      new_quota: [],
+      new_multisig: [],
     });
     user1.walletAgent.setCurrentAccessConfig(UNLIMITED_ACCESS_CONFIG_ID);
     walletCreator.walletAgent.setCurrentAccessConfig(
@@ -84,6 +88,9 @@ describe("votings", () => {
         QuantityOf: { quantity: 1n, target: { Group: HAS_PROFILE_GROUP_ID } },
       },
       round: { round_delay: 0n, round_duration: BigInt(30 * 10 ** 9) },
+      allow_abstain: false,
+      proposal_bond: [],
+      proposer_limits: { max_active_votings: [], rate_limit: [] },
     });
 
     // THERE IS NOTHING SPECIAL ABOUT WALLET CREATOR ANYMORE - ALL OF THEM ARE JUST 'Has profile' GROUP MEMBERS NOW
@@ -93,6 +100,8 @@ describe("votings", () => {
       description: "Lets make us a new king!",
       voting_config_id: vcId,
       winners_need: 1,
+      // votings are only proposed on behalf of the program initiator
+      proposer: [await user1.agent.getPrincipal()],
     });
 
     const updateAccessConfigRequestType = IDL.Record({
@@ -185,19 +194,14 @@ describe("votings", () => {
       query_delegation_proof_opt: [],
     });
 
-    const {
-      shares_info: user1SharesInfo,
-    } = await user1.walletPersonal.actor.get_my_shares_info_at({
-      group_id: HAS_PROFILE_GROUP_ID,
-      at: voting0.created_at,
-    });
     await user1.walletPersonal.actor.cast_my_vote({
       id: votingId,
       vote: {
         Approval: {
-          shares_info: user1SharesInfo[0] as SharesInfo,
+          group_id: HAS_PROFILE_GROUP_ID,
         },
       },
+      rationale: [],
     });
 
     console.log("VOTING 0", stringify(voting0));
@@ -220,10 +224,11 @@ describe("votings", () => {
       id: votingId,
       vote: {
         Common: {
-          shares_info: user1SharesInfo[0] as SharesInfo,
+          group_id: HAS_PROFILE_GROUP_ID,
           vote: [[voting.choices[0], "1.0"]],
         },
       },
+      rationale: [],
     });
 
     const timestampPre = BigInt(new Date().getTime()) * BigInt(1000000);
@@ -8,7 +8,7 @@ import {
     UNLIMITED_ACCESS_CONFIG_ID
 } from "../utils";
 import {Ed25519KeyIdentity} from "@dfinity/identity";
-import {CreateVotingRequest, SharesInfo, UpdateAccessConfigRequest} from "dfx-type/wallet/wallet";
+import {CreateVotingRequest, UpdateAccessConfigRequest} from "dfx-type/wallet/wallet";
 import {assert} from "chai";
 import {Principal} from "@dfinity/principal";
 import {IDL} from "@dfinity/candid";
@@ -52,7 +52,12 @@ describe('votings', () => {
             new_name: [],
             new_description: [],
             new_permissions: [],
-            new_allowees: [[]]
+            new_allowees: [[]],
+            new_query_delegation_expiry: [],
+            new_cycles_limits: [],
+            new_validity: [],
+            new_quota: [],
+            new_multisig: [],
         });
         walletCreator.walletAgent.setCurrentAccessConfig(ALLOW_VOTE_ACCESS_CONFIG_ID);
 
@@ -62,7 +67,9 @@ describe('votings', () => {
             name: 'Test',
             description: 'Lets make us a new king!',
             voting_config_id: EMERGENCY_VOTING_CONFIG_ID,
-            winners_need: 1
+            winners_need: 1,
+            // votings are only proposed on behalf of the program initiator
+            proposer: [await user1.agent.getPrincipal()],
         });
 
         const updateAccessConfigRequestType = IDL.Record({
@@ -137,21 +144,6 @@ describe('votings', () => {
             }
         });
 
-        const {voting: voting0} = await user1.wallet.actor.get_voting({id: votingId, query_delegation_proof_opt: []});
-
-        const {shares_info: user1SharesInfo} = await user1.walletPersonal.actor.get_my_shares_info_at({
-            group_id: HAS_PROFILE_GROUP_ID,
-            at: voting0.created_at
-        });
-        const {shares_info: user2SharesInfo} = await user2.walletPersonal.actor.get_my_shares_info_at({
-            group_id: HAS_PROFILE_GROUP_ID,
-            at: voting0.created_at
-        });
-        const {shares_info: walletCreatorSharesInfo} = await walletCreator.walletPersonal.actor.get_my_shares_info_at({
-            group_id: HAS_PROFILE_GROUP_ID,
-            at: voting0.created_at
-        });
-
         // skip first minute because of how emergency voting config works
         await delay(1000 * 65);
 
@@ -168,30 +160,33 @@ describe('votings', () => {
             id: votingId,
             vote: {
                 Common: {
-                    shares_info: user1SharesInfo[0] as SharesInfo,
+                    group_id: HAS_PROFILE_GROUP_ID,
                     vote: [[voting.choices[0], "1.0"]]
                 }
-            }
+            },
+            rationale: [],
         });
 
         await user2.walletPersonal.actor.cast_my_vote({
             id: votingId,
             vote: {
                 Common: {
-                    shares_info: user2SharesInfo[0] as SharesInfo,
+                    group_id: HAS_PROFILE_GROUP_ID,
                     vote: [[voting.choices[0], "1.0"]]
                 }
-            }
+            },
+            rationale: [],
         });
 
         await walletCreator.walletPersonal.actor.cast_my_vote({
             id: votingId,
             vote: {
                 Common: {
-                    shares_info: walletCreatorSharesInfo[0] as SharesInfo,
+                    group_id: HAS_PROFILE_GROUP_ID,
                     vote: [[voting.choices[0], "1.0"]]
                 }
-            }
+            },
+            rationale: [],
         });
 
         const timestampPre = BigInt((new Date()).getTime()) * BigInt(1000000);
@@ -11,6 +11,7 @@ export interface GetProgramExecutionEntryMetaRequest { 'id' : bigint }
 export interface GetProgramExecutionEntryMetaResponse {
   'initiator' : Principal,
   'program_executed_with' : ProgramExecutedWith,
+  'approvers' : Array<Principal>,
 }
 export interface GetProgramExecutionEntryProgramRequest { 'id' : bigint }
 export interface GetProgramExecutionEntryProgramResponse {
@@ -65,13 +66,20 @@ export interface RemoteCallPayload {
 }
 export type Shares = bigint;
 export interface SharesInfo {
-  'signature' : never,
+  'signature' : [] | [SharesInfoSignature],
   'balance' : Shares,
   'group_id' : GroupId,
   'timestamp' : bigint,
   'principal_id' : Principal,
   'total_supply' : Shares,
 }
+export interface SharesInfoSignature {
+  'certificate' : Array<number>,
+  'total_supply_valid_from' : bigint,
+  'balance_valid_from' : bigint,
+  'balance_witness' : Array<number>,
+  'total_supply_witness' : Array<number>,
+}
 export type VotingConfigId = Id;
 export interface _SERVICE {
   'get_program_execution_entry_meta' : (
@@ -10,6 +10,7 @@ export const idlFactory = ({ IDL }) => {
   const GetProgramExecutionEntryMetaResponse = IDL.Record({
     'initiator' : IDL.Principal,
     'program_executed_with' : ProgramExecutedWith,
+    'approvers' : IDL.Vec(IDL.Principal),
   });
   const GetProgramExecutionEntryProgramRequest = IDL.Record({
     'id' : IDL.Nat64,
@@ -63,9 +64,16 @@ export const idlFactory = ({ IDL }) => {
     'of' : IDL.Principal,
     'group_id' : GroupId,
   });
+  const SharesInfoSignature = IDL.Record({
+    'certificate' : IDL.Vec(IDL.Nat8),
+    'total_supply_valid_from' : IDL.Nat64,
+    'balance_valid_from' : IDL.Nat64,
+    'balance_witness' : IDL.Vec(IDL.Nat8),
+    'total_supply_witness' : IDL.Vec(IDL.Nat8),
+  });
   const Shares = IDL.Nat;
   const SharesInfo = IDL.Record({
-    'signature' : IDL.Empty,
+    'signature' : IDL.Opt(SharesInfoSignature),
     'balance' : Shares,
     'group_id' : GroupId,
     'timestamp' : IDL.Nat64,
@@ -5,10 +5,23 @@ export interface AcceptMyGroupSharesRequest {
 }
 export interface AccessConfig {
   'id' : [] | [AccessConfigId],
+  'validity' : AccessConfigValidity,
   'permissions' : Array<PermissionId>,
+  'query_delegation_expiry' : [] | [bigint],
+  'cycles_limits' : CyclesLimits,
   'name' : string,
+  'quota' : AccessConfigQuota,
   'description' : string,
+  'multisig' : [] | [MultisigSettings],
   'allowees' : Array<AlloweeConstraint>,
+  'is_active' : boolean,
+  'expiration_task' : [] | [bigint],
+}
+export interface AccessConfigExplanation {
+  'id' : AccessConfigId,
+  'is_multisig' : boolean,
+  'allowees' : Array<AlloweeConstraint>,
+  'is_active' : boolean,
 }
 export interface AccessConfigFilter {
   'permission' : [] | [PermissionId],
@@ -16,9 +29,36 @@ export interface AccessConfigFilter {
   'profile' : [] | [ProfileId],
 }
 export type AccessConfigId = Id;
-export type AlloweeConstraint = { 'Group' : GroupCondition } |
+export interface AccessConfigQuota {
+  'max_total_executions' : [] | [bigint],
+  'per_principal' : [] | [PeriodicLimit],
+  'min_interval' : [] | [bigint],
+}
+export interface AccessConfigValidity {
+  'not_before' : [] | [bigint],
+  'recurring_windows' : Array<RecurringWindow>,
+  'not_after' : [] | [bigint],
+}
+export type AlloweeConstraint = { 'Or' : Array<AlloweeConstraint> } |
+  { 'And' : Array<AlloweeConstraint> } |
+  { 'Not' : AlloweeConstraint } |
+  { 'Group' : GroupCondition } |
+  { 'GroupTree' : GroupCondition } |
   { 'Profile' : ProfileId } |
   { 'Everyone' : null };
+export interface ApproveMultisigProposalRequest { 'id' : MultisigProposalId }
+export interface ApproveMultisigProposalResponse {
+  'result' : [] | [ProgramExecutionResult],
+}
+export type ArgPredicate = { 'PrincipalIn' : [ArgSelector, Array<Principal>] } |
+  { 'Equals' : [ArgSelector, ArgValue] } |
+  { 'AtLeast' : [ArgSelector, bigint] } |
+  { 'AtMost' : [ArgSelector, bigint] };
+export interface ArgSelector { 'path' : Array<string>, 'arg_index' : number }
+export type ArgValue = { 'Bool' : boolean } |
+  { 'Text' : string } |
+  { 'Principal' : Principal } |
+  { 'Number' : bigint };
 export interface Batch {
   'id' : [] | [BatchId],
   'key' : Key,
@@ -35,6 +75,11 @@ export interface BurnMyGroupSharesRequest {
   'qty' : Shares,
   'group_id' : GroupId,
 }
+export interface CallCheck {
+  'endpoint' : RemoteCallEndpoint,
+  'verdicts' : Array<[PermissionId, TargetMatch]>,
+}
+export interface CancelMultisigProposalRequest { 'id' : MultisigProposalId }
 export type CandidRejectionCode = { 'NoError' : null } |
   { 'CanisterError' : null } |
   { 'SysTransient' : null } |
@@ -46,7 +91,16 @@ export interface CastMyNestedVoteRequest {
   'id' : NestedVotingId,
   'vote' : MultiChoiceVote,
 }
-export interface CastMyVoteRequest { 'id' : VotingId, 'vote' : Vote }
+export interface CastMyVoteRequest {
+  'id' : VotingId,
+  'vote' : Vote,
+  'rationale' : [] | [string],
+}
+export interface CheckMyExecutionRequest {
+  'access_config_id' : AccessConfigId,
+  'program' : Program,
+}
+export interface CheckMyExecutionResponse { 'check' : ExecutionCheck }
 export interface Choice {
   'id' : [] | [ChoiceId],
   'name' : string,
@@ -64,10 +118,39 @@ export interface Chunk {
 }
 export interface ChunkFilter { 'batch_id' : BatchId }
 export type ChunkId = Id;
+export interface Comment {
+  'id' : [] | [CommentId],
+  'updated_at' : bigint,
+  'text' : string,
+  'created_at' : bigint,
+  'author' : Principal,
+  'voting_id' : VotingId,
+  'parent_id' : [] | [CommentId],
+  'removed' : boolean,
+}
+export interface CommentFilter {
+  'voting_id' : VotingId,
+  'parent_id' : [] | [CommentId],
+}
+export type CommentId = Id;
+export interface CommonVote {
+  'vote' : Array<[ChoiceId, Fraction]>,
+  'group_id' : GroupId,
+}
+export interface ConstrainedEndpoint {
+  'predicates' : Array<ArgPredicate>,
+  'endpoint' : RemoteCallEndpoint,
+}
+export interface ConvertGroupRequest { 'group_id' : GroupId }
 export interface CreateAccessConfigRequest {
+  'validity' : AccessConfigValidity,
   'permissions' : Array<PermissionId>,
+  'query_delegation_expiry' : [] | [bigint],
+  'cycles_limits' : CyclesLimits,
   'name' : string,
+  'quota' : AccessConfigQuota,
   'description' : string,
+  'multisig' : [] | [MultisigSettings],
   'allowees' : Array<AlloweeConstraint>,
 }
 export interface CreateAccessConfigResponse { 'id' : AccessConfigId }
@@ -78,13 +161,25 @@ export interface CreateChunkRequest {
   'batch_id' : BatchId,
 }
 export interface CreateChunkResponse { 'chunk_id' : ChunkId }
+export interface CreateComputedGroupRequest {
+  'name' : string,
+  'rule' : GroupRule,
+  'description' : string,
+}
 export interface CreateGroupRequest {
   'transferable' : boolean,
   'name' : string,
   'description' : string,
   'private' : boolean,
+  'parent' : [] | [GroupId],
 }
 export interface CreateGroupResponse { 'group_id' : GroupId }
+export interface CreateMyVotingCommentRequest {
+  'text' : string,
+  'voting_id' : VotingId,
+  'parent_id' : [] | [CommentId],
+}
+export interface CreateMyVotingCommentResponse { 'id' : CommentId }
 export interface CreateNestedVotingConfigRequest {
   'remote_union_id' : Principal,
   'remote_voting_config_id' : RemoteVotingConfigId,
@@ -102,7 +197,12 @@ export interface CreateNestedVotingRequest {
   'remote_group_id' : GroupId,
 }
 export interface CreateNestedVotingResponse { 'id' : NestedVotingId }
+export interface CreatePermissionFromTemplateRequest {
+  'template' : PermissionTemplate,
+}
+export interface CreatePermissionFromTemplateResponse { 'id' : PermissionId }
 export interface CreatePermissionRequest {
+  'cycles_limits' : CyclesLimits,
   'name' : string,
   'description' : string,
   'targets' : Array<PermissionTarget>,
@@ -113,6 +213,15 @@ export interface CreateProfileRequest {
   'name' : string,
   'description' : string,
 }
+export interface CreateVestingGrantRequest {
+  'qty' : Shares,
+  'beneficiary' : Principal,
+  'revocable' : boolean,
+  'group_id' : GroupId,
+  'schedule' : VestingSchedule,
+  'voting_power' : boolean,
+}
+export interface CreateVestingGrantResponse { 'id' : VestingGrantId }
 export interface CreateVotingChoiceRequest {
   'name' : string,
   'description' : string,
@@ -124,13 +233,16 @@ export interface CreateVotingConfigRequest {
   'win' : ThresholdValue,
   'winners_count' : [] | [LenInterval],
   'permissions' : Array<PermissionId>,
+  'proposer_limits' : ProposerLimits,
   'name' : string,
   'description' : string,
   'rejection' : ThresholdValue,
   'next_round' : ThresholdValue,
   'choices_count' : [] | [LenInterval],
   'approval' : ThresholdValue,
+  'allow_abstain' : boolean,
   'quorum' : ThresholdValue,
+  'proposal_bond' : [] | [ProposalBond],
   'round' : RoundSettings,
 }
 export interface CreateVotingConfigResponse { 'id' : VotingConfigId }
@@ -138,9 +250,17 @@ export interface CreateVotingRequest {
   'name' : string,
   'description' : string,
   'voting_config_id' : VotingConfigId,
+  'proposer' : [] | [Principal],
   'winners_need' : number,
 }
 export interface CreateVotingResponse { 'id' : VotingId }
+export interface CreatedAtRange { 'to' : bigint, 'from' : bigint }
+export interface CyclesBudget { 'period' : bigint, 'amount' : bigint }
+export interface CyclesLimits {
+  'max_per_call' : [] | [bigint],
+  'max_per_program' : [] | [bigint],
+}
+export interface CyclesSpending { 'period_start' : bigint, 'spent' : bigint }
 export interface DeclineMyGroupSharesRequest {
   'qty' : Shares,
   'group_id' : GroupId,
@@ -148,6 +268,7 @@ export interface DeclineMyGroupSharesRequest {
 export interface DeleteAccessConfigRequest { 'id' : AccessConfigId }
 export interface DeleteBatchesRequest { 'ids' : Array<BatchId> }
 export interface DeleteGroupRequest { 'group_id' : GroupId }
+export interface DeleteMyVotingCommentRequest { 'id' : CommentId }
 export interface DeleteNestedVotingConfigRequest { 'id' : NestedVotingConfigId }
 export interface DeleteNestedVotingRequest { 'id' : NestedVotingId }
 export interface DeletePermissionRequest { 'id' : PermissionId }
@@ -158,11 +279,45 @@ export interface DeleteVotingChoiceRequest {
 }
 export interface DeleteVotingConfigRequest { 'id' : VotingConfigId }
 export interface DeleteVotingRequest { 'id' : VotingId }
+export interface EndpointAccessExplanation {
+  'permissions' : Array<PermissionExplanation>,
+  'voting_proposers' : Array<AccessConfigExplanation>,
+}
 export interface ExecuteRequest {
   'access_config_id' : AccessConfigId,
   'program' : Program,
 }
 export interface ExecuteResponse { 'result' : ProgramExecutionResult }
+export interface ExecutionCheck {
+  'failure' : [] | [ExecutionCheckFailure],
+  'calls' : Array<CallCheck>,
+  'allowing_permission' : [] | [PermissionId],
+  'satisfied_allowee' : [] | [AlloweeConstraint],
+}
+export type ExecutionCheckFailure = { 'CyclesBudgetExceeded' : null } |
+  { 'TypecheckFailed' : string } |
+  { 'InvalidProgram' : string } |
+  { 'InvalidProposer' : string } |
+  { 'MultisigApprovalRequired' : null } |
+  { 'CallerNotAllowed' : null } |
+  { 'ExecutionQuotaExceeded' : RemainingQuota } |
+  { 'ProgramNotAllowedByAccessConfig' : null } |
+  { 'CyclesLimitExceeded' : null } |
+  { 'AccessConfigInactive' : null };
+export interface ExplainEndpointAccessRequest {
+  'endpoint' : RemoteCallEndpoint,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ExplainEndpointAccessResponse {
+  'explanation' : EndpointAccessExplanation,
+}
+export interface ExplainPrincipalAccessRequest {
+  'principal' : Principal,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ExplainPrincipalAccessResponse {
+  'explanation' : PrincipalAccessExplanation,
+}
 export type Fraction = string;
 export interface FractionOf { 'fraction' : Fraction, 'target' : Target }
 export interface GetAccessConfigRequest {
@@ -196,6 +351,11 @@ export interface GetGroupsOfRequest {
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface GetGroupsResponse { 'groups' : Array<GroupExt> }
+export interface GetMultisigProposalRequest {
+  'id' : MultisigProposalId,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface GetMultisigProposalResponse { 'proposal' : MultisigProposal }
 export interface GetMyGroupSharesBalanceRequest { 'group_id' : GroupId }
 export interface GetMyGroupSharesBalanceResponse { 'balance' : Shares }
 export interface GetMyNestedVoteRequest {
@@ -205,12 +365,16 @@ export interface GetMyNestedVoteRequest {
 export interface GetMyNestedVoteResponse {
   'my_vote' : Array<[ChoiceId, Shares]>,
 }
-export interface GetMyQueryDelegationProofRequest {
-  'requested_targets' : Array<PermissionTarget>,
-}
+export interface GetMyQueryDelegationProofRequest { 'id' : QueryDelegationId }
 export interface GetMyQueryDelegationProofResponse {
   'proof' : QueryDelegationProof,
 }
+export interface GetMyRemainingQuotaRequest {
+  'access_config_id' : AccessConfigId,
+}
+export interface GetMyRemainingQuotaResponse {
+  'remaining_quota' : RemainingQuota,
+}
 export interface GetMySharesInfoAtRequest {
   'at' : bigint,
   'group_id' : GroupId,
@@ -258,12 +422,22 @@ export interface GetTotalGroupSharesRequest {
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface GetTotalGroupSharesResponse { 'total' : Shares }
+export interface GetVestingGrantRequest {
+  'id' : VestingGrantId,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface GetVestingGrantResponse { 'grant' : VestingGrantExt }
 export interface GetVotingChoiceRequest {
   'choice_id' : ChoiceId,
   'voting_id' : RemoteVotingId,
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface GetVotingChoiceResponse { 'choice' : Choice }
+export interface GetVotingCommentRequest {
+  'id' : CommentId,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface GetVotingCommentResponse { 'comment' : Comment }
 export interface GetVotingConfigRequest {
   'id' : VotingConfigId,
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
@@ -280,24 +454,39 @@ export interface GetVotingResultsRequest {
 }
 export interface GetVotingResultsResponse {
   'results' : Array<[ChoiceId, Array<[GroupId, Shares]>]>,
+  'rationales' : Array<[GroupId, Array<[Principal, string]>]>,
 }
 export interface Group {
   'id' : [] | [GroupId],
   'token' : [] | [TokenId],
+  'vote_escrow' : [] | [VoteEscrowSettings],
   'name' : string,
+  'rule' : [] | [GroupRule],
   'description' : string,
   'private' : boolean,
+  'parent' : [] | [GroupId],
 }
 export interface GroupCondition { 'id' : GroupId, 'min_shares' : Shares }
 export interface GroupExt { 'it' : Group, 'transferable' : boolean }
 export type GroupId = Id;
+export type GroupRule = { 'Union' : Array<GroupCondition> } |
+  { 'Intersection' : Array<GroupCondition> } |
+  { 'WeightedSum' : Array<[GroupId, Fraction]> };
 export type Id = bigint;
 export interface InitRequest {
+  'ic_root_key' : [] | [Array<number>],
   'union_description' : string,
   'wallet_creator' : Principal,
   'union_name' : string,
   'history_ledger' : Principal,
 }
+export interface IssueMyQueryDelegationProofRequest {
+  'requested_targets' : Array<PermissionTarget>,
+  'expires_in_opt' : [] | [bigint],
+}
+export interface IssueMyQueryDelegationProofResponse {
+  'proof' : QueryDelegationProof,
+}
 export type Key = string;
 export interface LenInterval { 'max' : number, 'min' : number }
 export interface ListAccessConfigsPage {
@@ -333,6 +522,11 @@ export interface ListChunksRequest {
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface ListChunksResponse { 'page' : ListChunksPage }
+export interface ListGroupRelativesRequest {
+  'group_id' : GroupId,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListGroupRelativesResponse { 'group_ids' : Array<GroupId> }
 export interface ListGroupSharesPage {
   'data' : Array<[Principal, Shares]>,
   'has_next' : boolean,
@@ -352,6 +546,26 @@ export interface ListGroupsRequest {
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface ListGroupsResponse { 'page' : ListGroupsPage }
+export interface ListMultisigProposalsPage {
+  'data' : Array<MultisigProposal>,
+  'has_next' : boolean,
+}
+export interface ListMultisigProposalsPageRequest {
+  'page_size' : number,
+  'page_index' : number,
+  'sort' : null,
+  'filter' : MultisigProposalFilter,
+}
+export interface ListMultisigProposalsRequest {
+  'page_req' : ListMultisigProposalsPageRequest,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListMultisigProposalsResponse {
+  'page' : ListMultisigProposalsPage,
+}
+export interface ListMyVestingGrantsRequest { 'page_req' : PageRequest }
+export interface ListMyVoteEscrowLocksRequest { 'page_req' : PageRequest }
+export interface ListMyVotingsRequest { 'page_req' : PageRequest }
 export interface ListNestedVotingConfigsPage {
   'data' : Array<NestedVotingConfig>,
   'has_next' : boolean,
@@ -383,6 +597,12 @@ export interface ListNestedVotingsRequest {
   'page_req' : ListNestedVotingsPageRequest,
 }
 export interface ListNestedVotingsResponse { 'page' : ListNestedVotingsPage }
+export interface ListPermissionTemplatesRequest {
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListPermissionTemplatesResponse {
+  'templates' : Array<PermissionTemplateInfo>,
+}
 export interface ListPermissionsPage {
   'data' : Array<Permission>,
   'has_next' : boolean,
@@ -425,6 +645,44 @@ export interface ListProgramExecutionEntryIdsResponse {
   'page' : ListProgramExecutionEntryIdsPage,
   'history_ledger_canister_id' : Principal,
 }
+export interface ListRevokedQueryDelegatesRequest {
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListRevokedQueryDelegatesResponse {
+  'revoked_delegates' : Array<[Principal, bigint]>,
+}
+export interface ListVestingGrantsPage {
+  'data' : Array<VestingGrantExt>,
+  'has_next' : boolean,
+}
+export interface ListVestingGrantsPageRequest {
+  'page_size' : number,
+  'page_index' : number,
+  'sort' : null,
+  'filter' : VestingGrantFilter,
+}
+export interface ListVestingGrantsRequest {
+  'page_req' : ListVestingGrantsPageRequest,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListVestingGrantsResponse { 'page' : ListVestingGrantsPage }
+export interface ListVoteEscrowLocksPage {
+  'data' : Array<VoteEscrowLock>,
+  'has_next' : boolean,
+}
+export interface ListVoteEscrowLocksPageRequest {
+  'page_size' : number,
+  'page_index' : number,
+  'sort' : null,
+  'filter' : VoteEscrowLockFilter,
+}
+export interface ListVoteEscrowLocksRequest {
+  'page_req' : ListVoteEscrowLocksPageRequest,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListVoteEscrowLocksResponse {
+  'page' : ListVoteEscrowLocksPage,
+}
 export interface ListVotingChoicesPage {
   'data' : Array<Choice>,
   'has_next' : boolean,
@@ -440,6 +698,21 @@ export interface ListVotingChoicesRequest {
   'query_delegation_proof_opt' : [] | [QueryDelegationProof],
 }
 export interface ListVotingChoicesResponse { 'page' : ListVotingChoicesPage }
+export interface ListVotingCommentsPage {
+  'data' : Array<Comment>,
+  'has_next' : boolean,
+}
+export interface ListVotingCommentsPageRequest {
+  'page_size' : number,
+  'page_index' : number,
+  'sort' : null,
+  'filter' : CommentFilter,
+}
+export interface ListVotingCommentsRequest {
+  'page_req' : ListVotingCommentsPageRequest,
+  'query_delegation_proof_opt' : [] | [QueryDelegationProof],
+}
+export interface ListVotingCommentsResponse { 'page' : ListVotingCommentsPage }
 export interface ListVotingConfigsPage {
   'data' : Array<VotingConfig>,
   'has_next' : boolean,
@@ -463,7 +736,7 @@ export interface ListVotingsPageRequest {
   'page_size' : number,
   'page_index' : number,
   'sort' : VotingSort,
-  'filter' : null,
+  'filter' : VotingFilter,
 }
 export interface ListVotingsRequest {
   'page_req' : ListVotingsPageRequest,
@@ -471,15 +744,53 @@ export interface ListVotingsRequest {
 }
 export interface ListVotingsResponse { 'page' : ListVotingsPage }
 export interface LockBatchesRequest { 'ids' : Array<BatchId> }
+export interface LockMyGroupSharesForVotingPowerRequest {
+  'qty' : Shares,
+  'duration' : bigint,
+  'group_id' : GroupId,
+}
+export interface LockMyGroupSharesForVotingPowerResponse {
+  'id' : VoteEscrowLockId,
+}
+export interface LockedProposalBond {
+  'status' : ProposalBondStatus,
+  'bond' : ProposalBond,
+}
 export interface MintGroupSharesRequest {
   'qty' : Shares,
   'owner' : Principal,
   'group_id' : GroupId,
 }
+export interface ModerateVotingCommentRequest { 'id' : CommentId }
 export interface MultiChoiceVote {
   'vote' : Array<[ChoiceId, Fraction]>,
   'shares_info' : SharesInfo,
 }
+export interface MultisigProposal {
+  'id' : [] | [MultisigProposalId],
+  'status' : MultisigProposalStatus,
+  'rejecters' : Array<Principal>,
+  'created_at' : bigint,
+  'access_config_id' : AccessConfigId,
+  'proposer' : Principal,
+  'expires_at' : bigint,
+  'approvers' : Array<Principal>,
+  'program' : Program,
+}
+export interface MultisigProposalFilter {
+  'access_config_id' : [] | [AccessConfigId],
+}
+export type MultisigProposalId = Id;
+export type MultisigProposalStatus = { 'Rejected' : null } |
+  { 'Executed' : null } |
+  { 'Cancelled' : null } |
+  { 'Expired' : null } |
+  { 'Pending' : null };
+export interface MultisigSettings {
+  'threshold' : number,
+  'veto_threshold' : [] | [number],
+  'timeout' : bigint,
+}
 export type NestedVoteCalculation = { 'Turnout' : null } |
   { 'Total' : null };
 export interface NestedVoting {
@@ -518,16 +829,41 @@ export interface PageRequest {
   'sort' : null,
   'filter' : null,
 }
+export interface PeriodicLimit { 'max_executions' : bigint, 'period' : bigint }
 export interface Permission {
   'id' : [] | [PermissionId],
+  'cycles_limits' : CyclesLimits,
   'name' : string,
   'description' : string,
   'targets' : Array<PermissionTarget>,
 }
+export interface PermissionExplanation {
+  'voting_configs' : Array<VotingConfigId>,
+  'access_configs' : Array<AccessConfigExplanation>,
+  'permission_id' : PermissionId,
+}
 export interface PermissionFilter { 'target' : [] | [PermissionTarget] }
 export type PermissionId = Id;
 export type PermissionTarget = { 'Endpoint' : RemoteCallEndpoint } |
+  { 'Deny' : RemoteCallEndpoint } |
+  { 'ConstrainedEndpoint' : ConstrainedEndpoint } |
   { 'SelfEmptyProgram' : null };
+export type PermissionTemplate = { 'ReadOnly' : null } |
+  { 'VotingAdmin' : null } |
+  { 'GroupAdmin' : null } |
+  { 'StreamingAdmin' : null };
+export interface PermissionTemplateInfo {
+  'name' : string,
+  'description' : string,
+  'targets' : Array<PermissionTarget>,
+  'template' : PermissionTemplate,
+  'permission_id' : [] | [PermissionId],
+}
+export interface PrincipalAccessExplanation {
+  'delegated' : Array<PermissionTarget>,
+  'by_voting' : Array<PermissionTarget>,
+  'direct' : Array<PermissionTarget>,
+}
 export interface Profile {
   'id' : ProfileId,
   'name' : string,
@@ -543,16 +879,59 @@ export interface ProgramExecutionFilter {
 }
 export type ProgramExecutionResult = { 'Empty' : null } |
   { 'RemoteCallSequence' : Array<RawCandidCallResult> };
+export interface ProposalBond {
+  'group_id' : GroupId,
+  'on_rejection' : RejectedBondAction,
+  'quantity' : Shares,
+}
+export type ProposalBondStatus = { 'Burned' : null } |
+  { 'Refunded' : null } |
+  { 'Locked' : null } |
+  { 'SentToTreasury' : null };
+export interface ProposeMultisigProgramRequest {
+  'access_config_id' : AccessConfigId,
+  'program' : Program,
+}
+export interface ProposeMultisigProgramResponse {
+  'id' : MultisigProposalId,
+  'result' : [] | [ProgramExecutionResult],
+}
+export interface ProposerLimits {
+  'max_active_votings' : [] | [number],
+  'rate_limit' : [] | [ProposerRateLimit],
+}
+export interface ProposerRateLimit { 'window' : bigint, 'max_votings' : number }
 export interface QuantityOf { 'target' : Target, 'quantity' : Shares }
+export type QueryDelegationId = Id;
 export interface QueryDelegationProof {
-  'signature' : null,
+  'id' : QueryDelegationId,
+  'signature' : [] | [QueryDelegationProofSignature],
+  'issued_at' : bigint,
   'union_id' : Principal,
   'delegate_id' : Principal,
   'allowed_query_targets' : Array<PermissionTarget>,
   'expires_at' : bigint,
 }
+export interface QueryDelegationProofSignature {
+  'certificate' : Array<number>,
+  'witness' : Array<number>,
+}
 export type RawCandidCallResult = { 'Ok' : Array<number> } |
   { 'Err' : [CandidRejectionCode, string] };
+export interface RecurringWindow {
+  'duration' : bigint,
+  'period' : bigint,
+  'offset' : bigint,
+}
+export interface RejectMultisigProposalRequest { 'id' : MultisigProposalId }
+export type RejectedBondAction = { 'Burn' : null } |
+  { 'SendToTreasury' : null };
+export interface RemainingQuota {
+  'period_ends_at' : [] | [bigint],
+  'total_executions' : [] | [bigint],
+  'executions_in_period' : [] | [bigint],
+  'next_execution_at' : [] | [bigint],
+}
 export type RemoteCallArgs = { 'CandidString' : Array<string> } |
   { 'Encoded' : Array<number> };
 export interface RemoteCallEndpoint {
@@ -568,6 +947,8 @@ export type RemoteVotingConfigId = { 'Nested' : NestedVotingConfigId } |
   { 'Common' : VotingConfigId };
 export type RemoteVotingId = { 'Nested' : NestedVotingId } |
   { 'Common' : VotingId };
+export interface RevokeQueryDelegateRequest { 'delegate_id' : Principal }
+export interface RevokeVestingGrantRequest { 'id' : VestingGrantId }
 export type RoundId = number;
 export interface RoundResult { 'choices' : Array<ChoiceId>, 'round' : RoundId }
 export interface RoundSettings {
@@ -579,22 +960,36 @@ export interface SendBatchRequest {
   'target_canister' : Principal,
 }
 export interface Settings {
+  'cycles_budget' : [] | [CyclesBudget],
   'name' : string,
   'description' : string,
+  'ic_root_key' : [] | [Array<number>],
   'history_ledgers' : Array<TimestampedRecord>,
+  'cycles_spending' : CyclesSpending,
 }
 export type Shares = bigint;
 export interface SharesInfo {
-  'signature' : null,
+  'signature' : [] | [SharesInfoSignature],
   'balance' : Shares,
   'group_id' : GroupId,
   'timestamp' : bigint,
   'principal_id' : Principal,
   'total_supply' : Shares,
 }
-export interface SingleChoiceVote { 'shares_info' : SharesInfo }
+export interface SharesInfoSignature {
+  'certificate' : Array<number>,
+  'total_supply_valid_from' : bigint,
+  'balance_valid_from' : bigint,
+  'balance_witness' : Array<number>,
+  'total_supply_witness' : Array<number>,
+}
+export interface SingleChoiceVote { 'group_id' : GroupId }
 export type Target = { 'Group' : GroupId } |
+  { 'GroupTree' : GroupId } |
   { 'Thresholds' : Array<ThresholdValue> };
+export type TargetMatch = { 'Allowed' : PermissionTarget } |
+  { 'NotMatched' : null } |
+  { 'Denied' : PermissionTarget };
 export type TaskId = Id;
 export type ThresholdValue = { 'FractionOf' : FractionOf } |
   { 'QuantityOf' : QuantityOf };
@@ -617,19 +1012,34 @@ export interface TransferMyGroupSharesRequest {
 export interface UpdateAccessConfigRequest {
   'id' : AccessConfigId,
   'new_description' : [] | [string],
+  'new_multisig' : [] | [[] | [MultisigSettings]],
   'new_allowees' : [] | [Array<AlloweeConstraint>],
+  'new_query_delegation_expiry' : [] | [[] | [bigint]],
   'new_name' : [] | [string],
+  'new_cycles_limits' : [] | [CyclesLimits],
   'new_permissions' : [] | [Array<PermissionId>],
+  'new_validity' : [] | [AccessConfigValidity],
+  'new_quota' : [] | [AccessConfigQuota],
+}
+export interface UpdateComputedGroupRuleRequest {
+  'new_rule' : GroupRule,
+  'group_id' : GroupId,
 }
 export interface UpdateGroupRequest {
   'new_description' : [] | [string],
   'new_name' : [] | [string],
   'group_id' : GroupId,
+  'new_parent' : [] | [[] | [GroupId]],
+  'new_vote_escrow' : [] | [[] | [VoteEscrowSettings]],
 }
 export interface UpdateMyProfileRequest {
   'new_description' : [] | [string],
   'new_name' : [] | [string],
 }
+export interface UpdateMyVotingCommentRequest {
+  'id' : CommentId,
+  'new_text' : string,
+}
 export interface UpdateNestedVotingConfigRequest {
   'id' : NestedVotingConfigId,
   'description_opt' : [] | [string],
@@ -642,6 +1052,7 @@ export interface UpdatePermissionRequest {
   'new_description' : [] | [string],
   'new_targets' : [] | [Array<PermissionTarget>],
   'new_name' : [] | [string],
+  'new_cycles_limits' : [] | [CyclesLimits],
 }
 export interface UpdateProfileRequest {
   'id' : ProfileId,
@@ -650,6 +1061,8 @@ export interface UpdateProfileRequest {
 }
 export interface UpdateSettingsRequest {
   'new_description' : [] | [string],
+  'new_ic_root_key' : [] | [[] | [Array<number>]],
+  'new_cycles_budget' : [] | [[] | [CyclesBudget]],
   'new_name' : [] | [string],
 }
 export interface UpdateVotingChoiceRequest {
@@ -660,15 +1073,18 @@ export interface UpdateVotingChoiceRequest {
 }
 export interface UpdateVotingConfigRequest {
   'id' : VotingConfigId,
+  'allow_abstain_opt' : [] | [boolean],
   'description_opt' : [] | [string],
   'next_round_opt' : [] | [ThresholdValue],
   'name_opt' : [] | [string],
   'quorum_opt' : [] | [ThresholdValue],
+  'proposal_bond_opt' : [] | [[] | [ProposalBond]],
   'approval_opt' : [] | [ThresholdValue],
   'round_opt' : [] | [RoundSettings],
   'choices_count_opt' : [] | [[] | [LenInterval]],
   'winners_count_opt' : [] | [[] | [LenInterval]],
   'rejection_opt' : [] | [ThresholdValue],
+  'proposer_limits_opt' : [] | [ProposerLimits],
   'win_opt' : [] | [ThresholdValue],
   'permissions_opt' : [] | [Array<PermissionId>],
 }
@@ -678,9 +1094,55 @@ export interface UpdateVotingRequest {
   'new_winners_need' : [] | [number],
   'new_name' : [] | [string],
 }
+export interface VestingGrant {
+  'id' : [] | [VestingGrantId],
+  'total' : Shares,
+  'release_task' : [] | [TaskId],
+  'beneficiary' : Principal,
+  'revocable' : boolean,
+  'created_at' : bigint,
+  'revoked_at' : [] | [bigint],
+  'released' : Shares,
+  'group_id' : GroupId,
+  'schedule' : VestingSchedule,
+  'voting_power' : boolean,
+}
+export interface VestingGrantExt { 'it' : VestingGrant, 'vested' : Shares }
+export interface VestingGrantFilter {
+  'beneficiary' : [] | [Principal],
+  'group_id' : [] | [GroupId],
+}
+export type VestingGrantId = Id;
+export interface VestingSchedule {
+  'duration' : bigint,
+  'period' : bigint,
+  'cliff' : bigint,
+  'start' : bigint,
+}
 export type Vote = { 'Rejection' : SingleChoiceVote } |
+  { 'Abstain' : SingleChoiceVote } |
   { 'Approval' : SingleChoiceVote } |
-  { 'Common' : MultiChoiceVote };
+  { 'Common' : CommonVote };
+export interface VoteEscrowLock {
+  'id' : [] | [VoteEscrowLockId],
+  'qty' : Shares,
+  'locked_at' : bigint,
+  'expired' : boolean,
+  'token_id' : TokenId,
+  'unlocks_at' : bigint,
+  'owner' : Principal,
+  'boost' : Shares,
+  'expiration_task' : [] | [TaskId],
+}
+export interface VoteEscrowLockFilter {
+  'token_id' : [] | [TokenId],
+  'owner' : [] | [Principal],
+}
+export type VoteEscrowLockId = Id;
+export interface VoteEscrowSettings {
+  'max_duration' : bigint,
+  'max_multiplier' : Fraction,
+}
 export interface Voting {
   'id' : [] | [VotingId],
   'status' : VotingStatus,
@@ -688,29 +1150,37 @@ export interface Voting {
   'task_id' : [] | [TaskId],
   'approval_choice' : [] | [ChoiceId],
   'name' : string,
+  'shares_info_cache' : Array<[GroupId, Array<[Principal, SharesInfo]>]>,
+  'group_trees' : Array<[GroupId, Array<GroupId>]>,
   'description' : string,
   'losers' : Array<RoundResult>,
   'created_at' : bigint,
   'voting_config_id' : VotingConfigId,
   'rejection_choice' : [] | [ChoiceId],
+  'abstain_choice' : [] | [ChoiceId],
   'proposer' : Principal,
   'winners_need' : number,
   'choices' : Array<ChoiceId>,
+  'proposal_bond' : [] | [LockedProposalBond],
   'winners' : Array<RoundResult>,
   'total_voting_power_by_group' : Array<[GroupId, Shares]>,
+  'vote_rationales' : Array<[GroupId, Array<[Principal, string]>]>,
 }
 export interface VotingConfig {
   'id' : [] | [VotingConfigId],
   'win' : ThresholdValue,
   'winners_count' : [] | [LenInterval],
   'permissions' : Array<PermissionId>,
+  'proposer_limits' : ProposerLimits,
   'name' : string,
   'description' : string,
   'rejection' : ThresholdValue,
   'next_round' : ThresholdValue,
   'choices_count' : [] | [LenInterval],
   'approval' : ThresholdValue,
+  'allow_abstain' : boolean,
   'quorum' : ThresholdValue,
+  'proposal_bond' : [] | [ProposalBond],
   'round' : RoundSettings,
 }
 export interface VotingConfigFilter {
@@ -718,6 +1188,13 @@ export interface VotingConfigFilter {
   'group' : [] | [GroupId],
 }
 export type VotingConfigId = Id;
+export interface VotingFilter {
+  'status' : [] | [VotingStatusFilter],
+  'name' : [] | [string],
+  'created_at' : [] | [CreatedAtRange],
+  'proposer' : [] | [Principal],
+  'voting_config' : [] | [VotingConfigId],
+}
 export type VotingId = Id;
 export type VotingSort = { 'UpdatedAt' : boolean } |
   { 'None' : null } |
@@ -727,10 +1204,17 @@ export type VotingStatus = { 'Fail' : string } |
   { 'Round' : RoundId } |
   { 'Rejected' : null } |
   { 'Success' : null };
+export type VotingStatusFilter = { 'PreRound' : null } |
+  { 'Active' : null } |
+  { 'Finished' : null } |
+  { 'Rejected' : null };
 export interface _SERVICE {
   'accept_my_group_shares' : (arg_0: AcceptMyGroupSharesRequest) => Promise<
       undefined
     >,
+  'approve_multisig_proposal' : (
+      arg_0: ApproveMultisigProposalRequest,
+    ) => Promise<ApproveMultisigProposalResponse>,
   'burn_group_shares' : (arg_0: BurnGroupSharesRequest) => Promise<undefined>,
   'burn_my_group_shares' : (arg_0: BurnMyGroupSharesRequest) => Promise<
       undefined
@@ -738,16 +1222,34 @@ export interface _SERVICE {
   'burn_unaccepted_group_shares' : (arg_0: BurnGroupSharesRequest) => Promise<
       undefined
     >,
+  'cancel_multisig_proposal' : (
+      arg_0: CancelMultisigProposalRequest,
+    ) => Promise<undefined>,
   'cast_my_nested_vote' : (arg_0: CastMyNestedVoteRequest) => Promise<
       undefined
     >,
   'cast_my_vote' : (arg_0: CastMyVoteRequest) => Promise<undefined>,
+  'check_my_execution' : (arg_0: CheckMyExecutionRequest) => Promise<
+      CheckMyExecutionResponse
+    >,
+  'convert_group_to_private' : (arg_0: ConvertGroupRequest) => Promise<
+      undefined
+    >,
+  'convert_group_to_public' : (arg_0: ConvertGroupRequest) => Promise<
+      undefined
+    >,
   'create_access_config' : (arg_0: CreateAccessConfigRequest) => Promise<
       CreateAccessConfigResponse
     >,
   'create_batch' : (arg_0: CreateBatchRequest) => Promise<CreateBatchResponse>,
   'create_chunk' : (arg_0: CreateChunkRequest) => Promise<CreateChunkResponse>,
+  'create_computed_group' : (arg_0: CreateComputedGroupRequest) => Promise<
+      CreateGroupResponse
+    >,
   'create_group' : (arg_0: CreateGroupRequest) => Promise<CreateGroupResponse>,
+  'create_my_voting_comment' : (arg_0: CreateMyVotingCommentRequest) => Promise<
+      CreateMyVotingCommentResponse
+    >,
   'create_nested_voting' : (arg_0: CreateNestedVotingRequest) => Promise<
       CreateNestedVotingResponse
     >,
@@ -757,7 +1259,13 @@ export interface _SERVICE {
   'create_permission' : (arg_0: CreatePermissionRequest) => Promise<
       CreatePermissionResponse
     >,
+  'create_permission_from_template' : (
+      arg_0: CreatePermissionFromTemplateRequest,
+    ) => Promise<CreatePermissionFromTemplateResponse>,
   'create_profile' : (arg_0: CreateProfileRequest) => Promise<undefined>,
+  'create_vesting_grant' : (arg_0: CreateVestingGrantRequest) => Promise<
+      CreateVestingGrantResponse
+    >,
   'create_voting' : (arg_0: CreateVotingRequest) => Promise<
       CreateVotingResponse
     >,
@@ -775,6 +1283,9 @@ export interface _SERVICE {
     >,
   'delete_batches' : (arg_0: DeleteBatchesRequest) => Promise<undefined>,
   'delete_group' : (arg_0: DeleteGroupRequest) => Promise<undefined>,
+  'delete_my_voting_comment' : (arg_0: DeleteMyVotingCommentRequest) => Promise<
+      undefined
+    >,
   'delete_nested_voting' : (arg_0: DeleteNestedVotingRequest) => Promise<
       undefined
     >,
@@ -794,6 +1305,12 @@ export interface _SERVICE {
       undefined
     >,
   'execute' : (arg_0: ExecuteRequest) => Promise<ExecuteResponse>,
+  'explain_endpoint_access' : (arg_0: ExplainEndpointAccessRequest) => Promise<
+      ExplainEndpointAccessResponse
+    >,
+  'explain_principal_access' : (
+      arg_0: ExplainPrincipalAccessRequest,
+    ) => Promise<ExplainPrincipalAccessResponse>,
   'get_access_config' : (arg_0: GetAccessConfigRequest) => Promise<
       GetAccessConfigResponse
     >,
@@ -803,11 +1320,26 @@ export interface _SERVICE {
   'get_group_shares_balance_of' : (
       arg_0: GetGroupSharesBalanceOfRequest,
     ) => Promise<GetGroupSharesBalanceOfResponse>,
+  'get_group_voting_power_of' : (
+      arg_0: GetGroupSharesBalanceOfRequest,
+    ) => Promise<GetGroupSharesBalanceOfResponse>,
   'get_groups_of' : (arg_0: GetGroupsOfRequest) => Promise<GetGroupsResponse>,
+  'get_locked_group_shares_balance_of' : (
+      arg_0: GetGroupSharesBalanceOfRequest,
+    ) => Promise<GetGroupSharesBalanceOfResponse>,
+  'get_multisig_proposal' : (arg_0: GetMultisigProposalRequest) => Promise<
+      GetMultisigProposalResponse
+    >,
   'get_my_group_shares_balance' : (
       arg_0: GetMyGroupSharesBalanceRequest,
     ) => Promise<GetMyGroupSharesBalanceResponse>,
+  'get_my_group_voting_power' : (
+      arg_0: GetMyGroupSharesBalanceRequest,
+    ) => Promise<GetMyGroupSharesBalanceResponse>,
   'get_my_groups' : () => Promise<GetGroupsResponse>,
+  'get_my_locked_group_shares_balance' : (
+      arg_0: GetMyGroupSharesBalanceRequest,
+    ) => Promise<GetMyGroupSharesBalanceResponse>,
   'get_my_nested_vote' : (arg_0: GetMyNestedVoteRequest) => Promise<
       GetMyNestedVoteResponse
     >,
@@ -815,6 +1347,9 @@ export interface _SERVICE {
   'get_my_query_delegation_proof' : (
       arg_0: GetMyQueryDelegationProofRequest,
     ) => Promise<GetMyQueryDelegationProofResponse>,
+  'get_my_remaining_quota' : (arg_0: GetMyRemainingQuotaRequest) => Promise<
+      GetMyRemainingQuotaResponse
+    >,
   'get_my_shares_info_at' : (arg_0: GetMySharesInfoAtRequest) => Promise<
       GetSharesInfoOfAtResponse
     >,
@@ -839,37 +1374,70 @@ export interface _SERVICE {
   'get_total_group_shares' : (arg_0: GetTotalGroupSharesRequest) => Promise<
       GetTotalGroupSharesResponse
     >,
+  'get_total_group_voting_power' : (
+      arg_0: GetTotalGroupSharesRequest,
+    ) => Promise<GetTotalGroupSharesResponse>,
   'get_total_unaccepted_group_shares' : (
       arg_0: GetTotalGroupSharesRequest,
     ) => Promise<GetTotalGroupSharesResponse>,
   'get_unaccepted_group_shares_balance_of' : (
       arg_0: GetGroupSharesBalanceOfRequest,
     ) => Promise<GetGroupSharesBalanceOfResponse>,
+  'get_vesting_grant' : (arg_0: GetVestingGrantRequest) => Promise<
+      GetVestingGrantResponse
+    >,
   'get_voting' : (arg_0: GetVotingRequest) => Promise<GetVotingResponse>,
   'get_voting_choice' : (arg_0: GetVotingChoiceRequest) => Promise<
       GetVotingChoiceResponse
     >,
+  'get_voting_comment' : (arg_0: GetVotingCommentRequest) => Promise<
+      GetVotingCommentResponse
+    >,
   'get_voting_config' : (arg_0: GetVotingConfigRequest) => Promise<
       GetVotingConfigResponse
     >,
   'get_voting_results' : (arg_0: GetVotingResultsRequest) => Promise<
       GetVotingResultsResponse
     >,
+  'issue_my_query_delegation_proof' : (
+      arg_0: IssueMyQueryDelegationProofRequest,
+    ) => Promise<IssueMyQueryDelegationProofResponse>,
   'list_access_configs' : (arg_0: ListAccessConfigsRequest) => Promise<
       ListAccessConfigsResponse
     >,
   'list_batches' : (arg_0: ListBatchesRequest) => Promise<ListBatchesResponse>,
   'list_chunks' : (arg_0: ListChunksRequest) => Promise<ListChunksResponse>,
+  'list_group_ancestors' : (arg_0: ListGroupRelativesRequest) => Promise<
+      ListGroupRelativesResponse
+    >,
+  'list_group_descendants' : (arg_0: ListGroupRelativesRequest) => Promise<
+      ListGroupRelativesResponse
+    >,
   'list_group_shares' : (arg_0: ListGroupSharesRequest) => Promise<
       ListGroupSharesResponse
     >,
   'list_groups' : (arg_0: ListGroupsRequest) => Promise<ListGroupsResponse>,
+  'list_multisig_proposals' : (arg_0: ListMultisigProposalsRequest) => Promise<
+      ListMultisigProposalsResponse
+    >,
+  'list_my_vesting_grants' : (arg_0: ListMyVestingGrantsRequest) => Promise<
+      ListVestingGrantsResponse
+    >,
+  'list_my_vote_escrow_locks' : (
+      arg_0: ListMyVoteEscrowLocksRequest,
+    ) => Promise<ListVoteEscrowLocksResponse>,
+  'list_my_votings' : (arg_0: ListMyVotingsRequest) => Promise<
+      ListVotingsResponse
+    >,
   'list_nested_voting_configs' : (
       arg_0: ListNestedVotingConfigsRequest,
     ) => Promise<ListNestedVotingConfigsResponse>,
   'list_nested_votings' : (arg_0: ListNestedVotingsRequest) => Promise<
       ListNestedVotingsResponse
     >,
+  'list_permission_templates' : (
+      arg_0: ListPermissionTemplatesRequest,
+    ) => Promise<ListPermissionTemplatesResponse>,
   'list_permissions' : (arg_0: ListPermissionsRequest) => Promise<
       ListPermissionsResponse
     >,
@@ -879,18 +1447,48 @@ export interface _SERVICE {
   'list_program_execution_entry_ids' : (
       arg_0: ListProgramExecutionEntryIdsRequest,
     ) => Promise<ListProgramExecutionEntryIdsResponse>,
+  'list_revoked_query_delegates' : (
+      arg_0: ListRevokedQueryDelegatesRequest,
+    ) => Promise<ListRevokedQueryDelegatesResponse>,
   'list_unaccepted_group_shares' : (arg_0: ListGroupSharesRequest) => Promise<
       ListGroupSharesResponse
     >,
+  'list_vesting_grants' : (arg_0: ListVestingGrantsRequest) => Promise<
+      ListVestingGrantsResponse
+    >,
+  'list_vote_escrow_locks' : (arg_0: ListVoteEscrowLocksRequest) => Promise<
+      ListVoteEscrowLocksResponse
+    >,
   'list_voting_choices' : (arg_0: ListVotingChoicesRequest) => Promise<
       ListVotingChoicesResponse
     >,
+  'list_voting_comments' : (arg_0: ListVotingCommentsRequest) => Promise<
+      ListVotingCommentsResponse
+    >,
   'list_voting_configs' : (arg_0: ListVotingConfigsRequest) => Promise<
       ListVotingConfigsResponse
     >,
   'list_votings' : (arg_0: ListVotingsRequest) => Promise<ListVotingsResponse>,
   'lock_batches' : (arg_0: LockBatchesRequest) => Promise<undefined>,
+  'lock_my_group_shares_for_voting_power' : (
+      arg_0: LockMyGroupSharesForVotingPowerRequest,
+    ) => Promise<LockMyGroupSharesForVotingPowerResponse>,
   'mint_group_shares' : (arg_0: MintGroupSharesRequest) => Promise<undefined>,
+  'moderate_voting_comment' : (arg_0: ModerateVotingCommentRequest) => Promise<
+      undefined
+    >,
+  'propose_multisig_program' : (
+      arg_0: ProposeMultisigProgramRequest,
+    ) => Promise<ProposeMultisigProgramResponse>,
+  'reject_multisig_proposal' : (
+      arg_0: RejectMultisigProposalRequest,
+    ) => Promise<undefined>,
+  'revoke_query_delegate' : (arg_0: RevokeQueryDelegateRequest) => Promise<
+      undefined
+    >,
+  'revoke_vesting_grant' : (arg_0: RevokeVestingGrantRequest) => Promise<
+      undefined
+    >,
   'send_batch' : (arg_0: SendBatchRequest) => Promise<undefined>,
   'transfer_group_shares' : (arg_0: TransferGroupSharesRequest) => Promise<
       undefined
@@ -901,8 +1499,14 @@ export interface _SERVICE {
   'update_access_config' : (arg_0: UpdateAccessConfigRequest) => Promise<
       undefined
     >,
+  'update_computed_group_rule' : (
+      arg_0: UpdateComputedGroupRuleRequest,
+    ) => Promise<undefined>,
   'update_group' : (arg_0: UpdateGroupRequest) => Promise<undefined>,
   'update_my_profile' : (arg_0: UpdateMyProfileRequest) => Promise<undefined>,
+  'update_my_voting_comment' : (arg_0: UpdateMyVotingCommentRequest) => Promise<
+      undefined
+    >,
   'update_nested_voting_config' : (
       arg_0: UpdateNestedVotingConfigRequest,
     ) => Promise<undefined>,
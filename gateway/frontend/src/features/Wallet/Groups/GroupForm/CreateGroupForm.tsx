@@ -36,6 +36,7 @@ export const CreateGroupForm = styled(({ ...p }: CreateGroupFormProps) => {
         description: { order: 2, multiline: true },
         private: { order: 3, label: 'Is private?' },
         transferable: { order: 4, label: 'Is dynamic?' },
+        parent: { order: 5, label: 'Parent group' },
       },
     }),
     [],
@@ -61,9 +61,15 @@ export const SubmitWizzard = styled(({ data, unionId, onSuccess, ...p }: SubmitW
     union.canister.list_votings({
       page_req: {
         page_index: 0,
-        page_size: 100, // FIXME need filters by status
+        page_size: 100,
         sort: { UpdatedAt: false },
-        filter: null,
+        filter: {
+          status: [{ Active: null }],
+          name: [],
+          created_at: [],
+          proposer: [],
+          voting_config: [],
+        },
       },
       query_delegation_proof_opt: [],
     });
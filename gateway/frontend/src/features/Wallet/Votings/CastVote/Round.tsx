@@ -20,7 +20,6 @@ import {
   GetVotingResultsResponse,
   Group,
   Shares,
-  SharesInfo,
   Voting,
   VotingConfig,
 } from 'union-ts';
@@ -64,7 +63,7 @@ export interface RoundProps {
   onVoted(vote: [ChoiceId, Shares][]): void;
 }
 
-type Info = { group: Group; group_id: bigint; shares_info: SharesInfo };
+type Info = { group: Group; group_id: bigint; balance: Shares; total_supply: Shares };
 type FormData = { choices: Choice[]; info: Info; fractions: Record<number, number> };
 
 export const Round = styled(
@@ -108,7 +107,6 @@ export const Round = styled(
     const { choices, shareInfos, getShareInfo } = useChoices({
       unionId,
       votingId: voting.id[0]!,
-      at: voting.created_at,
       choiceInfos,
     });
 
@@ -132,16 +130,17 @@ export const Round = styled(
           id: voting.id[0]!,
           vote: {
             Rejection: {
-              shares_info: values.info.shares_info,
+              group_id: values.info.group_id,
             },
           },
+          rationale: [],
         });
       } else {
         await canister.cast_my_vote({
           id: voting.id[0]!,
           vote: {
             Common: {
-              shares_info: values.info.shares_info,
+              group_id: values.info.group_id,
               vote: values.choices.map((c) => {
                 const fraction = values.fractions[Number(c.id[0])] || 0;
 
@@ -149,6 +148,7 @@ export const Round = styled(
               }),
             },
           },
+          rationale: [],
         });
       }
 
@@ -341,15 +341,15 @@ export const Round = styled(
                           {`${String(
                             round(
                               Number(
-                                (BigInt(100) * field.value.shares_info.balance) /
-                                  field.value.shares_info.total_supply,
+                                (BigInt(100) * field.value.balance) /
+                                  field.value.total_supply,
                               ),
                             ),
                           )}% voting power`}
                         </Chips>
                         <Chips variant='caption'>
-                          {`${String(field.value.shares_info.balance)} of ${String(
-                            field.value.shares_info.total_supply,
+                          {`${String(field.value.balance)} of ${String(
+                            field.value.total_supply,
                           )} shares`}
                         </Chips>
                       </OptionContent>
@@ -364,15 +364,15 @@ export const Round = styled(
                           {`${String(
                             round(
                               Number(
-                                (BigInt(100) * field.value.shares_info.balance) /
-                                  field.value.shares_info.total_supply,
+                                (BigInt(100) * field.value.balance) /
+                                  field.value.total_supply,
                               ),
                             ),
                           )}% voting power`}
                         </Chips>
                         <Chips variant='caption'>
-                          {`${String(info.shares_info.balance)} of ${String(
-                            info.shares_info.total_supply,
+                          {`${String(info.balance)} of ${String(
+                            info.total_supply,
                           )} shares`}
                         </Chips>
                       </OptionContent>
@@ -19,7 +19,6 @@ import {
   GetVotingResultsResponse,
   Group,
   Shares,
-  SharesInfo,
   Voting,
   VotingConfig,
 } from 'union-ts';
@@ -58,7 +57,7 @@ export interface Round0Props {
   onVoted(vote: [ChoiceId, Shares][]): void;
 }
 
-type Info = { group: Group; group_id: bigint; shares_info: SharesInfo };
+type Info = { group: Group; group_id: bigint; balance: Shares; total_supply: Shares };
 type FormData = { choice: Choice; info: Info };
 
 export const Round0 = styled(
@@ -88,7 +87,6 @@ export const Round0 = styled(
     const { choices, shareInfos, getShareInfo } = useChoices({
       unionId,
       votingId: voting.id[0]!,
-      at: voting.created_at,
       choiceInfos,
     });
 
@@ -100,18 +98,20 @@ export const Round0 = styled(
           id: voting.id[0]!,
           vote: {
             Approval: {
-              shares_info: values.info.shares_info,
+              group_id: values.info.group_id,
             },
           },
+          rationale: [],
         });
       } else if (values.choice.id[0] == voting.rejection_choice[0]) {
         await canister.cast_my_vote({
           id: voting.id[0]!,
           vote: {
             Rejection: {
-              shares_info: values.info.shares_info,
+              group_id: values.info.group_id,
             },
           },
+          rationale: [],
         });
       } else {
         throw new Error('Wrong choice');
@@ -222,15 +222,15 @@ export const Round0 = styled(
                           {`${String(
                             round(
                               Number(
-                                (BigInt(100) * field.value.shares_info.balance) /
-                                  field.value.shares_info.total_supply,
+                                (BigInt(100) * field.value.balance) /
+                                  field.value.total_supply,
                               ),
                             ),
                           )}% voting power`}
                         </Chips>
                         <Chips variant='caption'>
-                          {`${String(field.value.shares_info.balance)} of ${String(
-                            field.value.shares_info.total_supply,
+                          {`${String(field.value.balance)} of ${String(
+                            field.value.total_supply,
                           )} shares`}
                         </Chips>
                       </OptionContent>
@@ -245,15 +245,15 @@ export const Round0 = styled(
                           {`${String(
                             round(
                               Number(
-                                (BigInt(100) * info.shares_info.balance) /
-                                  info.shares_info.total_supply,
+                                (BigInt(100) * info.balance) /
+                                  info.total_supply,
                               ),
                             ),
                           )}% voting power`}
                         </Chips>
                         <Chips variant='caption'>
-                          {`${String(info.shares_info.balance)} of ${String(
-                            info.shares_info.total_supply,
+                          {`${String(info.balance)} of ${String(
+                            info.total_supply,
                           )} shares`}
                         </Chips>
                       </OptionContent>
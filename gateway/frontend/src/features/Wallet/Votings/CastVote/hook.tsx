@@ -1,19 +1,18 @@
 import React, { useCallback, useEffect, useMemo, useState } from 'react';
 import { Principal } from '@dfinity/principal';
 import { useUnion } from 'services';
-import { Choice, Group, SharesInfo, ThresholdValue } from 'union-ts';
+import { Choice, Group, Shares, ThresholdValue } from 'union-ts';
 import { getGroupsFromThresholds } from './utils';
 
 export interface UseChoicesProps {
   unionId: Principal;
   votingId: bigint;
   choiceInfos: { choice_id: bigint; choice?: Choice; thresholds: ThresholdValue[] }[];
-  at: bigint;
 }
 
-type Info = { group: Group; group_id: bigint; shares_info: SharesInfo };
+type Info = { group: Group; group_id: bigint; balance: Shares; total_supply: Shares };
 
-export const useChoices = ({ unionId, choiceInfos, votingId, at }: UseChoicesProps) => {
+export const useChoices = ({ unionId, choiceInfos, votingId }: UseChoicesProps) => {
   const [shareInfos, setShareInfos] = useState<Info[] | null>(null);
   const [choices, setChoices] = useState<Choice[] | null>(null);
   const { canister } = useUnion(unionId);
@@ -54,17 +53,18 @@ export const useChoices = ({ unionId, choiceInfos, votingId, at }: UseChoicesPro
     Promise.all(
       Array.from(allGroups).map(async (group_id) => {
         const { group } = await canister.get_group({ group_id, query_delegation_proof_opt: [] });
-        const { shares_info } = await canister.get_my_shares_info_at({
+        const { balance } = await canister.get_my_group_voting_power({ group_id });
+        const { total } = await canister.get_total_group_voting_power({
           group_id,
-          at,
+          query_delegation_proof_opt: [],
         });
 
-        return { group: group.it, group_id, shares_info: shares_info[0] };
+        return { group: group.it, group_id, balance, total_supply: total };
       }),
     )
-      .then((shareInfos) => shareInfos.filter((s): s is Info => !!s.shares_info))
+      .then((shareInfos) => shareInfos.filter((s) => !!s.balance))
       .then(setShareInfos);
-  }, [groups, setShareInfos, choiceInfos, at]);
+  }, [groups, setShareInfos, choiceInfos]);
 
   const getShareInfo = useCallback(
     (choiceId: bigint | undefined) => {
@@ -5,7 +5,7 @@ import { PageWrapper, SubmitButton as SB } from '@union/components';
 import { CreateVotingRequest, _SERVICE } from 'union-ts';
 import { Controller } from 'react-hook-form';
 import { useNavigate } from 'react-router-dom';
-import { getMethodAccessVotingConfig, useUnion } from 'services';
+import { getMethodAccessVotingConfig, useAuth, useUnion } from 'services';
 import { EditorSettings, useRender } from '../../../../IDLRenderer';
 import { useUnionSubmit, AnyService } from '../../../../../components/UnionSubmit';
 import { VotingConfigListField } from '../../../IDLFields';
@@ -40,6 +40,7 @@ export function CreateVotingForm({
     onExecuted: (p, res) => nav(`../choices/${res.id.toString()}`, { state: data, replace: true }),
   });
   const { canister } = useUnion(unionId);
+  const { identity } = useAuth();
   const [filterConfigs, setFilterConfigs] = useState<bigint[] | undefined>(undefined);
 
   const { Form } = useRender<CreateVotingRequest>({
@@ -84,6 +85,8 @@ export function CreateVotingForm({
         name: { order: 1, options: { required: 'Field is required' } },
         description: { order: 2, options: { required: 'Field is required' }, multiline: true },
         winners_need: { order: 3 },
+        // votings are only proposed on behalf of the user executing the program
+        proposer: { hide: true },
         voting_config_id: {
           order: 4,
           adornment: {
@@ -118,7 +121,11 @@ export function CreateVotingForm({
         {(ctx) => (
           <SubmitButton
             disabled={!ctx.isValid || !submitProps.isAllowed}
-            onClick={(e) => submitProps.submit(e, [ctx.getValues()])}
+            onClick={(e) =>
+              submitProps.submit(e, [
+                { ...ctx.getValues(), proposer: identity ? [identity.getPrincipal()] : [] },
+              ])
+            }
           >
             Start voting
           </SubmitButton>
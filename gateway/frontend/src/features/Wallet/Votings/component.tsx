@@ -78,7 +78,13 @@ export const Votings = styled(({ ...p }: VotingsProps) => {
             page_req: {
               page_index: index,
               page_size: size,
-              filter: null,
+              filter: {
+                status: [],
+                name: [],
+                created_at: [],
+                proposer: [],
+                voting_config: [],
+              },
               sort: { CreatedAt: false },
             },
             query_delegation_proof_opt: [],
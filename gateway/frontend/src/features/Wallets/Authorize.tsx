@@ -17,7 +17,7 @@ import {
 } from '@union/components';
 import { initUnionController, useAuth, useGateway, unionIdl, _SERVICE } from 'services';
 import { checkPrincipal } from 'toolkit';
-import { GetMyQueryDelegationProofRequest } from 'union-ts';
+import { IssueMyQueryDelegationProofRequest } from 'union-ts';
 import { buildEncoder } from '@union/serialize';
 import { useClient } from '../useClient';
 import { normalizeValues } from '../IDLRenderer';
@@ -76,7 +76,14 @@ export const AuthorizeWallet = () => {
         const union = initUnionController(wallet.toString());
         const encoder = buildEncoder<_SERVICE>(unionIdl, 'retTypes');
 
-        const response = await union.canister.get_my_query_delegation_proof(request);
+        const issued = await union.canister.issue_my_query_delegation_proof({
+          requested_targets: request.requested_targets,
+          expires_in_opt: request.expires_in_opt || [],
+        });
+        // only query calls return the proof with a certificate
+        const response = await union.canister.get_my_query_delegation_proof({
+          id: issued.proof.id,
+        });
 
         const encoded = encoder.get_my_query_delegation_proof(response);
 
@@ -231,7 +238,7 @@ const parser = (p: any) => {
     !!payload.request.requested_targets.length &&
     ('SelfEmptyProgram' in payload.request.requested_targets[0] ||
       'Endpoint' in payload.request.requested_targets[0])
-      ? (payload.request as GetMyQueryDelegationProofRequest)
+      ? (payload.request as IssueMyQueryDelegationProofRequest)
       : null;
 
   return { principal, request };
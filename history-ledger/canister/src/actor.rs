@@ -1,4 +1,5 @@
 use crate::repository::{set_repositories, take_repositories};
+use crate::service::certification::CertificationService;
 use crate::service::cron::CronService;
 use candid::Principal;
 use ic_cdk::storage::{stable_restore, stable_save};
@@ -27,6 +28,8 @@ fn post_upgrade_hook() {
 
     set_repositories(repos);
     _put_cron_state(cron);
+
+    CertificationService::rebuild();
 }
 
 #[pre_upgrade]
@@ -22,6 +22,7 @@ fn get_program_execution_entry_meta(
     GetProgramExecutionEntryMetaResponse { 
         initiator: entry.get_initiator(),
         program_executed_with: entry.get_with(),
+        approvers: entry.get_approvers(),
     }
 }
 
@@ -10,6 +10,8 @@ pub struct ProgramExecutionEntry {
     timestamp: u64,
     initiator: Principal,
     program_executed_with: ProgramExecutedWith,
+    // none for entries created before approvers were tracked
+    approvers: Option<Vec<Principal>>,
     pub program: Option<Program>,
     pub result: Option<ProgramExecutionResult>,
 }
@@ -21,6 +23,7 @@ impl ProgramExecutionEntry {
             timestamp: ev.timestamp,
             initiator: ev.initiator,
             program_executed_with: ev.with,
+            approvers: None,
             program: None,
             result: None,
         }
@@ -33,6 +36,13 @@ impl ProgramExecutionEntry {
     pub fn get_initiator(&self) -> Principal { self.initiator }
     
     pub fn get_with(&self) -> ProgramExecutedWith { self.program_executed_with }
+
+    pub fn get_approvers(&self) -> Vec<Principal> { self.approvers.clone().unwrap_or_default() }
+
+    pub fn set_approvers(&mut self, approvers: Vec<Principal>) {
+        assert!(self.approvers.is_none());
+        self.approvers = Some(approvers);
+    }
     
     pub fn set_program(&mut self, program: Program) {
         assert!(self.program.is_none());
@@ -4,6 +4,7 @@ use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::{IdGenerator, Model, Repository};
 use shared::pageable::{Page, PageRequest, Pageable};
 use shared::sorted_by_timestamp::SortedByTimestamp;
+use shared::types::history_ledger::SharesSnapshot;
 use shared::types::wallet::{GroupId, Shareholder, Shares};
 use std::collections::HashMap;
 
@@ -105,6 +106,64 @@ impl SharesMoveRepository {
         }
     }
 
+    pub fn balance_snapshot_of_at(
+        &self,
+        group_id: GroupId,
+        of: Principal,
+        timestamp: u64,
+    ) -> Option<(u64, SharesSnapshot)> {
+        let sorted = self.records_by_timestamp_index.get(&(group_id, of))?;
+        let valid_from = sorted.most_actual_timestamp_by(&timestamp)?;
+        let value = self.entry_of_at(group_id, of, valid_from)?.balance_of(of)?;
+
+        let snapshot = SharesSnapshot {
+            value,
+            valid_to: sorted.next_timestamp_after(&valid_from),
+        };
+
+        Some((valid_from, snapshot))
+    }
+
+    pub fn total_supply_snapshot_at(
+        &self,
+        group_id: &GroupId,
+        timestamp: u64,
+    ) -> Option<(u64, SharesSnapshot)> {
+        let sorted = self.total_supplies_by_timestamp_index.get(group_id)?;
+        let valid_from = sorted.most_actual_timestamp_by(&timestamp)?;
+
+        let snapshot = SharesSnapshot {
+            value: self.total_supply_at(group_id, valid_from),
+            valid_to: sorted.next_timestamp_after(&valid_from),
+        };
+
+        Some((valid_from, snapshot))
+    }
+
+    pub fn list_balance_snapshot_timestamps(&self) -> Vec<(GroupId, Principal, u64)> {
+        self.records_by_timestamp_index
+            .iter()
+            .flat_map(|((group_id, principal), sorted)| {
+                sorted
+                    .get_timestamps()
+                    .into_iter()
+                    .map(move |timestamp| (*group_id, *principal, timestamp))
+            })
+            .collect()
+    }
+
+    pub fn list_total_supply_snapshot_timestamps(&self) -> Vec<(GroupId, u64)> {
+        self.total_supplies_by_timestamp_index
+            .iter()
+            .flat_map(|(group_id, sorted)| {
+                sorted
+                    .get_timestamps()
+                    .into_iter()
+                    .map(move |timestamp| (*group_id, timestamp))
+            })
+            .collect()
+    }
+
     fn add_to_timestamp_index(
         &mut self,
         group_id: GroupId,
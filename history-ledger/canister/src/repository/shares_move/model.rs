@@ -1,5 +1,5 @@
 use crate::repository::shares_move::types::SharesMoveEntryId;
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::Model;
 use shared::types::wallet::{GroupId, Shareholder, Shares, SharesMoveEvent};
 
@@ -44,6 +44,22 @@ impl SharesMoveEntry {
     pub fn get_qty(&self) -> &Shares {
         &self.qty
     }
+
+    pub fn balance_of(&self, of: Principal) -> Option<Shares> {
+        if let Shareholder::Principal(ps) = self.get_from() {
+            if ps.principal_id == of {
+                return Some(ps.new_balance.clone());
+            }
+        }
+
+        if let Shareholder::Principal(ps) = self.get_to() {
+            if ps.principal_id == of {
+                return Some(ps.new_balance.clone());
+            }
+        }
+
+        None
+    }
 }
 
 impl Model<SharesMoveEntryId> for SharesMoveEntry {
@@ -0,0 +1,128 @@
+use crate::repository::shares_move::model::SharesMoveEntry;
+use candid::Principal;
+use ic_cdk::api::{data_certificate, set_certified_data};
+use ic_certified_map::{AsHashTree, RbTree};
+use shared::mvc::HasRepository;
+use shared::types::history_ledger::{
+    balance_snapshot_key, total_supply_snapshot_key, SharesInfo, SharesInfoSignature,
+};
+use shared::types::wallet::{GroupId, Shareholder};
+use shared::types::Blob;
+
+// not persisted - rebuilt from the repository after each upgrade
+static mut CERTIFIED_SNAPSHOTS: Option<RbTree<Blob, Blob>> = None;
+
+fn get_certified_snapshots() -> &'static mut RbTree<Blob, Blob> {
+    unsafe {
+        match CERTIFIED_SNAPSHOTS.as_mut() {
+            Some(t) => t,
+            None => {
+                CERTIFIED_SNAPSHOTS = Some(RbTree::new());
+                get_certified_snapshots()
+            }
+        }
+    }
+}
+
+pub struct CertificationService;
+
+impl CertificationService {
+    pub fn certify_shares_move(entry: &SharesMoveEntry) {
+        if let Shareholder::Principal(ps) = entry.get_from() {
+            Self::certify_balance(entry.get_group_id(), ps.principal_id, entry.get_timestamp());
+        }
+
+        if let Shareholder::Principal(ps) = entry.get_to() {
+            Self::certify_balance(entry.get_group_id(), ps.principal_id, entry.get_timestamp());
+        }
+    }
+
+    // certifies the snapshot starting at the timestamp and re-certifies the previous one,
+    // since its validity interval is now closed by the new snapshot
+    pub fn certify_balance(group_id: GroupId, of: Principal, timestamp: u64) {
+        if let Some(prev) = timestamp.checked_sub(1) {
+            Self::certify_balance_at(group_id, of, prev);
+        }
+
+        Self::certify_balance_at(group_id, of, timestamp);
+    }
+
+    pub fn certify_total_supply(group_id: GroupId, timestamp: u64) {
+        if let Some(prev) = timestamp.checked_sub(1) {
+            Self::certify_total_supply_at(group_id, prev);
+        }
+
+        Self::certify_total_supply_at(group_id, timestamp);
+    }
+
+    pub fn update_certified_data() {
+        set_certified_data(&get_certified_snapshots().root_hash());
+    }
+
+    pub fn rebuild() {
+        unsafe { CERTIFIED_SNAPSHOTS = Some(RbTree::new()) };
+
+        for (group_id, of, timestamp) in SharesMoveEntry::repo().list_balance_snapshot_timestamps()
+        {
+            Self::certify_balance_at(group_id, of, timestamp);
+        }
+
+        for (group_id, timestamp) in SharesMoveEntry::repo().list_total_supply_snapshot_timestamps()
+        {
+            Self::certify_total_supply_at(group_id, timestamp);
+        }
+
+        Self::update_certified_data();
+    }
+
+    // the certificate is only available for non-replicated query calls
+    pub fn sign_shares_info(info: &SharesInfo) -> Option<SharesInfoSignature> {
+        let certificate = data_certificate()?;
+
+        let (balance_valid_from, _) = SharesMoveEntry::repo().balance_snapshot_of_at(
+            info.group_id,
+            info.principal_id,
+            info.timestamp,
+        )?;
+        let (total_supply_valid_from, _) =
+            SharesMoveEntry::repo().total_supply_snapshot_at(&info.group_id, info.timestamp)?;
+
+        let balance_key =
+            balance_snapshot_key(info.group_id, info.principal_id, balance_valid_from);
+        let total_supply_key = total_supply_snapshot_key(info.group_id, total_supply_valid_from);
+
+        Some(SharesInfoSignature {
+            certificate,
+            balance_valid_from,
+            balance_witness: Self::witness(&balance_key),
+            total_supply_valid_from,
+            total_supply_witness: Self::witness(&total_supply_key),
+        })
+    }
+
+    fn certify_balance_at(group_id: GroupId, of: Principal, timestamp: u64) {
+        if let Some((valid_from, snapshot)) =
+            SharesMoveEntry::repo().balance_snapshot_of_at(group_id, of, timestamp)
+        {
+            get_certified_snapshots().insert(
+                balance_snapshot_key(group_id, of, valid_from),
+                snapshot.encode(),
+            );
+        }
+    }
+
+    fn certify_total_supply_at(group_id: GroupId, timestamp: u64) {
+        if let Some((valid_from, snapshot)) =
+            SharesMoveEntry::repo().total_supply_snapshot_at(&group_id, timestamp)
+        {
+            get_certified_snapshots().insert(
+                total_supply_snapshot_key(group_id, valid_from),
+                snapshot.encode(),
+            );
+        }
+    }
+
+    fn witness(key: &[u8]) -> Blob {
+        serde_cbor::to_vec(&get_certified_snapshots().witness(key)).unwrap()
+    }
+}
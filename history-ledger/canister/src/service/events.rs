@@ -1,5 +1,6 @@
 use crate::repository::program_execution::model::ProgramExecutionEntry;
 use crate::repository::shares_move::model::SharesMoveEntry;
+use crate::service::certification::CertificationService;
 use candid::Principal;
 use ic_cdk::print;
 use ic_event_hub::api::IEventHubClient;
@@ -8,7 +9,8 @@ use shared::mvc::{HasRepository, Repository};
 use shared::types::wallet::{
     ProgramExecutedEvent_0, ProgramExecutedEvent_0Filter, ProgramExecutedEvent_1,
     ProgramExecutedEvent_1Filter, ProgramExecutedEvent_2, ProgramExecutedEvent_2Filter,
-    SharesMoveEvent, SharesMoveEventFilter, TotalSupplyUpdatedEvent, TotalSupplyUpdatedEventFilter,
+    ProgramExecutedEvent_3, ProgramExecutedEvent_3Filter, SharesMoveEvent, SharesMoveEventFilter,
+    TotalSupplyUpdatedEvent, TotalSupplyUpdatedEventFilter,
 };
 
 pub struct EventsService;
@@ -20,6 +22,7 @@ impl EventsService {
         let f3 = ProgramExecutedEvent_1Filter {};
         let f4 = ProgramExecutedEvent_2Filter {};
         let f5 = TotalSupplyUpdatedEventFilter {};
+        let f6 = ProgramExecutedEvent_3Filter {};
 
         // Warning! Method name should follow the name of the CONTROLLER method
         wallet_id
@@ -45,6 +48,10 @@ impl EventsService {
                         filter: f5.to_event_filter(),
                         method_name: String::from("process_events"),
                     },
+                    CallbackInfo {
+                        filter: f6.to_event_filter(),
+                        method_name: String::from("process_events"),
+                    },
                 ],
             })
             .await
@@ -60,7 +67,8 @@ impl EventsService {
                     let ev: SharesMoveEvent = SharesMoveEvent::from_event(event);
                     let it = SharesMoveEntry::from_event(ev);
 
-                    SharesMoveEntry::repo().save(it);
+                    SharesMoveEntry::repo().save(it.clone());
+                    CertificationService::certify_shares_move(&it);
                 }
                 "TotalSupplyUpdatedEvent" => {
                     let ev: TotalSupplyUpdatedEvent = TotalSupplyUpdatedEvent::from_event(event);
@@ -70,6 +78,7 @@ impl EventsService {
                         ev.total_supply,
                         ev.timestamp,
                     );
+                    CertificationService::certify_total_supply(ev.group_id, ev.timestamp);
                 }
                 "ProgramExecutedEvent_0" => {
                     let ev: ProgramExecutedEvent_0 = ProgramExecutedEvent_0::from_event(event);
@@ -102,8 +111,23 @@ impl EventsService {
                         ))
                     }
                 }
+                "ProgramExecutedEvent_3" => {
+                    let ev: ProgramExecutedEvent_3 = ProgramExecutedEvent_3::from_event(event);
+
+                    if let Some(mut it) = ProgramExecutionEntry::repo().get(&ev.timestamp) {
+                        it.set_approvers(ev.approvers);
+                        ProgramExecutionEntry::repo().save(it);
+                    } else {
+                        print(format!(
+                            "ERROR: Unable to find a previous event for {}",
+                            ev.timestamp
+                        ))
+                    }
+                }
                 _ => print(format!("Unknown event {:?}", event)),
             }
         }
+
+        CertificationService::update_certified_data();
     }
 }
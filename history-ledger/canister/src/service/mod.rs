@@ -1,3 +1,4 @@
+pub mod certification;
 pub mod cron;
 pub mod events;
 pub mod program_execution;
@@ -1,8 +1,9 @@
 use crate::repository::shares_move::model::SharesMoveEntry;
+use crate::service::certification::CertificationService;
 use candid::Principal;
 use shared::mvc::HasRepository;
 use shared::types::history_ledger::SharesInfo;
-use shared::types::wallet::{GroupId, Shareholder};
+use shared::types::wallet::GroupId;
 
 pub struct SharesMoveService;
 
@@ -11,32 +12,18 @@ impl SharesMoveService {
         let entry = SharesMoveEntry::repo().entry_of_at(group_id, of, at)?;
         let total_supply = SharesMoveEntry::repo().total_supply_at(&group_id, at);
 
-        if let Shareholder::Principal(ps) = entry.get_from() {
-            if ps.principal_id == of {
-                return Some(SharesInfo {
-                    balance: ps.new_balance.clone(),
-                    total_supply,
-                    group_id,
-                    principal_id: of,
-                    timestamp: at,
-                    signature: (),
-                });
-            }
-        }
+        let balance = entry.balance_of(of).unwrap();
 
-        if let Shareholder::Principal(ps) = entry.get_to() {
-            if ps.principal_id == of {
-                return Some(SharesInfo {
-                    balance: ps.new_balance.clone(),
-                    total_supply,
-                    timestamp: at,
-                    group_id,
-                    principal_id: of,
-                    signature: (),
-                });
-            }
-        }
+        let mut info = SharesInfo {
+            balance,
+            total_supply,
+            timestamp: at,
+            group_id,
+            principal_id: of,
+            signature: None,
+        };
+        info.signature = CertificationService::sign_shares_info(&info);
 
-        unreachable!();
+        Some(info)
     }
 }
@@ -46,6 +46,7 @@ pub struct GetProgramExecutionEntryMetaRequest {
 pub struct GetProgramExecutionEntryMetaResponse {
     pub initiator: Principal,
     pub program_executed_with: ProgramExecutedWith,
+    pub approvers: Vec<Principal>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -161,6 +161,7 @@ const auth = async () => {
             Endpoint: { canister_id: Principal.from('aaaaa-aa'), method_name: 'test' },
           },
         ],
+        expires_in_opt: [],
       },
     },
     { after: 'close' },
@@ -1,6 +1,8 @@
 export const idlFactory = ({ IDL }) => {
+  const AlloweeConstraint = IDL.Rec();
   const ThresholdValue = IDL.Rec();
   const InitRequest = IDL.Record({
+    'ic_root_key' : IDL.Opt(IDL.Vec(IDL.Nat8)),
     'union_description' : IDL.Text,
     'wallet_creator' : IDL.Principal,
     'union_name' : IDL.Text,
@@ -13,6 +15,30 @@ export const idlFactory = ({ IDL }) => {
     'qty' : Shares,
     'group_id' : GroupId,
   });
+  const MultisigProposalId = Id;
+  const ApproveMultisigProposalRequest = IDL.Record({
+    'id' : MultisigProposalId,
+  });
+  const CandidRejectionCode = IDL.Variant({
+    'NoError' : IDL.Null,
+    'CanisterError' : IDL.Null,
+    'SysTransient' : IDL.Null,
+    'DestinationInvalid' : IDL.Null,
+    'Unknown' : IDL.Null,
+    'SysFatal' : IDL.Null,
+    'CanisterReject' : IDL.Null,
+  });
+  const RawCandidCallResult = IDL.Variant({
+    'Ok' : IDL.Vec(IDL.Nat8),
+    'Err' : IDL.Tuple(CandidRejectionCode, IDL.Text),
+  });
+  const ProgramExecutionResult = IDL.Variant({
+    'Empty' : IDL.Null,
+    'RemoteCallSequence' : IDL.Vec(RawCandidCallResult),
+  });
+  const ApproveMultisigProposalResponse = IDL.Record({
+    'result' : IDL.Opt(ProgramExecutionResult),
+  });
   const BurnGroupSharesRequest = IDL.Record({
     'qty' : Shares,
     'owner' : IDL.Principal,
@@ -22,11 +48,21 @@ export const idlFactory = ({ IDL }) => {
     'qty' : Shares,
     'group_id' : GroupId,
   });
+  const CancelMultisigProposalRequest = IDL.Record({
+    'id' : MultisigProposalId,
+  });
   const NestedVotingId = Id;
   const ChoiceId = Id;
   const Fraction = IDL.Text;
+  const SharesInfoSignature = IDL.Record({
+    'certificate' : IDL.Vec(IDL.Nat8),
+    'total_supply_valid_from' : IDL.Nat64,
+    'balance_valid_from' : IDL.Nat64,
+    'balance_witness' : IDL.Vec(IDL.Nat8),
+    'total_supply_witness' : IDL.Vec(IDL.Nat8),
+  });
   const SharesInfo = IDL.Record({
-    'signature' : IDL.Null,
+    'signature' : IDL.Opt(SharesInfoSignature),
     'balance' : Shares,
     'group_id' : GroupId,
     'timestamp' : IDL.Nat64,
@@ -42,28 +78,158 @@ export const idlFactory = ({ IDL }) => {
     'vote' : MultiChoiceVote,
   });
   const VotingId = Id;
-  const SingleChoiceVote = IDL.Record({ 'shares_info' : SharesInfo });
+  const SingleChoiceVote = IDL.Record({ 'group_id' : GroupId });
+  const CommonVote = IDL.Record({
+    'vote' : IDL.Vec(IDL.Tuple(ChoiceId, Fraction)),
+    'group_id' : GroupId,
+  });
   const Vote = IDL.Variant({
     'Rejection' : SingleChoiceVote,
+    'Abstain' : SingleChoiceVote,
     'Approval' : SingleChoiceVote,
-    'Common' : MultiChoiceVote,
+    'Common' : CommonVote,
+  });
+  const CastMyVoteRequest = IDL.Record({
+    'id' : VotingId,
+    'vote' : Vote,
+    'rationale' : IDL.Opt(IDL.Text),
+  });
+  const AccessConfigId = Id;
+  const RemoteCallEndpoint = IDL.Record({
+    'canister_id' : IDL.Principal,
+    'method_name' : IDL.Text,
+  });
+  const RemoteCallArgs = IDL.Variant({
+    'CandidString' : IDL.Vec(IDL.Text),
+    'Encoded' : IDL.Vec(IDL.Nat8),
+  });
+  const RemoteCallPayload = IDL.Record({
+    'endpoint' : RemoteCallEndpoint,
+    'args' : RemoteCallArgs,
+    'cycles' : IDL.Nat64,
+  });
+  const Program = IDL.Variant({
+    'Empty' : IDL.Null,
+    'RemoteCallSequence' : IDL.Vec(RemoteCallPayload),
+  });
+  const CheckMyExecutionRequest = IDL.Record({
+    'access_config_id' : AccessConfigId,
+    'program' : Program,
+  });
+  const RemainingQuota = IDL.Record({
+    'period_ends_at' : IDL.Opt(IDL.Nat64),
+    'total_executions' : IDL.Opt(IDL.Nat64),
+    'executions_in_period' : IDL.Opt(IDL.Nat64),
+    'next_execution_at' : IDL.Opt(IDL.Nat64),
+  });
+  const ExecutionCheckFailure = IDL.Variant({
+    'CyclesBudgetExceeded' : IDL.Null,
+    'TypecheckFailed' : IDL.Text,
+    'InvalidProgram' : IDL.Text,
+    'InvalidProposer' : IDL.Text,
+    'MultisigApprovalRequired' : IDL.Null,
+    'CallerNotAllowed' : IDL.Null,
+    'ExecutionQuotaExceeded' : RemainingQuota,
+    'ProgramNotAllowedByAccessConfig' : IDL.Null,
+    'CyclesLimitExceeded' : IDL.Null,
+    'AccessConfigInactive' : IDL.Null,
   });
-  const CastMyVoteRequest = IDL.Record({ 'id' : VotingId, 'vote' : Vote });
   const PermissionId = Id;
+  const ArgSelector = IDL.Record({
+    'path' : IDL.Vec(IDL.Text),
+    'arg_index' : IDL.Nat32,
+  });
+  const ArgValue = IDL.Variant({
+    'Bool' : IDL.Bool,
+    'Text' : IDL.Text,
+    'Principal' : IDL.Principal,
+    'Number' : IDL.Int,
+  });
+  const ArgPredicate = IDL.Variant({
+    'PrincipalIn' : IDL.Tuple(ArgSelector, IDL.Vec(IDL.Principal)),
+    'Equals' : IDL.Tuple(ArgSelector, ArgValue),
+    'AtLeast' : IDL.Tuple(ArgSelector, IDL.Int),
+    'AtMost' : IDL.Tuple(ArgSelector, IDL.Int),
+  });
+  const ConstrainedEndpoint = IDL.Record({
+    'predicates' : IDL.Vec(ArgPredicate),
+    'endpoint' : RemoteCallEndpoint,
+  });
+  const PermissionTarget = IDL.Variant({
+    'Endpoint' : RemoteCallEndpoint,
+    'Deny' : RemoteCallEndpoint,
+    'ConstrainedEndpoint' : ConstrainedEndpoint,
+    'SelfEmptyProgram' : IDL.Null,
+  });
+  const TargetMatch = IDL.Variant({
+    'Allowed' : PermissionTarget,
+    'NotMatched' : IDL.Null,
+    'Denied' : PermissionTarget,
+  });
+  const CallCheck = IDL.Record({
+    'endpoint' : RemoteCallEndpoint,
+    'verdicts' : IDL.Vec(IDL.Tuple(PermissionId, TargetMatch)),
+  });
   const GroupCondition = IDL.Record({ 'id' : GroupId, 'min_shares' : Shares });
   const ProfileId = IDL.Principal;
-  const AlloweeConstraint = IDL.Variant({
-    'Group' : GroupCondition,
-    'Profile' : ProfileId,
-    'Everyone' : IDL.Null,
+  AlloweeConstraint.fill(
+    IDL.Variant({
+      'Or' : IDL.Vec(AlloweeConstraint),
+      'And' : IDL.Vec(AlloweeConstraint),
+      'Not' : AlloweeConstraint,
+      'Group' : GroupCondition,
+      'GroupTree' : GroupCondition,
+      'Profile' : ProfileId,
+      'Everyone' : IDL.Null,
+    })
+  );
+  const ExecutionCheck = IDL.Record({
+    'failure' : IDL.Opt(ExecutionCheckFailure),
+    'calls' : IDL.Vec(CallCheck),
+    'allowing_permission' : IDL.Opt(PermissionId),
+    'satisfied_allowee' : IDL.Opt(AlloweeConstraint),
+  });
+  const CheckMyExecutionResponse = IDL.Record({ 'check' : ExecutionCheck });
+  const ConvertGroupRequest = IDL.Record({ 'group_id' : GroupId });
+  const RecurringWindow = IDL.Record({
+    'duration' : IDL.Nat64,
+    'period' : IDL.Nat64,
+    'offset' : IDL.Nat64,
+  });
+  const AccessConfigValidity = IDL.Record({
+    'not_before' : IDL.Opt(IDL.Nat64),
+    'recurring_windows' : IDL.Vec(RecurringWindow),
+    'not_after' : IDL.Opt(IDL.Nat64),
+  });
+  const CyclesLimits = IDL.Record({
+    'max_per_call' : IDL.Opt(IDL.Nat64),
+    'max_per_program' : IDL.Opt(IDL.Nat64),
+  });
+  const PeriodicLimit = IDL.Record({
+    'max_executions' : IDL.Nat64,
+    'period' : IDL.Nat64,
+  });
+  const AccessConfigQuota = IDL.Record({
+    'max_total_executions' : IDL.Opt(IDL.Nat64),
+    'per_principal' : IDL.Opt(PeriodicLimit),
+    'min_interval' : IDL.Opt(IDL.Nat64),
+  });
+  const MultisigSettings = IDL.Record({
+    'threshold' : IDL.Nat32,
+    'veto_threshold' : IDL.Opt(IDL.Nat32),
+    'timeout' : IDL.Nat64,
   });
   const CreateAccessConfigRequest = IDL.Record({
+    'validity' : AccessConfigValidity,
     'permissions' : IDL.Vec(PermissionId),
+    'query_delegation_expiry' : IDL.Opt(IDL.Nat64),
+    'cycles_limits' : CyclesLimits,
     'name' : IDL.Text,
+    'quota' : AccessConfigQuota,
     'description' : IDL.Text,
+    'multisig' : IDL.Opt(MultisigSettings),
     'allowees' : IDL.Vec(AlloweeConstraint),
   });
-  const AccessConfigId = Id;
   const CreateAccessConfigResponse = IDL.Record({ 'id' : AccessConfigId });
   const Key = IDL.Text;
   const CreateBatchRequest = IDL.Record({
@@ -78,13 +244,31 @@ export const idlFactory = ({ IDL }) => {
   });
   const ChunkId = Id;
   const CreateChunkResponse = IDL.Record({ 'chunk_id' : ChunkId });
+  const GroupRule = IDL.Variant({
+    'Union' : IDL.Vec(GroupCondition),
+    'Intersection' : IDL.Vec(GroupCondition),
+    'WeightedSum' : IDL.Vec(IDL.Tuple(GroupId, Fraction)),
+  });
+  const CreateComputedGroupRequest = IDL.Record({
+    'name' : IDL.Text,
+    'rule' : GroupRule,
+    'description' : IDL.Text,
+  });
+  const CreateGroupResponse = IDL.Record({ 'group_id' : GroupId });
   const CreateGroupRequest = IDL.Record({
     'transferable' : IDL.Bool,
     'name' : IDL.Text,
     'description' : IDL.Text,
     'private' : IDL.Bool,
+    'parent' : IDL.Opt(GroupId),
   });
-  const CreateGroupResponse = IDL.Record({ 'group_id' : GroupId });
+  const CommentId = Id;
+  const CreateMyVotingCommentRequest = IDL.Record({
+    'text' : IDL.Text,
+    'voting_id' : VotingId,
+    'parent_id' : IDL.Opt(CommentId),
+  });
+  const CreateMyVotingCommentResponse = IDL.Record({ 'id' : CommentId });
   const RemoteVotingId = IDL.Variant({
     'Nested' : NestedVotingId,
     'Common' : VotingId,
@@ -116,45 +300,54 @@ export const idlFactory = ({ IDL }) => {
   const CreateNestedVotingConfigResponse = IDL.Record({
     'id' : NestedVotingConfigId,
   });
-  const RemoteCallEndpoint = IDL.Record({
-    'canister_id' : IDL.Principal,
-    'method_name' : IDL.Text,
-  });
-  const PermissionTarget = IDL.Variant({
-    'Endpoint' : RemoteCallEndpoint,
-    'SelfEmptyProgram' : IDL.Null,
-  });
   const CreatePermissionRequest = IDL.Record({
+    'cycles_limits' : CyclesLimits,
     'name' : IDL.Text,
     'description' : IDL.Text,
     'targets' : IDL.Vec(PermissionTarget),
   });
   const CreatePermissionResponse = IDL.Record({ 'id' : PermissionId });
+  const PermissionTemplate = IDL.Variant({
+    'ReadOnly' : IDL.Null,
+    'VotingAdmin' : IDL.Null,
+    'GroupAdmin' : IDL.Null,
+    'StreamingAdmin' : IDL.Null,
+  });
+  const CreatePermissionFromTemplateRequest = IDL.Record({
+    'template' : PermissionTemplate,
+  });
+  const CreatePermissionFromTemplateResponse = IDL.Record({
+    'id' : PermissionId,
+  });
   const CreateProfileRequest = IDL.Record({
     'id' : ProfileId,
     'name' : IDL.Text,
     'description' : IDL.Text,
   });
+  const VestingSchedule = IDL.Record({
+    'duration' : IDL.Nat64,
+    'period' : IDL.Nat64,
+    'cliff' : IDL.Nat64,
+    'start' : IDL.Nat64,
+  });
+  const CreateVestingGrantRequest = IDL.Record({
+    'qty' : Shares,
+    'beneficiary' : IDL.Principal,
+    'revocable' : IDL.Bool,
+    'group_id' : GroupId,
+    'schedule' : VestingSchedule,
+    'voting_power' : IDL.Bool,
+  });
+  const VestingGrantId = Id;
+  const CreateVestingGrantResponse = IDL.Record({ 'id' : VestingGrantId });
   const CreateVotingRequest = IDL.Record({
     'name' : IDL.Text,
     'description' : IDL.Text,
     'voting_config_id' : VotingConfigId,
+    'proposer' : IDL.Opt(IDL.Principal),
     'winners_need' : IDL.Nat32,
   });
   const CreateVotingResponse = IDL.Record({ 'id' : VotingId });
-  const RemoteCallArgs = IDL.Variant({
-    'CandidString' : IDL.Vec(IDL.Text),
-    'Encoded' : IDL.Vec(IDL.Nat8),
-  });
-  const RemoteCallPayload = IDL.Record({
-    'endpoint' : RemoteCallEndpoint,
-    'args' : RemoteCallArgs,
-    'cycles' : IDL.Nat64,
-  });
-  const Program = IDL.Variant({
-    'Empty' : IDL.Null,
-    'RemoteCallSequence' : IDL.Vec(RemoteCallPayload),
-  });
   const CreateVotingChoiceRequest = IDL.Record({
     'name' : IDL.Text,
     'description' : IDL.Text,
@@ -164,6 +357,7 @@ export const idlFactory = ({ IDL }) => {
   const CreateVotingChoiceResponse = IDL.Record({ 'choice_id' : ChoiceId });
   const Target = IDL.Variant({
     'Group' : GroupId,
+    'GroupTree' : GroupId,
     'Thresholds' : IDL.Vec(ThresholdValue),
   });
   const FractionOf = IDL.Record({ 'fraction' : Fraction, 'target' : Target });
@@ -172,6 +366,23 @@ export const idlFactory = ({ IDL }) => {
     IDL.Variant({ 'FractionOf' : FractionOf, 'QuantityOf' : QuantityOf })
   );
   const LenInterval = IDL.Record({ 'max' : IDL.Nat32, 'min' : IDL.Nat32 });
+  const ProposerRateLimit = IDL.Record({
+    'window' : IDL.Nat64,
+    'max_votings' : IDL.Nat32,
+  });
+  const ProposerLimits = IDL.Record({
+    'max_active_votings' : IDL.Opt(IDL.Nat32),
+    'rate_limit' : IDL.Opt(ProposerRateLimit),
+  });
+  const RejectedBondAction = IDL.Variant({
+    'Burn' : IDL.Null,
+    'SendToTreasury' : IDL.Null,
+  });
+  const ProposalBond = IDL.Record({
+    'group_id' : GroupId,
+    'on_rejection' : RejectedBondAction,
+    'quantity' : Shares,
+  });
   const RoundSettings = IDL.Record({
     'round_delay' : IDL.Nat64,
     'round_duration' : IDL.Nat64,
@@ -180,13 +391,16 @@ export const idlFactory = ({ IDL }) => {
     'win' : ThresholdValue,
     'winners_count' : IDL.Opt(LenInterval),
     'permissions' : IDL.Vec(PermissionId),
+    'proposer_limits' : ProposerLimits,
     'name' : IDL.Text,
     'description' : IDL.Text,
     'rejection' : ThresholdValue,
     'next_round' : ThresholdValue,
     'choices_count' : IDL.Opt(LenInterval),
     'approval' : ThresholdValue,
+    'allow_abstain' : IDL.Bool,
     'quorum' : ThresholdValue,
+    'proposal_bond' : IDL.Opt(ProposalBond),
     'round' : RoundSettings,
   });
   const CreateVotingConfigResponse = IDL.Record({ 'id' : VotingConfigId });
@@ -197,6 +411,7 @@ export const idlFactory = ({ IDL }) => {
   const DeleteAccessConfigRequest = IDL.Record({ 'id' : AccessConfigId });
   const DeleteBatchesRequest = IDL.Record({ 'ids' : IDL.Vec(BatchId) });
   const DeleteGroupRequest = IDL.Record({ 'group_id' : GroupId });
+  const DeleteMyVotingCommentRequest = IDL.Record({ 'id' : CommentId });
   const DeleteNestedVotingRequest = IDL.Record({ 'id' : NestedVotingId });
   const DeleteNestedVotingConfigRequest = IDL.Record({
     'id' : NestedVotingConfigId,
@@ -213,41 +428,72 @@ export const idlFactory = ({ IDL }) => {
     'access_config_id' : AccessConfigId,
     'program' : Program,
   });
-  const CandidRejectionCode = IDL.Variant({
-    'NoError' : IDL.Null,
-    'CanisterError' : IDL.Null,
-    'SysTransient' : IDL.Null,
-    'DestinationInvalid' : IDL.Null,
-    'Unknown' : IDL.Null,
-    'SysFatal' : IDL.Null,
-    'CanisterReject' : IDL.Null,
-  });
-  const RawCandidCallResult = IDL.Variant({
-    'Ok' : IDL.Vec(IDL.Nat8),
-    'Err' : IDL.Tuple(CandidRejectionCode, IDL.Text),
-  });
-  const ProgramExecutionResult = IDL.Variant({
-    'Empty' : IDL.Null,
-    'RemoteCallSequence' : IDL.Vec(RawCandidCallResult),
-  });
   const ExecuteResponse = IDL.Record({ 'result' : ProgramExecutionResult });
+  const QueryDelegationId = Id;
+  const QueryDelegationProofSignature = IDL.Record({
+    'certificate' : IDL.Vec(IDL.Nat8),
+    'witness' : IDL.Vec(IDL.Nat8),
+  });
   const QueryDelegationProof = IDL.Record({
-    'signature' : IDL.Null,
+    'id' : QueryDelegationId,
+    'signature' : IDL.Opt(QueryDelegationProofSignature),
+    'issued_at' : IDL.Nat64,
     'union_id' : IDL.Principal,
     'delegate_id' : IDL.Principal,
     'allowed_query_targets' : IDL.Vec(PermissionTarget),
     'expires_at' : IDL.Nat64,
   });
+  const ExplainEndpointAccessRequest = IDL.Record({
+    'endpoint' : RemoteCallEndpoint,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const AccessConfigExplanation = IDL.Record({
+    'id' : AccessConfigId,
+    'is_multisig' : IDL.Bool,
+    'allowees' : IDL.Vec(AlloweeConstraint),
+    'is_active' : IDL.Bool,
+  });
+  const PermissionExplanation = IDL.Record({
+    'voting_configs' : IDL.Vec(VotingConfigId),
+    'access_configs' : IDL.Vec(AccessConfigExplanation),
+    'permission_id' : PermissionId,
+  });
+  const EndpointAccessExplanation = IDL.Record({
+    'permissions' : IDL.Vec(PermissionExplanation),
+    'voting_proposers' : IDL.Vec(AccessConfigExplanation),
+  });
+  const ExplainEndpointAccessResponse = IDL.Record({
+    'explanation' : EndpointAccessExplanation,
+  });
+  const ExplainPrincipalAccessRequest = IDL.Record({
+    'principal' : IDL.Principal,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const PrincipalAccessExplanation = IDL.Record({
+    'delegated' : IDL.Vec(PermissionTarget),
+    'by_voting' : IDL.Vec(PermissionTarget),
+    'direct' : IDL.Vec(PermissionTarget),
+  });
+  const ExplainPrincipalAccessResponse = IDL.Record({
+    'explanation' : PrincipalAccessExplanation,
+  });
   const GetAccessConfigRequest = IDL.Record({
     'id' : AccessConfigId,
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
   const AccessConfig = IDL.Record({
     'id' : IDL.Opt(AccessConfigId),
+    'validity' : AccessConfigValidity,
     'permissions' : IDL.Vec(PermissionId),
+    'query_delegation_expiry' : IDL.Opt(IDL.Nat64),
+    'cycles_limits' : CyclesLimits,
     'name' : IDL.Text,
+    'quota' : AccessConfigQuota,
     'description' : IDL.Text,
+    'multisig' : IDL.Opt(MultisigSettings),
     'allowees' : IDL.Vec(AlloweeConstraint),
+    'is_active' : IDL.Bool,
+    'expiration_task' : IDL.Opt(IDL.Nat64),
   });
   const GetAccessConfigResponse = IDL.Record({
     'access_config' : AccessConfig,
@@ -278,12 +524,19 @@ export const idlFactory = ({ IDL }) => {
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
   const TokenId = Id;
+  const VoteEscrowSettings = IDL.Record({
+    'max_duration' : IDL.Nat64,
+    'max_multiplier' : Fraction,
+  });
   const Group = IDL.Record({
     'id' : IDL.Opt(GroupId),
     'token' : IDL.Opt(TokenId),
+    'vote_escrow' : IDL.Opt(VoteEscrowSettings),
     'name' : IDL.Text,
+    'rule' : IDL.Opt(GroupRule),
     'description' : IDL.Text,
     'private' : IDL.Bool,
+    'parent' : IDL.Opt(GroupId),
   });
   const GroupExt = IDL.Record({ 'it' : Group, 'transferable' : IDL.Bool });
   const GetGroupResponse = IDL.Record({ 'group' : GroupExt });
@@ -298,6 +551,31 @@ export const idlFactory = ({ IDL }) => {
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
   const GetGroupsResponse = IDL.Record({ 'groups' : IDL.Vec(GroupExt) });
+  const GetMultisigProposalRequest = IDL.Record({
+    'id' : MultisigProposalId,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const MultisigProposalStatus = IDL.Variant({
+    'Rejected' : IDL.Null,
+    'Executed' : IDL.Null,
+    'Cancelled' : IDL.Null,
+    'Expired' : IDL.Null,
+    'Pending' : IDL.Null,
+  });
+  const MultisigProposal = IDL.Record({
+    'id' : IDL.Opt(MultisigProposalId),
+    'status' : MultisigProposalStatus,
+    'rejecters' : IDL.Vec(IDL.Principal),
+    'created_at' : IDL.Nat64,
+    'access_config_id' : AccessConfigId,
+    'proposer' : IDL.Principal,
+    'expires_at' : IDL.Nat64,
+    'approvers' : IDL.Vec(IDL.Principal),
+    'program' : Program,
+  });
+  const GetMultisigProposalResponse = IDL.Record({
+    'proposal' : MultisigProposal,
+  });
   const GetMyGroupSharesBalanceRequest = IDL.Record({ 'group_id' : GroupId });
   const GetMyGroupSharesBalanceResponse = IDL.Record({ 'balance' : Shares });
   const GetMyNestedVoteRequest = IDL.Record({
@@ -314,11 +592,17 @@ export const idlFactory = ({ IDL }) => {
   });
   const GetProfileResponse = IDL.Record({ 'profile' : Profile });
   const GetMyQueryDelegationProofRequest = IDL.Record({
-    'requested_targets' : IDL.Vec(PermissionTarget),
+    'id' : QueryDelegationId,
   });
   const GetMyQueryDelegationProofResponse = IDL.Record({
     'proof' : QueryDelegationProof,
   });
+  const GetMyRemainingQuotaRequest = IDL.Record({
+    'access_config_id' : AccessConfigId,
+  });
+  const GetMyRemainingQuotaResponse = IDL.Record({
+    'remaining_quota' : RemainingQuota,
+  });
   const GetMySharesInfoAtRequest = IDL.Record({
     'at' : IDL.Nat64,
     'group_id' : GroupId,
@@ -379,6 +663,7 @@ export const idlFactory = ({ IDL }) => {
   });
   const Permission = IDL.Record({
     'id' : IDL.Opt(PermissionId),
+    'cycles_limits' : CyclesLimits,
     'name' : IDL.Text,
     'description' : IDL.Text,
     'targets' : IDL.Vec(PermissionTarget),
@@ -391,14 +676,25 @@ export const idlFactory = ({ IDL }) => {
   const GetSettingsRequest = IDL.Record({
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
+  const CyclesBudget = IDL.Record({
+    'period' : IDL.Nat64,
+    'amount' : IDL.Nat64,
+  });
   const TimestampedRecord = IDL.Record({
     'records' : IDL.Vec(IDL.Principal),
     'timestamp' : IDL.Nat64,
   });
+  const CyclesSpending = IDL.Record({
+    'period_start' : IDL.Nat64,
+    'spent' : IDL.Nat64,
+  });
   const Settings = IDL.Record({
+    'cycles_budget' : IDL.Opt(CyclesBudget),
     'name' : IDL.Text,
     'description' : IDL.Text,
+    'ic_root_key' : IDL.Opt(IDL.Vec(IDL.Nat8)),
     'history_ledgers' : IDL.Vec(TimestampedRecord),
+    'cycles_spending' : CyclesSpending,
   });
   const GetSettingsResponse = IDL.Record({ 'settings' : Settings });
   const GetSharesInfoOfAtRequest = IDL.Record({
@@ -412,6 +708,29 @@ export const idlFactory = ({ IDL }) => {
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
   const GetTotalGroupSharesResponse = IDL.Record({ 'total' : Shares });
+  const GetVestingGrantRequest = IDL.Record({
+    'id' : VestingGrantId,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const TaskId = Id;
+  const VestingGrant = IDL.Record({
+    'id' : IDL.Opt(VestingGrantId),
+    'total' : Shares,
+    'release_task' : IDL.Opt(TaskId),
+    'beneficiary' : IDL.Principal,
+    'revocable' : IDL.Bool,
+    'created_at' : IDL.Nat64,
+    'revoked_at' : IDL.Opt(IDL.Nat64),
+    'released' : Shares,
+    'group_id' : GroupId,
+    'schedule' : VestingSchedule,
+    'voting_power' : IDL.Bool,
+  });
+  const VestingGrantExt = IDL.Record({
+    'it' : VestingGrant,
+    'vested' : Shares,
+  });
+  const GetVestingGrantResponse = IDL.Record({ 'grant' : VestingGrantExt });
   const GetVotingRequest = IDL.Record({
     'id' : VotingId,
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
@@ -423,7 +742,16 @@ export const idlFactory = ({ IDL }) => {
     'Rejected' : IDL.Null,
     'Success' : IDL.Null,
   });
-  const TaskId = Id;
+  const ProposalBondStatus = IDL.Variant({
+    'Burned' : IDL.Null,
+    'Refunded' : IDL.Null,
+    'Locked' : IDL.Null,
+    'SentToTreasury' : IDL.Null,
+  });
+  const LockedProposalBond = IDL.Record({
+    'status' : ProposalBondStatus,
+    'bond' : ProposalBond,
+  });
   const Voting = IDL.Record({
     'id' : IDL.Opt(VotingId),
     'status' : VotingStatus,
@@ -431,16 +759,25 @@ export const idlFactory = ({ IDL }) => {
     'task_id' : IDL.Opt(TaskId),
     'approval_choice' : IDL.Opt(ChoiceId),
     'name' : IDL.Text,
+    'shares_info_cache' : IDL.Vec(
+      IDL.Tuple(GroupId, IDL.Vec(IDL.Tuple(IDL.Principal, SharesInfo)))
+    ),
+    'group_trees' : IDL.Vec(IDL.Tuple(GroupId, IDL.Vec(GroupId))),
     'description' : IDL.Text,
     'losers' : IDL.Vec(RoundResult),
     'created_at' : IDL.Nat64,
     'voting_config_id' : VotingConfigId,
     'rejection_choice' : IDL.Opt(ChoiceId),
+    'abstain_choice' : IDL.Opt(ChoiceId),
     'proposer' : IDL.Principal,
     'winners_need' : IDL.Nat32,
     'choices' : IDL.Vec(ChoiceId),
+    'proposal_bond' : IDL.Opt(LockedProposalBond),
     'winners' : IDL.Vec(RoundResult),
     'total_voting_power_by_group' : IDL.Vec(IDL.Tuple(GroupId, Shares)),
+    'vote_rationales' : IDL.Vec(
+      IDL.Tuple(GroupId, IDL.Vec(IDL.Tuple(IDL.Principal, IDL.Text)))
+    ),
   });
   const GetVotingResponse = IDL.Record({ 'voting' : Voting });
   const GetVotingChoiceRequest = IDL.Record({
@@ -457,6 +794,21 @@ export const idlFactory = ({ IDL }) => {
     'program' : Program,
   });
   const GetVotingChoiceResponse = IDL.Record({ 'choice' : Choice });
+  const GetVotingCommentRequest = IDL.Record({
+    'id' : CommentId,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const Comment = IDL.Record({
+    'id' : IDL.Opt(CommentId),
+    'updated_at' : IDL.Nat64,
+    'text' : IDL.Text,
+    'created_at' : IDL.Nat64,
+    'author' : IDL.Principal,
+    'voting_id' : VotingId,
+    'parent_id' : IDL.Opt(CommentId),
+    'removed' : IDL.Bool,
+  });
+  const GetVotingCommentResponse = IDL.Record({ 'comment' : Comment });
   const GetVotingConfigRequest = IDL.Record({
     'id' : VotingConfigId,
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
@@ -466,13 +818,16 @@ export const idlFactory = ({ IDL }) => {
     'win' : ThresholdValue,
     'winners_count' : IDL.Opt(LenInterval),
     'permissions' : IDL.Vec(PermissionId),
+    'proposer_limits' : ProposerLimits,
     'name' : IDL.Text,
     'description' : IDL.Text,
     'rejection' : ThresholdValue,
     'next_round' : ThresholdValue,
     'choices_count' : IDL.Opt(LenInterval),
     'approval' : ThresholdValue,
+    'allow_abstain' : IDL.Bool,
     'quorum' : ThresholdValue,
+    'proposal_bond' : IDL.Opt(ProposalBond),
     'round' : RoundSettings,
   });
   const GetVotingConfigResponse = IDL.Record({
@@ -486,6 +841,16 @@ export const idlFactory = ({ IDL }) => {
     'results' : IDL.Vec(
       IDL.Tuple(ChoiceId, IDL.Vec(IDL.Tuple(GroupId, Shares)))
     ),
+    'rationales' : IDL.Vec(
+      IDL.Tuple(GroupId, IDL.Vec(IDL.Tuple(IDL.Principal, IDL.Text)))
+    ),
+  });
+  const IssueMyQueryDelegationProofRequest = IDL.Record({
+    'requested_targets' : IDL.Vec(PermissionTarget),
+    'expires_in_opt' : IDL.Opt(IDL.Nat64),
+  });
+  const IssueMyQueryDelegationProofResponse = IDL.Record({
+    'proof' : QueryDelegationProof,
   });
   const AccessConfigFilter = IDL.Record({
     'permission' : IDL.Opt(PermissionId),
@@ -540,6 +905,13 @@ export const idlFactory = ({ IDL }) => {
     'has_next' : IDL.Bool,
   });
   const ListChunksResponse = IDL.Record({ 'page' : ListChunksPage });
+  const ListGroupRelativesRequest = IDL.Record({
+    'group_id' : GroupId,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const ListGroupRelativesResponse = IDL.Record({
+    'group_ids' : IDL.Vec(GroupId),
+  });
   const ListGroupSharesRequest = IDL.Record({
     'page_req' : PageRequest,
     'group_id' : GroupId,
@@ -559,6 +931,60 @@ export const idlFactory = ({ IDL }) => {
     'has_next' : IDL.Bool,
   });
   const ListGroupsResponse = IDL.Record({ 'page' : ListGroupsPage });
+  const MultisigProposalFilter = IDL.Record({
+    'access_config_id' : IDL.Opt(AccessConfigId),
+  });
+  const ListMultisigProposalsPageRequest = IDL.Record({
+    'page_size' : IDL.Nat32,
+    'page_index' : IDL.Nat32,
+    'sort' : IDL.Null,
+    'filter' : MultisigProposalFilter,
+  });
+  const ListMultisigProposalsRequest = IDL.Record({
+    'page_req' : ListMultisigProposalsPageRequest,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const ListMultisigProposalsPage = IDL.Record({
+    'data' : IDL.Vec(MultisigProposal),
+    'has_next' : IDL.Bool,
+  });
+  const ListMultisigProposalsResponse = IDL.Record({
+    'page' : ListMultisigProposalsPage,
+  });
+  const ListMyVestingGrantsRequest = IDL.Record({ 'page_req' : PageRequest });
+  const ListVestingGrantsPage = IDL.Record({
+    'data' : IDL.Vec(VestingGrantExt),
+    'has_next' : IDL.Bool,
+  });
+  const ListVestingGrantsResponse = IDL.Record({
+    'page' : ListVestingGrantsPage,
+  });
+  const ListMyVoteEscrowLocksRequest = IDL.Record({ 'page_req' : PageRequest });
+  const VoteEscrowLockId = Id;
+  const VoteEscrowLock = IDL.Record({
+    'id' : IDL.Opt(VoteEscrowLockId),
+    'qty' : Shares,
+    'locked_at' : IDL.Nat64,
+    'expired' : IDL.Bool,
+    'token_id' : TokenId,
+    'unlocks_at' : IDL.Nat64,
+    'owner' : IDL.Principal,
+    'boost' : Shares,
+    'expiration_task' : IDL.Opt(TaskId),
+  });
+  const ListVoteEscrowLocksPage = IDL.Record({
+    'data' : IDL.Vec(VoteEscrowLock),
+    'has_next' : IDL.Bool,
+  });
+  const ListVoteEscrowLocksResponse = IDL.Record({
+    'page' : ListVoteEscrowLocksPage,
+  });
+  const ListMyVotingsRequest = IDL.Record({ 'page_req' : PageRequest });
+  const ListVotingsPage = IDL.Record({
+    'data' : IDL.Vec(Voting),
+    'has_next' : IDL.Bool,
+  });
+  const ListVotingsResponse = IDL.Record({ 'page' : ListVotingsPage });
   const NestedVotingConfigFilter = IDL.Record({
     'remote_voting_config' : IDL.Opt(IDL.Tuple(IDL.Principal, VotingConfigId)),
     'remote_nested_voting_config' : IDL.Opt(
@@ -601,6 +1027,19 @@ export const idlFactory = ({ IDL }) => {
   const ListNestedVotingsResponse = IDL.Record({
     'page' : ListNestedVotingsPage,
   });
+  const ListPermissionTemplatesRequest = IDL.Record({
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const PermissionTemplateInfo = IDL.Record({
+    'name' : IDL.Text,
+    'description' : IDL.Text,
+    'targets' : IDL.Vec(PermissionTarget),
+    'template' : PermissionTemplate,
+    'permission_id' : IDL.Opt(PermissionId),
+  });
+  const ListPermissionTemplatesResponse = IDL.Record({
+    'templates' : IDL.Vec(PermissionTemplateInfo),
+  });
   const PermissionFilter = IDL.Record({ 'target' : IDL.Opt(PermissionTarget) });
   const ListPermissionsPageRequest = IDL.Record({
     'page_size' : IDL.Nat32,
@@ -649,6 +1088,40 @@ export const idlFactory = ({ IDL }) => {
     'page' : ListProgramExecutionEntryIdsPage,
     'history_ledger_canister_id' : IDL.Principal,
   });
+  const ListRevokedQueryDelegatesRequest = IDL.Record({
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const ListRevokedQueryDelegatesResponse = IDL.Record({
+    'revoked_delegates' : IDL.Vec(IDL.Tuple(IDL.Principal, IDL.Nat64)),
+  });
+  const VestingGrantFilter = IDL.Record({
+    'beneficiary' : IDL.Opt(IDL.Principal),
+    'group_id' : IDL.Opt(GroupId),
+  });
+  const ListVestingGrantsPageRequest = IDL.Record({
+    'page_size' : IDL.Nat32,
+    'page_index' : IDL.Nat32,
+    'sort' : IDL.Null,
+    'filter' : VestingGrantFilter,
+  });
+  const ListVestingGrantsRequest = IDL.Record({
+    'page_req' : ListVestingGrantsPageRequest,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const VoteEscrowLockFilter = IDL.Record({
+    'token_id' : IDL.Opt(TokenId),
+    'owner' : IDL.Opt(IDL.Principal),
+  });
+  const ListVoteEscrowLocksPageRequest = IDL.Record({
+    'page_size' : IDL.Nat32,
+    'page_index' : IDL.Nat32,
+    'sort' : IDL.Null,
+    'filter' : VoteEscrowLockFilter,
+  });
+  const ListVoteEscrowLocksRequest = IDL.Record({
+    'page_req' : ListVoteEscrowLocksPageRequest,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
   const ChoiceFilter = IDL.Record({ 'voting_id' : RemoteVotingId });
   const ListVotingChoicesPageRequest = IDL.Record({
     'page_size' : IDL.Nat32,
@@ -667,6 +1140,27 @@ export const idlFactory = ({ IDL }) => {
   const ListVotingChoicesResponse = IDL.Record({
     'page' : ListVotingChoicesPage,
   });
+  const CommentFilter = IDL.Record({
+    'voting_id' : VotingId,
+    'parent_id' : IDL.Opt(CommentId),
+  });
+  const ListVotingCommentsPageRequest = IDL.Record({
+    'page_size' : IDL.Nat32,
+    'page_index' : IDL.Nat32,
+    'sort' : IDL.Null,
+    'filter' : CommentFilter,
+  });
+  const ListVotingCommentsRequest = IDL.Record({
+    'page_req' : ListVotingCommentsPageRequest,
+    'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
+  });
+  const ListVotingCommentsPage = IDL.Record({
+    'data' : IDL.Vec(Comment),
+    'has_next' : IDL.Bool,
+  });
+  const ListVotingCommentsResponse = IDL.Record({
+    'page' : ListVotingCommentsPage,
+  });
   const VotingConfigFilter = IDL.Record({
     'permission' : IDL.Opt(PermissionId),
     'group' : IDL.Opt(GroupId),
@@ -693,27 +1187,60 @@ export const idlFactory = ({ IDL }) => {
     'None' : IDL.Null,
     'CreatedAt' : IDL.Bool,
   });
+  const VotingStatusFilter = IDL.Variant({
+    'PreRound' : IDL.Null,
+    'Active' : IDL.Null,
+    'Finished' : IDL.Null,
+    'Rejected' : IDL.Null,
+  });
+  const CreatedAtRange = IDL.Record({ 'to' : IDL.Nat64, 'from' : IDL.Nat64 });
+  const VotingFilter = IDL.Record({
+    'status' : IDL.Opt(VotingStatusFilter),
+    'name' : IDL.Opt(IDL.Text),
+    'created_at' : IDL.Opt(CreatedAtRange),
+    'proposer' : IDL.Opt(IDL.Principal),
+    'voting_config' : IDL.Opt(VotingConfigId),
+  });
   const ListVotingsPageRequest = IDL.Record({
     'page_size' : IDL.Nat32,
     'page_index' : IDL.Nat32,
     'sort' : VotingSort,
-    'filter' : IDL.Null,
+    'filter' : VotingFilter,
   });
   const ListVotingsRequest = IDL.Record({
     'page_req' : ListVotingsPageRequest,
     'query_delegation_proof_opt' : IDL.Opt(QueryDelegationProof),
   });
-  const ListVotingsPage = IDL.Record({
-    'data' : IDL.Vec(Voting),
-    'has_next' : IDL.Bool,
-  });
-  const ListVotingsResponse = IDL.Record({ 'page' : ListVotingsPage });
   const LockBatchesRequest = IDL.Record({ 'ids' : IDL.Vec(BatchId) });
+  const LockMyGroupSharesForVotingPowerRequest = IDL.Record({
+    'qty' : Shares,
+    'duration' : IDL.Nat64,
+    'group_id' : GroupId,
+  });
+  const LockMyGroupSharesForVotingPowerResponse = IDL.Record({
+    'id' : VoteEscrowLockId,
+  });
   const MintGroupSharesRequest = IDL.Record({
     'qty' : Shares,
     'owner' : IDL.Principal,
     'group_id' : GroupId,
   });
+  const ModerateVotingCommentRequest = IDL.Record({ 'id' : CommentId });
+  const ProposeMultisigProgramRequest = IDL.Record({
+    'access_config_id' : AccessConfigId,
+    'program' : Program,
+  });
+  const ProposeMultisigProgramResponse = IDL.Record({
+    'id' : MultisigProposalId,
+    'result' : IDL.Opt(ProgramExecutionResult),
+  });
+  const RejectMultisigProposalRequest = IDL.Record({
+    'id' : MultisigProposalId,
+  });
+  const RevokeQueryDelegateRequest = IDL.Record({
+    'delegate_id' : IDL.Principal,
+  });
+  const RevokeVestingGrantRequest = IDL.Record({ 'id' : VestingGrantId });
   const SendBatchRequest = IDL.Record({
     'batch_id' : BatchId,
     'target_canister' : IDL.Principal,
@@ -732,19 +1259,34 @@ export const idlFactory = ({ IDL }) => {
   const UpdateAccessConfigRequest = IDL.Record({
     'id' : AccessConfigId,
     'new_description' : IDL.Opt(IDL.Text),
+    'new_multisig' : IDL.Opt(IDL.Opt(MultisigSettings)),
     'new_allowees' : IDL.Opt(IDL.Vec(AlloweeConstraint)),
+    'new_query_delegation_expiry' : IDL.Opt(IDL.Opt(IDL.Nat64)),
     'new_name' : IDL.Opt(IDL.Text),
+    'new_cycles_limits' : IDL.Opt(CyclesLimits),
     'new_permissions' : IDL.Opt(IDL.Vec(PermissionId)),
+    'new_validity' : IDL.Opt(AccessConfigValidity),
+    'new_quota' : IDL.Opt(AccessConfigQuota),
+  });
+  const UpdateComputedGroupRuleRequest = IDL.Record({
+    'new_rule' : GroupRule,
+    'group_id' : GroupId,
   });
   const UpdateGroupRequest = IDL.Record({
     'new_description' : IDL.Opt(IDL.Text),
     'new_name' : IDL.Opt(IDL.Text),
     'group_id' : GroupId,
+    'new_parent' : IDL.Opt(IDL.Opt(GroupId)),
+    'new_vote_escrow' : IDL.Opt(IDL.Opt(VoteEscrowSettings)),
   });
   const UpdateMyProfileRequest = IDL.Record({
     'new_description' : IDL.Opt(IDL.Text),
     'new_name' : IDL.Opt(IDL.Text),
   });
+  const UpdateMyVotingCommentRequest = IDL.Record({
+    'id' : CommentId,
+    'new_text' : IDL.Text,
+  });
   const UpdateNestedVotingConfigRequest = IDL.Record({
     'id' : NestedVotingConfigId,
     'description_opt' : IDL.Opt(IDL.Text),
@@ -757,6 +1299,7 @@ export const idlFactory = ({ IDL }) => {
     'new_description' : IDL.Opt(IDL.Text),
     'new_targets' : IDL.Opt(IDL.Vec(PermissionTarget)),
     'new_name' : IDL.Opt(IDL.Text),
+    'new_cycles_limits' : IDL.Opt(CyclesLimits),
   });
   const UpdateProfileRequest = IDL.Record({
     'id' : ProfileId,
@@ -765,6 +1308,8 @@ export const idlFactory = ({ IDL }) => {
   });
   const UpdateSettingsRequest = IDL.Record({
     'new_description' : IDL.Opt(IDL.Text),
+    'new_ic_root_key' : IDL.Opt(IDL.Opt(IDL.Vec(IDL.Nat8))),
+    'new_cycles_budget' : IDL.Opt(IDL.Opt(CyclesBudget)),
     'new_name' : IDL.Opt(IDL.Text),
   });
   const UpdateVotingRequest = IDL.Record({
@@ -781,25 +1326,45 @@ export const idlFactory = ({ IDL }) => {
   });
   const UpdateVotingConfigRequest = IDL.Record({
     'id' : VotingConfigId,
+    'allow_abstain_opt' : IDL.Opt(IDL.Bool),
     'description_opt' : IDL.Opt(IDL.Text),
     'next_round_opt' : IDL.Opt(ThresholdValue),
     'name_opt' : IDL.Opt(IDL.Text),
     'quorum_opt' : IDL.Opt(ThresholdValue),
+    'proposal_bond_opt' : IDL.Opt(IDL.Opt(ProposalBond)),
     'approval_opt' : IDL.Opt(ThresholdValue),
     'round_opt' : IDL.Opt(RoundSettings),
     'choices_count_opt' : IDL.Opt(IDL.Opt(LenInterval)),
     'winners_count_opt' : IDL.Opt(IDL.Opt(LenInterval)),
     'rejection_opt' : IDL.Opt(ThresholdValue),
+    'proposer_limits_opt' : IDL.Opt(ProposerLimits),
     'win_opt' : IDL.Opt(ThresholdValue),
     'permissions_opt' : IDL.Opt(IDL.Vec(PermissionId)),
   });
   return IDL.Service({
     'accept_my_group_shares' : IDL.Func([AcceptMyGroupSharesRequest], [], []),
+    'approve_multisig_proposal' : IDL.Func(
+        [ApproveMultisigProposalRequest],
+        [ApproveMultisigProposalResponse],
+        [],
+      ),
     'burn_group_shares' : IDL.Func([BurnGroupSharesRequest], [], []),
     'burn_my_group_shares' : IDL.Func([BurnMyGroupSharesRequest], [], []),
     'burn_unaccepted_group_shares' : IDL.Func([BurnGroupSharesRequest], [], []),
+    'cancel_multisig_proposal' : IDL.Func(
+        [CancelMultisigProposalRequest],
+        [],
+        [],
+      ),
     'cast_my_nested_vote' : IDL.Func([CastMyNestedVoteRequest], [], []),
     'cast_my_vote' : IDL.Func([CastMyVoteRequest], [], []),
+    'check_my_execution' : IDL.Func(
+        [CheckMyExecutionRequest],
+        [CheckMyExecutionResponse],
+        [],
+      ),
+    'convert_group_to_private' : IDL.Func([ConvertGroupRequest], [], []),
+    'convert_group_to_public' : IDL.Func([ConvertGroupRequest], [], []),
     'create_access_config' : IDL.Func(
         [CreateAccessConfigRequest],
         [CreateAccessConfigResponse],
@@ -807,7 +1372,17 @@ export const idlFactory = ({ IDL }) => {
       ),
     'create_batch' : IDL.Func([CreateBatchRequest], [CreateBatchResponse], []),
     'create_chunk' : IDL.Func([CreateChunkRequest], [CreateChunkResponse], []),
+    'create_computed_group' : IDL.Func(
+        [CreateComputedGroupRequest],
+        [CreateGroupResponse],
+        [],
+      ),
     'create_group' : IDL.Func([CreateGroupRequest], [CreateGroupResponse], []),
+    'create_my_voting_comment' : IDL.Func(
+        [CreateMyVotingCommentRequest],
+        [CreateMyVotingCommentResponse],
+        [],
+      ),
     'create_nested_voting' : IDL.Func(
         [CreateNestedVotingRequest],
         [CreateNestedVotingResponse],
@@ -823,7 +1398,17 @@ export const idlFactory = ({ IDL }) => {
         [CreatePermissionResponse],
         [],
       ),
+    'create_permission_from_template' : IDL.Func(
+        [CreatePermissionFromTemplateRequest],
+        [CreatePermissionFromTemplateResponse],
+        [],
+      ),
     'create_profile' : IDL.Func([CreateProfileRequest], [], []),
+    'create_vesting_grant' : IDL.Func(
+        [CreateVestingGrantRequest],
+        [CreateVestingGrantResponse],
+        [],
+      ),
     'create_voting' : IDL.Func(
         [CreateVotingRequest],
         [CreateVotingResponse],
@@ -843,6 +1428,11 @@ export const idlFactory = ({ IDL }) => {
     'delete_access_config' : IDL.Func([DeleteAccessConfigRequest], [], []),
     'delete_batches' : IDL.Func([DeleteBatchesRequest], [], []),
     'delete_group' : IDL.Func([DeleteGroupRequest], [], []),
+    'delete_my_voting_comment' : IDL.Func(
+        [DeleteMyVotingCommentRequest],
+        [],
+        [],
+      ),
     'delete_nested_voting' : IDL.Func([DeleteNestedVotingRequest], [], []),
     'delete_nested_voting_config' : IDL.Func(
         [DeleteNestedVotingConfigRequest],
@@ -856,6 +1446,16 @@ export const idlFactory = ({ IDL }) => {
     'delete_voting_choice' : IDL.Func([DeleteVotingChoiceRequest], [], []),
     'delete_voting_config' : IDL.Func([DeleteVotingConfigRequest], [], []),
     'execute' : IDL.Func([ExecuteRequest], [ExecuteResponse], []),
+    'explain_endpoint_access' : IDL.Func(
+        [ExplainEndpointAccessRequest],
+        [ExplainEndpointAccessResponse],
+        ['query'],
+      ),
+    'explain_principal_access' : IDL.Func(
+        [ExplainPrincipalAccessRequest],
+        [ExplainPrincipalAccessResponse],
+        ['query'],
+      ),
     'get_access_config' : IDL.Func(
         [GetAccessConfigRequest],
         [GetAccessConfigResponse],
@@ -869,17 +1469,42 @@ export const idlFactory = ({ IDL }) => {
         [GetGroupSharesBalanceOfResponse],
         ['query'],
       ),
+    'get_group_voting_power_of' : IDL.Func(
+        [GetGroupSharesBalanceOfRequest],
+        [GetGroupSharesBalanceOfResponse],
+        ['query'],
+      ),
     'get_groups_of' : IDL.Func(
         [GetGroupsOfRequest],
         [GetGroupsResponse],
         ['query'],
       ),
+    'get_locked_group_shares_balance_of' : IDL.Func(
+        [GetGroupSharesBalanceOfRequest],
+        [GetGroupSharesBalanceOfResponse],
+        ['query'],
+      ),
+    'get_multisig_proposal' : IDL.Func(
+        [GetMultisigProposalRequest],
+        [GetMultisigProposalResponse],
+        ['query'],
+      ),
     'get_my_group_shares_balance' : IDL.Func(
         [GetMyGroupSharesBalanceRequest],
         [GetMyGroupSharesBalanceResponse],
         ['query'],
       ),
+    'get_my_group_voting_power' : IDL.Func(
+        [GetMyGroupSharesBalanceRequest],
+        [GetMyGroupSharesBalanceResponse],
+        ['query'],
+      ),
     'get_my_groups' : IDL.Func([], [GetGroupsResponse], ['query']),
+    'get_my_locked_group_shares_balance' : IDL.Func(
+        [GetMyGroupSharesBalanceRequest],
+        [GetMyGroupSharesBalanceResponse],
+        ['query'],
+      ),
     'get_my_nested_vote' : IDL.Func(
         [GetMyNestedVoteRequest],
         [GetMyNestedVoteResponse],
@@ -891,6 +1516,11 @@ export const idlFactory = ({ IDL }) => {
         [GetMyQueryDelegationProofResponse],
         ['query'],
       ),
+    'get_my_remaining_quota' : IDL.Func(
+        [GetMyRemainingQuotaRequest],
+        [GetMyRemainingQuotaResponse],
+        ['query'],
+      ),
     'get_my_shares_info_at' : IDL.Func(
         [GetMySharesInfoAtRequest],
         [GetSharesInfoOfAtResponse],
@@ -941,6 +1571,11 @@ export const idlFactory = ({ IDL }) => {
         [GetTotalGroupSharesResponse],
         ['query'],
       ),
+    'get_total_group_voting_power' : IDL.Func(
+        [GetTotalGroupSharesRequest],
+        [GetTotalGroupSharesResponse],
+        ['query'],
+      ),
     'get_total_unaccepted_group_shares' : IDL.Func(
         [GetTotalGroupSharesRequest],
         [GetTotalGroupSharesResponse],
@@ -951,12 +1586,22 @@ export const idlFactory = ({ IDL }) => {
         [GetGroupSharesBalanceOfResponse],
         ['query'],
       ),
+    'get_vesting_grant' : IDL.Func(
+        [GetVestingGrantRequest],
+        [GetVestingGrantResponse],
+        ['query'],
+      ),
     'get_voting' : IDL.Func([GetVotingRequest], [GetVotingResponse], ['query']),
     'get_voting_choice' : IDL.Func(
         [GetVotingChoiceRequest],
         [GetVotingChoiceResponse],
         ['query'],
       ),
+    'get_voting_comment' : IDL.Func(
+        [GetVotingCommentRequest],
+        [GetVotingCommentResponse],
+        ['query'],
+      ),
     'get_voting_config' : IDL.Func(
         [GetVotingConfigRequest],
         [GetVotingConfigResponse],
@@ -967,6 +1612,11 @@ export const idlFactory = ({ IDL }) => {
         [GetVotingResultsResponse],
         ['query'],
       ),
+    'issue_my_query_delegation_proof' : IDL.Func(
+        [IssueMyQueryDelegationProofRequest],
+        [IssueMyQueryDelegationProofResponse],
+        [],
+      ),
     'list_access_configs' : IDL.Func(
         [ListAccessConfigsRequest],
         [ListAccessConfigsResponse],
@@ -982,6 +1632,16 @@ export const idlFactory = ({ IDL }) => {
         [ListChunksResponse],
         ['query'],
       ),
+    'list_group_ancestors' : IDL.Func(
+        [ListGroupRelativesRequest],
+        [ListGroupRelativesResponse],
+        ['query'],
+      ),
+    'list_group_descendants' : IDL.Func(
+        [ListGroupRelativesRequest],
+        [ListGroupRelativesResponse],
+        ['query'],
+      ),
     'list_group_shares' : IDL.Func(
         [ListGroupSharesRequest],
         [ListGroupSharesResponse],
@@ -992,6 +1652,26 @@ export const idlFactory = ({ IDL }) => {
         [ListGroupsResponse],
         ['query'],
       ),
+    'list_multisig_proposals' : IDL.Func(
+        [ListMultisigProposalsRequest],
+        [ListMultisigProposalsResponse],
+        ['query'],
+      ),
+    'list_my_vesting_grants' : IDL.Func(
+        [ListMyVestingGrantsRequest],
+        [ListVestingGrantsResponse],
+        ['query'],
+      ),
+    'list_my_vote_escrow_locks' : IDL.Func(
+        [ListMyVoteEscrowLocksRequest],
+        [ListVoteEscrowLocksResponse],
+        ['query'],
+      ),
+    'list_my_votings' : IDL.Func(
+        [ListMyVotingsRequest],
+        [ListVotingsResponse],
+        ['query'],
+      ),
     'list_nested_voting_configs' : IDL.Func(
         [ListNestedVotingConfigsRequest],
         [ListNestedVotingConfigsResponse],
@@ -1002,6 +1682,11 @@ export const idlFactory = ({ IDL }) => {
         [ListNestedVotingsResponse],
         ['query'],
       ),
+    'list_permission_templates' : IDL.Func(
+        [ListPermissionTemplatesRequest],
+        [ListPermissionTemplatesResponse],
+        ['query'],
+      ),
     'list_permissions' : IDL.Func(
         [ListPermissionsRequest],
         [ListPermissionsResponse],
@@ -1017,16 +1702,36 @@ export const idlFactory = ({ IDL }) => {
         [ListProgramExecutionEntryIdsResponse],
         [],
       ),
+    'list_revoked_query_delegates' : IDL.Func(
+        [ListRevokedQueryDelegatesRequest],
+        [ListRevokedQueryDelegatesResponse],
+        ['query'],
+      ),
     'list_unaccepted_group_shares' : IDL.Func(
         [ListGroupSharesRequest],
         [ListGroupSharesResponse],
         ['query'],
       ),
+    'list_vesting_grants' : IDL.Func(
+        [ListVestingGrantsRequest],
+        [ListVestingGrantsResponse],
+        ['query'],
+      ),
+    'list_vote_escrow_locks' : IDL.Func(
+        [ListVoteEscrowLocksRequest],
+        [ListVoteEscrowLocksResponse],
+        ['query'],
+      ),
     'list_voting_choices' : IDL.Func(
         [ListVotingChoicesRequest],
         [ListVotingChoicesResponse],
         ['query'],
       ),
+    'list_voting_comments' : IDL.Func(
+        [ListVotingCommentsRequest],
+        [ListVotingCommentsResponse],
+        ['query'],
+      ),
     'list_voting_configs' : IDL.Func(
         [ListVotingConfigsRequest],
         [ListVotingConfigsResponse],
@@ -1038,7 +1743,29 @@ export const idlFactory = ({ IDL }) => {
         ['query'],
       ),
     'lock_batches' : IDL.Func([LockBatchesRequest], [], []),
+    'lock_my_group_shares_for_voting_power' : IDL.Func(
+        [LockMyGroupSharesForVotingPowerRequest],
+        [LockMyGroupSharesForVotingPowerResponse],
+        [],
+      ),
     'mint_group_shares' : IDL.Func([MintGroupSharesRequest], [], []),
+    'moderate_voting_comment' : IDL.Func(
+        [ModerateVotingCommentRequest],
+        [],
+        [],
+      ),
+    'propose_multisig_program' : IDL.Func(
+        [ProposeMultisigProgramRequest],
+        [ProposeMultisigProgramResponse],
+        [],
+      ),
+    'reject_multisig_proposal' : IDL.Func(
+        [RejectMultisigProposalRequest],
+        [],
+        [],
+      ),
+    'revoke_query_delegate' : IDL.Func([RevokeQueryDelegateRequest], [], []),
+    'revoke_vesting_grant' : IDL.Func([RevokeVestingGrantRequest], [], []),
     'send_batch' : IDL.Func([SendBatchRequest], [], []),
     'transfer_group_shares' : IDL.Func([TransferGroupSharesRequest], [], []),
     'transfer_my_group_shares' : IDL.Func(
@@ -1047,8 +1774,18 @@ export const idlFactory = ({ IDL }) => {
         [],
       ),
     'update_access_config' : IDL.Func([UpdateAccessConfigRequest], [], []),
+    'update_computed_group_rule' : IDL.Func(
+        [UpdateComputedGroupRuleRequest],
+        [],
+        [],
+      ),
     'update_group' : IDL.Func([UpdateGroupRequest], [], []),
     'update_my_profile' : IDL.Func([UpdateMyProfileRequest], [], []),
+    'update_my_voting_comment' : IDL.Func(
+        [UpdateMyVotingCommentRequest],
+        [],
+        [],
+      ),
     'update_nested_voting_config' : IDL.Func(
         [UpdateNestedVotingConfigRequest],
         [],
@@ -1064,6 +1801,7 @@ export const idlFactory = ({ IDL }) => {
 };
 export const init = ({ IDL }) => {
   const InitRequest = IDL.Record({
+    'ic_root_key' : IDL.Opt(IDL.Vec(IDL.Nat8)),
     'union_description' : IDL.Text,
     'wallet_creator' : IDL.Principal,
     'union_name' : IDL.Text,
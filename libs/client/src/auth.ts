@@ -1,7 +1,7 @@
 import { Principal } from '@dfinity/principal';
 import { UnionWindowOpener, UnionWindowOpenerOptions } from './opener';
 import {
-  GetMyQueryDelegationProofRequest,
+  IssueMyQueryDelegationProofRequest,
   QueryDelegationProof,
   _SERVICE,
 } from './assets/union-wallet.did';
@@ -14,7 +14,7 @@ export interface UnionWindowAuthorizerOptions extends UnionWindowOpenerOptions {
 
 export interface LoginProps {
   principal: Principal;
-  request?: GetMyQueryDelegationProofRequest;
+  request?: IssueMyQueryDelegationProofRequest;
 }
 
 export type AuthorizationStatus = false | 'authorized' | 'delegated';
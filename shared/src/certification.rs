@@ -0,0 +1,403 @@
+use crate::types::Blob;
+use candid::Principal;
+use ic_verify_bls_signature::verify_bls_signature;
+use serde_cbor::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+pub type Hash = [u8; 32];
+
+// DER-encoded public key of the IC mainnet root subnet, local replicas have their own one
+pub const IC_ROOT_KEY_HEX: &str = "308182301d060d2b0601040182dc7c0503010201060c2b0601040182dc7c05030201036100814c0e6ec71fab583b08bd81373c255c3c371b2e84863c98a4f1e08b74235d14fb5d9c0cd546d9685f913a0c0b2cc5341583bf4b4392e467db96d65b9bb4cb717112f8472e0d5a4d14505ffd7484b01291091c5f87b98883463f98091a0baaae";
+
+const DER_PREFIX: [u8; 37] = [
+    0x30, 0x81, 0x82, 0x30, 0x1d, 0x06, 0x0d, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0xdc, 0x7c, 0x05,
+    0x03, 0x01, 0x02, 0x01, 0x06, 0x0c, 0x2b, 0x06, 0x01, 0x04, 0x01, 0x82, 0xdc, 0x7c, 0x05, 0x03,
+    0x02, 0x01, 0x03, 0x61, 0x00,
+];
+const BLS_KEY_LEN: usize = 96;
+
+pub fn ic_root_key() -> Blob {
+    hex::decode(IC_ROOT_KEY_HEX).unwrap()
+}
+
+pub fn is_root_key_valid(root_key_der: &[u8]) -> bool {
+    extract_der_key(root_key_der).is_some()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HashTree {
+    Empty,
+    Fork(Box<HashTree>, Box<HashTree>),
+    Labeled(Blob, Box<HashTree>),
+    Leaf(Blob),
+    Pruned(Hash),
+}
+
+impl HashTree {
+    pub fn from_cbor(bytes: &[u8]) -> Option<Self> {
+        let value: Value = serde_cbor::from_slice(bytes).ok()?;
+
+        Self::from_value(&value)
+    }
+
+    pub fn to_cbor(&self) -> Blob {
+        serde_cbor::to_vec(&self.to_value()).unwrap()
+    }
+
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let items = match value {
+            Value::Array(items) => items,
+            _ => return None,
+        };
+
+        match (items.get(0)?, items.len()) {
+            (Value::Integer(0), 1) => Some(HashTree::Empty),
+            (Value::Integer(1), 3) => Some(HashTree::Fork(
+                Box::new(Self::from_value(&items[1])?),
+                Box::new(Self::from_value(&items[2])?),
+            )),
+            (Value::Integer(2), 3) => Some(HashTree::Labeled(
+                as_bytes(&items[1])?.clone(),
+                Box::new(Self::from_value(&items[2])?),
+            )),
+            (Value::Integer(3), 2) => Some(HashTree::Leaf(as_bytes(&items[1])?.clone())),
+            (Value::Integer(4), 2) => {
+                let hash = as_bytes(&items[1])?;
+
+                Some(HashTree::Pruned(hash.as_slice().try_into().ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self {
+            HashTree::Empty => Value::Array(vec![Value::Integer(0)]),
+            HashTree::Fork(left, right) => {
+                Value::Array(vec![Value::Integer(1), left.to_value(), right.to_value()])
+            }
+            HashTree::Labeled(label, subtree) => Value::Array(vec![
+                Value::Integer(2),
+                Value::Bytes(label.clone()),
+                subtree.to_value(),
+            ]),
+            HashTree::Leaf(data) => {
+                Value::Array(vec![Value::Integer(3), Value::Bytes(data.clone())])
+            }
+            HashTree::Pruned(hash) => {
+                Value::Array(vec![Value::Integer(4), Value::Bytes(hash.to_vec())])
+            }
+        }
+    }
+
+    pub fn reconstruct(&self) -> Hash {
+        match self {
+            HashTree::Empty => hash_with_domain("ic-hashtree-empty", &[]),
+            HashTree::Fork(left, right) => hash_with_domain(
+                "ic-hashtree-fork",
+                &[&left.reconstruct(), &right.reconstruct()],
+            ),
+            HashTree::Labeled(label, subtree) => {
+                hash_with_domain("ic-hashtree-labeled", &[label, &subtree.reconstruct()])
+            }
+            HashTree::Leaf(data) => hash_with_domain("ic-hashtree-leaf", &[data]),
+            HashTree::Pruned(hash) => *hash,
+        }
+    }
+
+    pub fn lookup_path(&self, path: &[&[u8]]) -> Option<&[u8]> {
+        match path.split_first() {
+            None => match self {
+                HashTree::Leaf(data) => Some(data.as_slice()),
+                _ => None,
+            },
+            Some((label, rest)) => self.find_label(label)?.lookup_path(rest),
+        }
+    }
+
+    fn find_label(&self, label: &[u8]) -> Option<&HashTree> {
+        match self {
+            HashTree::Labeled(l, subtree) if l.as_slice() == label => Some(subtree),
+            HashTree::Fork(left, right) => {
+                left.find_label(label).or_else(|| right.find_label(label))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Delegation {
+    pub subnet_id: Blob,
+    pub certificate: Blob,
+}
+
+impl Delegation {
+    // returns the raw subnet key, if the subnet is certified by the root key to host the canister
+    pub fn verify(&self, canister_id: &Principal, root_key: &[u8]) -> Option<Blob> {
+        let certificate = Certificate::from_cbor(&self.certificate)?;
+
+        // nested delegations are not allowed
+        if certificate.delegation.is_some() || !certificate.verify_with_key(root_key) {
+            return None;
+        }
+
+        let ranges = certificate.tree.lookup_path(&[
+            b"subnet",
+            self.subnet_id.as_slice(),
+            b"canister_ranges",
+        ])?;
+
+        if !is_in_canister_ranges(ranges, canister_id)? {
+            return None;
+        }
+
+        let der_key =
+            certificate
+                .tree
+                .lookup_path(&[b"subnet", self.subnet_id.as_slice(), b"public_key"])?;
+
+        extract_der_key(der_key).map(|it| it.to_vec())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Certificate {
+    pub tree: HashTree,
+    pub signature: Blob,
+    pub delegation: Option<Delegation>,
+}
+
+impl Certificate {
+    pub fn from_cbor(bytes: &[u8]) -> Option<Self> {
+        let value: Value = serde_cbor::from_slice(bytes).ok()?;
+        let map = match value {
+            Value::Map(map) => map,
+            _ => return None,
+        };
+
+        let tree = HashTree::from_value(map.get(&text("tree"))?)?;
+        let signature = as_bytes(map.get(&text("signature"))?)?.clone();
+
+        let delegation = match map.get(&text("delegation")) {
+            Some(Value::Map(delegation)) => Some(Delegation {
+                subnet_id: as_bytes(delegation.get(&text("subnet_id"))?)?.clone(),
+                certificate: as_bytes(delegation.get(&text("certificate"))?)?.clone(),
+            }),
+            Some(_) => return None,
+            None => None,
+        };
+
+        Some(Self {
+            tree,
+            signature,
+            delegation,
+        })
+    }
+
+    pub fn to_cbor(&self) -> Blob {
+        let mut map = BTreeMap::new();
+        map.insert(text("tree"), self.tree.to_value());
+        map.insert(text("signature"), Value::Bytes(self.signature.clone()));
+
+        if let Some(delegation) = &self.delegation {
+            let mut delegation_map = BTreeMap::new();
+            delegation_map.insert(
+                text("subnet_id"),
+                Value::Bytes(delegation.subnet_id.clone()),
+            );
+            delegation_map.insert(
+                text("certificate"),
+                Value::Bytes(delegation.certificate.clone()),
+            );
+
+            map.insert(text("delegation"), Value::Map(delegation_map));
+        }
+
+        serde_cbor::to_vec(&Value::Map(map)).unwrap()
+    }
+
+    // checks the BLS signature of the certificate against the DER-encoded root key
+    pub fn verify(&self, canister_id: &Principal, root_key_der: &[u8]) -> bool {
+        let root_key = match extract_der_key(root_key_der) {
+            Some(k) => k,
+            None => return false,
+        };
+
+        let key = match &self.delegation {
+            Some(delegation) => match delegation.verify(canister_id, root_key) {
+                Some(k) => k,
+                None => return false,
+            },
+            None => root_key.to_vec(),
+        };
+
+        self.verify_with_key(&key)
+    }
+
+    pub fn get_certified_data(&self, canister_id: &Principal) -> Option<&[u8]> {
+        self.tree
+            .lookup_path(&[b"canister", canister_id.as_slice(), b"certified_data"])
+    }
+
+    pub fn get_time(&self) -> Option<u64> {
+        decode_leb128(self.tree.lookup_path(&[b"time"])?)
+    }
+
+    fn verify_with_key(&self, raw_key: &[u8]) -> bool {
+        let mut msg = domain_separator("ic-state-root");
+        msg.extend_from_slice(&self.tree.reconstruct());
+
+        verify_bls_signature(&self.signature, &msg, raw_key).is_ok()
+    }
+}
+
+pub fn sha256(data: &[u8]) -> Hash {
+    Sha256::digest(data).into()
+}
+
+pub fn decode_leb128(bytes: &[u8]) -> Option<u64> {
+    let mut result = 0u64;
+
+    for (i, byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        if shift >= 64 {
+            return None;
+        }
+
+        result |= ((byte & 0x7f) as u64) << shift;
+
+        if byte & 0x80 == 0 {
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+fn extract_der_key(der: &[u8]) -> Option<&[u8]> {
+    if der.len() != DER_PREFIX.len() + BLS_KEY_LEN || !der.starts_with(&DER_PREFIX) {
+        return None;
+    }
+
+    Some(&der[DER_PREFIX.len()..])
+}
+
+fn is_in_canister_ranges(ranges_cbor: &[u8], canister_id: &Principal) -> Option<bool> {
+    let ranges: Vec<(serde_bytes::ByteBuf, serde_bytes::ByteBuf)> =
+        serde_cbor::from_slice(ranges_cbor).ok()?;
+    let id = canister_id.as_slice();
+
+    Some(
+        ranges
+            .iter()
+            .any(|(from, to)| from.as_slice() <= id && id <= to.as_slice()),
+    )
+}
+
+fn domain_separator(name: &str) -> Blob {
+    let mut result = vec![name.len() as u8];
+    result.extend_from_slice(name.as_bytes());
+
+    result
+}
+
+fn hash_with_domain(name: &str, parts: &[&[u8]]) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(domain_separator(name));
+
+    for part in parts {
+        hasher.update(part);
+    }
+
+    hasher.finalize().into()
+}
+
+fn as_bytes(value: &Value) -> Option<&Blob> {
+    match value {
+        Value::Bytes(bytes) => Some(bytes),
+        _ => None,
+    }
+}
+
+fn text(it: &str) -> Value {
+    Value::Text(String::from(it))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::certification::{decode_leb128, Certificate, HashTree};
+
+    fn labeled(label: &[u8], subtree: HashTree) -> HashTree {
+        HashTree::Labeled(label.to_vec(), Box::new(subtree))
+    }
+
+    fn fork(left: HashTree, right: HashTree) -> HashTree {
+        HashTree::Fork(Box::new(left), Box::new(right))
+    }
+
+    #[test]
+    fn lookup_works_through_forks_and_pruned_nodes() {
+        let tree = fork(
+            labeled(b"a", HashTree::Leaf(b"1".to_vec())),
+            fork(
+                HashTree::Pruned([7u8; 32]),
+                labeled(b"b", labeled(b"c", HashTree::Leaf(b"2".to_vec()))),
+            ),
+        );
+
+        assert_eq!(tree.lookup_path(&[b"a"]), Some(b"1".as_slice()));
+        assert_eq!(tree.lookup_path(&[b"b", b"c"]), Some(b"2".as_slice()));
+        assert_eq!(tree.lookup_path(&[b"b"]), None);
+        assert_eq!(tree.lookup_path(&[b"d"]), None);
+    }
+
+    #[test]
+    fn pruning_keeps_the_root_hash() {
+        let left = labeled(b"a", HashTree::Leaf(b"1".to_vec()));
+        let right = labeled(b"b", HashTree::Leaf(b"2".to_vec()));
+
+        let full = fork(left.clone(), right.clone());
+        let pruned = fork(left, HashTree::Pruned(right.reconstruct()));
+
+        assert_eq!(full.reconstruct(), pruned.reconstruct());
+        assert_ne!(
+            full.reconstruct(),
+            fork(
+                labeled(b"a", HashTree::Leaf(b"1".to_vec())),
+                labeled(b"b", HashTree::Leaf(b"3".to_vec())),
+            )
+            .reconstruct()
+        );
+    }
+
+    #[test]
+    fn cbor_roundtrip_works() {
+        let tree = fork(
+            labeled(b"time", HashTree::Leaf(vec![0x80, 0x01])),
+            fork(HashTree::Empty, HashTree::Pruned([1u8; 32])),
+        );
+
+        assert_eq!(HashTree::from_cbor(&tree.to_cbor()), Some(tree.clone()));
+
+        let certificate = Certificate {
+            tree: tree.clone(),
+            signature: vec![1, 2, 3],
+            delegation: None,
+        };
+        let decoded = Certificate::from_cbor(&certificate.to_cbor()).unwrap();
+
+        assert_eq!(decoded.tree, tree);
+        assert_eq!(decoded.signature, vec![1, 2, 3]);
+        assert!(decoded.delegation.is_none());
+        assert_eq!(decoded.get_time(), Some(128));
+    }
+
+    #[test]
+    fn leb128_decoding_works() {
+        assert_eq!(decode_leb128(&[0x00]), Some(0));
+        assert_eq!(decode_leb128(&[0xe5, 0x8e, 0x26]), Some(624485));
+        assert_eq!(decode_leb128(&[0x80]), None);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod candid;
+pub mod certification;
 pub mod management_canister_client;
 pub mod mvc;
 pub mod pageable;
@@ -1,14 +1,22 @@
 use crate::candid::{CandidCallResult, ToCandidType};
 use crate::types::Blob;
 use crate::validation::ValidationError;
-use candid::parser::value::IDLValue;
+use candid::parser::types::IDLProg;
+use candid::parser::value::{IDLArgs, IDLValue};
 use candid::ser::IDLBuilder;
+use candid::types::Type;
 use candid::utils::{ArgumentDecoder, ArgumentEncoder};
-use candid::{decode_args, encode_args, CandidType, Deserialize, Principal};
-use ic_cdk::api::call::call_raw;
+use candid::{check_prog, decode_args, encode_args, CandidType, Deserialize, Principal, TypeEnv};
+use ic_cdk::api::call::{call, call_raw, CallResult};
 use ic_cdk::{id, print};
+use std::collections::{BTreeMap, BTreeSet};
 
 const WILDCARD: &str = "*";
+const ANY_SEQUENCE: char = '*';
+const ANY_CHAR: char = '?';
+
+// methods canisters usually expose their candid interface with
+const CANDID_INTERFACE_METHODS: [&str; 2] = ["__get_candid_interface_tmp_hack", "export_candid"];
 
 #[derive(CandidType, Deserialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct RemoteCallEndpoint {
@@ -41,6 +49,58 @@ impl RemoteCallEndpoint {
             method_name: String::from(WILDCARD),
         }
     }
+
+    // a glob method name, like "get_*" or "list_*_by_?d", the wildcard is a pattern too
+    pub fn is_pattern(&self) -> bool {
+        self.method_name.contains(&[ANY_SEQUENCE, ANY_CHAR][..])
+    }
+
+    // the literal part of the method name every matching method should start with
+    pub fn get_pattern_prefix(&self) -> &str {
+        match self.method_name.find(&[ANY_SEQUENCE, ANY_CHAR][..]) {
+            Some(idx) => &self.method_name[..idx],
+            None => &self.method_name,
+        }
+    }
+
+    pub fn matches(&self, endpoint: &RemoteCallEndpoint) -> bool {
+        if self.canister_id != endpoint.canister_id {
+            return false;
+        }
+
+        if self.is_pattern() {
+            glob_matches(&self.method_name, &endpoint.method_name)
+        } else {
+            self.method_name == endpoint.method_name
+        }
+    }
+}
+
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // the last seen '*' and the text position it is tried to match up to
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == ANY_CHAR || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == ANY_SEQUENCE {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            backtrack = Some((star_p, star_t + 1));
+            p = star_p + 1;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|it| *it == ANY_SEQUENCE)
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -65,6 +125,29 @@ impl RemoteCallArgs {
         }
     }
 
+    // checks arity and types of the args against the argument types of a method
+    pub fn typecheck(&self, env: &TypeEnv, arg_types: &[Type]) -> Result<(), ValidationError> {
+        match self {
+            RemoteCallArgs::CandidString(str_args) => {
+                if str_args.len() > arg_types.len() {
+                    return Err(ValidationError(format!(
+                        "Expected at most {} arguments, got {}",
+                        arg_types.len(),
+                        str_args.len()
+                    )));
+                }
+
+                IDLArgs::new(&self.decode_args()?)
+                    .annotate_types(true, env, arg_types)
+                    .map(|_| ())
+                    .map_err(|e| ValidationError(format!("Arguments type mismatch: {}", e)))
+            }
+            RemoteCallArgs::Encoded(blob) => IDLArgs::from_bytes_with_types(blob, env, arg_types)
+                .map(|_| ())
+                .map_err(|e| ValidationError(format!("Arguments type mismatch: {}", e))),
+        }
+    }
+
     pub fn serialize_args(&self) -> Result<Vec<u8>, ValidationError> {
         match self {
             RemoteCallArgs::CandidString(str_args) => {
@@ -87,6 +170,22 @@ impl RemoteCallArgs {
             RemoteCallArgs::Encoded(blob) => Ok(blob.clone()),
         }
     }
+
+    pub fn decode_args(&self) -> Result<Vec<IDLValue>, ValidationError> {
+        match self {
+            RemoteCallArgs::CandidString(str_args) => str_args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    arg.parse::<IDLValue>()
+                        .map_err(|e| ValidationError(format!("Invalid argument #{}: {:?}", i, e)))
+                })
+                .collect(),
+            RemoteCallArgs::Encoded(blob) => IDLArgs::from_bytes(blob)
+                .map(|it| it.args)
+                .map_err(|e| ValidationError(format!("Arguments decoding failed {:?}", e))),
+        }
+    }
 }
 
 #[derive(CandidType, Deserialize, Debug, Clone)]
@@ -151,6 +250,54 @@ pub trait ArgumentDecoderOwned: for<'de> ArgumentDecoder<'de> {}
 
 impl<T> ArgumentDecoderOwned for T where T: for<'de> ArgumentDecoder<'de> {}
 
+// Method signatures of a canister, parsed from its candid interface
+pub struct CandidInterface {
+    env: TypeEnv,
+    actor: Type,
+}
+
+impl CandidInterface {
+    pub fn parse(did: &str) -> Result<Self, ValidationError> {
+        let prog: IDLProg = did
+            .parse()
+            .map_err(|e| ValidationError(format!("Invalid candid interface: {}", e)))?;
+
+        let mut env = TypeEnv::new();
+        let actor = check_prog(&mut env, &prog)
+            .map_err(|e| ValidationError(format!("Invalid candid interface: {}", e)))?
+            .ok_or_else(|| ValidationError(String::from("Candid interface has no service")))?;
+
+        Ok(Self { env, actor })
+    }
+
+    pub async fn fetch(canister_id: Principal) -> Result<Self, ValidationError> {
+        for method_name in CANDID_INTERFACE_METHODS {
+            let res: CallResult<(String,)> = call(canister_id, method_name, ()).await;
+
+            if let Ok((did,)) = res {
+                return Self::parse(&did);
+            }
+        }
+
+        Err(ValidationError(format!(
+            "Unable to fetch candid interface of {}",
+            canister_id
+        )))
+    }
+
+    pub fn typecheck_call(&self, call: &RemoteCallPayload) -> Result<(), ValidationError> {
+        let method_name = &call.endpoint.method_name;
+        let func = self.env.get_method(&self.actor, method_name).map_err(|_| {
+            ValidationError(format!(
+                "Method {} not found in the interface of {}",
+                method_name, call.endpoint.canister_id
+            ))
+        })?;
+
+        call.args.typecheck(&self.env, &func.args)
+    }
+}
+
 #[derive(CandidType, Deserialize, Debug, Clone)]
 pub enum Program {
     Empty,
@@ -177,6 +324,64 @@ impl Program {
         }
     }
 
+    // fetches interfaces of target canisters, the given one is used for calls to this canister;
+    // calls to canisters which don't expose their interface (e.g. aaaaa-aa) are not typechecked
+    pub async fn typecheck(&self, this_interface: &CandidInterface) -> Result<(), ValidationError> {
+        let seq = match self {
+            Program::Empty => return Ok(()),
+            Program::RemoteCallSequence(seq) => seq,
+        };
+
+        let this_canister_id = id();
+        let remote_canister_ids: BTreeSet<_> = seq
+            .iter()
+            .map(|call| call.endpoint.canister_id)
+            .filter(|it| *it != this_canister_id)
+            .collect();
+
+        let mut interfaces = BTreeMap::new();
+        for canister_id in remote_canister_ids {
+            if let Ok(interface) = CandidInterface::fetch(canister_id).await {
+                interfaces.insert(canister_id, interface);
+            }
+        }
+
+        for (i, call) in seq.iter().enumerate() {
+            let interface = if call.endpoint.canister_id == this_canister_id {
+                this_interface
+            } else {
+                match interfaces.get(&call.endpoint.canister_id) {
+                    Some(it) => it,
+                    None => continue,
+                }
+            };
+
+            interface
+                .typecheck_call(call)
+                .map_err(|e| ValidationError(format!("Invalid call #{}: {}", i, e.0)))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_total_cycles(&self) -> u64 {
+        match self {
+            Program::Empty => 0,
+            Program::RemoteCallSequence(seq) => seq
+                .iter()
+                .fold(0u64, |acc, call| acc.saturating_add(call.cycles)),
+        }
+    }
+
+    pub fn get_max_cycles_per_call(&self) -> u64 {
+        match self {
+            Program::Empty => 0,
+            Program::RemoteCallSequence(seq) => {
+                seq.iter().map(|call| call.cycles).max().unwrap_or_default()
+            }
+        }
+    }
+
     pub async fn execute(&self) -> ProgramExecutionResult {
         match self {
             Program::Empty => ProgramExecutionResult::Empty,
@@ -204,3 +409,48 @@ impl Program {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::remote_call::{glob_matches, RemoteCallEndpoint};
+    use candid::Principal;
+
+    #[test]
+    fn glob_matches_literals_and_wildcards() {
+        assert!(glob_matches("transfer", "transfer"));
+        assert!(!glob_matches("transfer", "transfer_from"));
+
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("*", "anything"));
+        assert!(glob_matches("get_*", "get_"));
+        assert!(glob_matches("get_*", "get_balance"));
+        assert!(!glob_matches("get_*", "set_balance"));
+
+        assert!(glob_matches("list_*_by_?d", "list_tokens_by_id"));
+        assert!(!glob_matches("list_*_by_?d", "list_tokens_by_d"));
+        assert!(glob_matches("*_by_*_id", "get_by_user_by_group_id"));
+        assert!(!glob_matches("*_by_*_id", "get_by_user_name"));
+
+        assert!(glob_matches("?", "a"));
+        assert!(!glob_matches("?", ""));
+        assert!(!glob_matches("?", "ab"));
+    }
+
+    #[test]
+    fn endpoint_matches_only_on_the_same_canister() {
+        let canister = Principal::from_slice(&[1, 2, 3, 4, 5]);
+        let other_canister = Principal::from_slice(&[6, 7, 8, 9, 10]);
+
+        let pattern = RemoteCallEndpoint::new(canister, "get_*");
+        assert!(pattern.is_pattern());
+        assert_eq!(pattern.get_pattern_prefix(), "get_");
+
+        assert!(pattern.matches(&RemoteCallEndpoint::new(canister, "get_balance")));
+        assert!(!pattern.matches(&RemoteCallEndpoint::new(other_canister, "get_balance")));
+
+        let exact = RemoteCallEndpoint::new(canister, "transfer");
+        assert!(!exact.is_pattern());
+        assert!(exact.matches(&RemoteCallEndpoint::new(canister, "transfer")));
+        assert!(!exact.matches(&RemoteCallEndpoint::new(canister, "transfer_from")));
+    }
+}
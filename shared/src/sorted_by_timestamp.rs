@@ -53,6 +53,32 @@ impl<T: Ord> SortedByTimestamp<T> {
         }
     }
 
+    pub fn most_actual_timestamp_by(&self, timestamp: &u64) -> Option<u64> {
+        match self.0.binary_search_by(|it| it.timestamp.cmp(timestamp)) {
+            Ok(idx) => Some(self.0[idx].timestamp),
+            Err(idx) => {
+                if idx == 0 {
+                    None
+                } else {
+                    Some(self.0[idx - 1].timestamp)
+                }
+            }
+        }
+    }
+
+    pub fn next_timestamp_after(&self, timestamp: &u64) -> Option<u64> {
+        let idx = match self.0.binary_search_by(|it| it.timestamp.cmp(timestamp)) {
+            Ok(idx) => idx + 1,
+            Err(idx) => idx,
+        };
+
+        self.0.get(idx).map(|it| it.timestamp)
+    }
+
+    pub fn get_timestamps(&self) -> Vec<u64> {
+        self.0.iter().map(|it| it.timestamp).collect()
+    }
+
     pub fn get_all(&self) -> Vec<&T> {
         let mut result = vec![];
 
@@ -1,5 +1,60 @@
-use candid::{CandidType, Deserialize, Principal};
+use crate::certification::{Certificate, HashTree};
 use crate::types::wallet::{GroupId, Shares};
+use crate::types::Blob;
+use candid::{decode_one, encode_one, CandidType, Deserialize, Principal};
+
+pub const BALANCE_SNAPSHOT_LABEL: &[u8] = b"balance";
+pub const TOTAL_SUPPLY_SNAPSHOT_LABEL: &[u8] = b"total_supply";
+
+// A value certified by the history ledger, which is actual from the timestamp in its key
+// and until the 'valid_to' timestamp (exclusive) or forever, if it is the latest one
+#[derive(Clone, CandidType, Deserialize, PartialEq, Debug)]
+pub struct SharesSnapshot {
+    pub value: Shares,
+    pub valid_to: Option<u64>,
+}
+
+impl SharesSnapshot {
+    pub fn encode(&self) -> Blob {
+        encode_one(self).unwrap()
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        decode_one(bytes).ok()
+    }
+}
+
+pub fn balance_snapshot_key(group_id: GroupId, of: Principal, valid_from: u64) -> Blob {
+    let principal = of.as_slice();
+
+    let mut key = BALANCE_SNAPSHOT_LABEL.to_vec();
+    key.extend_from_slice(&group_id.to_be_bytes());
+    key.push(principal.len() as u8);
+    key.extend_from_slice(principal);
+    key.extend_from_slice(&valid_from.to_be_bytes());
+
+    key
+}
+
+pub fn total_supply_snapshot_key(group_id: GroupId, valid_from: u64) -> Blob {
+    let mut key = TOTAL_SUPPLY_SNAPSHOT_LABEL.to_vec();
+    key.extend_from_slice(&group_id.to_be_bytes());
+    key.extend_from_slice(&valid_from.to_be_bytes());
+
+    key
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct SharesInfoSignature {
+    // CBOR-encoded IC state certificate of the history ledger
+    pub certificate: Blob,
+    pub balance_valid_from: u64,
+    // CBOR-encoded hash tree witness of the balance snapshot
+    pub balance_witness: Blob,
+    pub total_supply_valid_from: u64,
+    // CBOR-encoded hash tree witness of the total supply snapshot
+    pub total_supply_witness: Blob,
+}
 
 #[derive(Clone, CandidType, Deserialize)]
 pub struct SharesInfo {
@@ -8,13 +63,316 @@ pub struct SharesInfo {
     pub timestamp: u64,
     pub group_id: GroupId,
     pub principal_id: Principal,
-    // TODO: implement subnet signature
-    pub signature: (),
+    // only present when the info is fetched with a non-replicated query call
+    pub signature: Option<SharesInfoSignature>,
 }
 
 impl SharesInfo {
-    pub fn is_signature_valid(&self) -> bool {
-        // TODO: implement subnet signature
-        true
+    // the root key is DER-encoded, see Certificate::verify()
+    pub fn is_signature_valid(&self, ledger_id: &Principal, root_key: &[u8]) -> bool {
+        self.is_signature_valid_with(ledger_id, |certificate| {
+            certificate.verify(ledger_id, root_key)
+        })
+    }
+
+    pub fn is_signature_valid_with<F: Fn(&Certificate) -> bool>(
+        &self,
+        ledger_id: &Principal,
+        verify_certificate: F,
+    ) -> bool {
+        self.check_signature(ledger_id, verify_certificate)
+            .unwrap_or(false)
+    }
+
+    fn check_signature<F: Fn(&Certificate) -> bool>(
+        &self,
+        ledger_id: &Principal,
+        verify_certificate: F,
+    ) -> Option<bool> {
+        let signature = self.signature.as_ref()?;
+
+        let certificate = Certificate::from_cbor(&signature.certificate)?;
+        if !verify_certificate(&certificate) {
+            return Some(false);
+        }
+
+        let certified_data = certificate.get_certified_data(ledger_id)?;
+        let certified_at = certificate.get_time()?;
+
+        let balance_key = balance_snapshot_key(
+            self.group_id,
+            self.principal_id,
+            signature.balance_valid_from,
+        );
+        let balance_ok = Self::check_snapshot(
+            &signature.balance_witness,
+            &balance_key,
+            signature.balance_valid_from,
+            certified_data,
+            certified_at,
+            self.timestamp,
+            &self.balance,
+        )?;
+
+        let total_supply_key =
+            total_supply_snapshot_key(self.group_id, signature.total_supply_valid_from);
+        let total_supply_ok = Self::check_snapshot(
+            &signature.total_supply_witness,
+            &total_supply_key,
+            signature.total_supply_valid_from,
+            certified_data,
+            certified_at,
+            self.timestamp,
+            &self.total_supply,
+        )?;
+
+        Some(balance_ok && total_supply_ok)
+    }
+
+    fn check_snapshot(
+        witness: &[u8],
+        key: &[u8],
+        valid_from: u64,
+        certified_data: &[u8],
+        certified_at: u64,
+        timestamp: u64,
+        expected_value: &Shares,
+    ) -> Option<bool> {
+        let witness = HashTree::from_cbor(witness)?;
+        if witness.reconstruct().as_slice() != certified_data {
+            return Some(false);
+        }
+
+        let snapshot = SharesSnapshot::decode(witness.lookup_path(&[key])?)?;
+
+        let is_actual = match snapshot.valid_to {
+            Some(valid_to) => valid_from <= timestamp && timestamp < valid_to,
+            // the latest snapshot is only known to be actual up to the certification time
+            None => valid_from <= timestamp && timestamp <= certified_at,
+        };
+
+        Some(is_actual && snapshot.value == *expected_value)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::certification::{Certificate, HashTree};
+    use crate::types::history_ledger::{
+        balance_snapshot_key, total_supply_snapshot_key, SharesInfo, SharesInfoSignature,
+        SharesSnapshot,
+    };
+    use crate::types::wallet::Shares;
+    use candid::Principal;
+
+    const STAND_IN_SIGNATURE: &[u8] = b"stand-in subnet signature";
+    const CERTIFIED_AT: u64 = 1_000;
+
+    fn ledger_id() -> Principal {
+        Principal::from_slice(&[0, 0, 0, 0, 0, 0, 0, 1, 1, 1])
+    }
+
+    fn voter() -> Principal {
+        Principal::from_slice(&[1, 2, 3, 4, 5])
+    }
+
+    fn labeled(label: &[u8], subtree: HashTree) -> HashTree {
+        HashTree::Labeled(label.to_vec(), Box::new(subtree))
+    }
+
+    fn fork(left: HashTree, right: HashTree) -> HashTree {
+        HashTree::Fork(Box::new(left), Box::new(right))
+    }
+
+    fn leb128(mut value: u64) -> Vec<u8> {
+        let mut result = vec![];
+
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                result.push(byte);
+                return result;
+            }
+
+            result.push(byte | 0x80);
+        }
+    }
+
+    fn snapshot(value: u64, valid_to: Option<u64>) -> HashTree {
+        HashTree::Leaf(
+            SharesSnapshot {
+                value: Shares::from(value),
+                valid_to,
+            }
+            .encode(),
+        )
+    }
+
+    // builds a ledger tree with two balance snapshots and a total supply snapshot, then
+    // a certificate over its root hash, mimicking what the replica would return
+    fn certified_shares_info(timestamp: u64) -> SharesInfo {
+        let first_balance = labeled(
+            &balance_snapshot_key(1, voter(), 10),
+            snapshot(50, Some(100)),
+        );
+        let second_balance = labeled(&balance_snapshot_key(1, voter(), 100), snapshot(70, None));
+        let total_supply = labeled(&total_supply_snapshot_key(1, 5), snapshot(200, None));
+
+        let root = fork(
+            fork(first_balance.clone(), second_balance.clone()),
+            total_supply.clone(),
+        )
+        .reconstruct();
+
+        let (balance, balance_valid_from, balance_witness) = if timestamp < 100 {
+            let witness = fork(
+                fork(
+                    first_balance,
+                    HashTree::Pruned(second_balance.reconstruct()),
+                ),
+                HashTree::Pruned(total_supply.reconstruct()),
+            );
+
+            (50u64, 10, witness)
+        } else {
+            let witness = fork(
+                fork(
+                    HashTree::Pruned(first_balance.reconstruct()),
+                    second_balance,
+                ),
+                HashTree::Pruned(total_supply.reconstruct()),
+            );
+
+            (70u64, 100, witness)
+        };
+
+        let total_supply_witness = fork(
+            HashTree::Pruned(
+                fork(
+                    labeled(
+                        &balance_snapshot_key(1, voter(), 10),
+                        snapshot(50, Some(100)),
+                    ),
+                    labeled(&balance_snapshot_key(1, voter(), 100), snapshot(70, None)),
+                )
+                .reconstruct(),
+            ),
+            total_supply,
+        );
+
+        let certificate = Certificate {
+            tree: fork(
+                labeled(
+                    b"canister",
+                    labeled(
+                        ledger_id().as_slice(),
+                        labeled(b"certified_data", HashTree::Leaf(root.to_vec())),
+                    ),
+                ),
+                labeled(b"time", HashTree::Leaf(leb128(CERTIFIED_AT))),
+            ),
+            signature: STAND_IN_SIGNATURE.to_vec(),
+            delegation: None,
+        };
+
+        SharesInfo {
+            balance: Shares::from(balance),
+            total_supply: Shares::from(200u64),
+            timestamp,
+            group_id: 1,
+            principal_id: voter(),
+            signature: Some(SharesInfoSignature {
+                certificate: certificate.to_cbor(),
+                balance_valid_from,
+                balance_witness: balance_witness.to_cbor(),
+                total_supply_valid_from: 5,
+                total_supply_witness: total_supply_witness.to_cbor(),
+            }),
+        }
+    }
+
+    fn is_valid(info: &SharesInfo) -> bool {
+        info.is_signature_valid_with(&ledger_id(), |certificate| {
+            certificate.signature.as_slice() == STAND_IN_SIGNATURE
+        })
+    }
+
+    #[test]
+    fn certified_info_is_accepted() {
+        assert!(is_valid(&certified_shares_info(50)));
+        assert!(is_valid(&certified_shares_info(100)));
+        assert!(is_valid(&certified_shares_info(CERTIFIED_AT)));
+    }
+
+    #[test]
+    fn tampered_values_are_rejected() {
+        let mut info = certified_shares_info(50);
+        info.balance = Shares::from(51u64);
+        assert!(!is_valid(&info));
+
+        let mut info = certified_shares_info(50);
+        info.total_supply = Shares::from(100u64);
+        assert!(!is_valid(&info));
+
+        let mut info = certified_shares_info(50);
+        info.principal_id = Principal::from_slice(&[9, 9, 9]);
+        assert!(!is_valid(&info));
+
+        let mut info = certified_shares_info(50);
+        info.group_id = 2;
+        assert!(!is_valid(&info));
+    }
+
+    #[test]
+    fn timestamp_outside_of_snapshot_is_rejected() {
+        // the balance of 50 is only actual until 100
+        let mut info = certified_shares_info(50);
+        info.timestamp = 100;
+        assert!(!is_valid(&info));
+
+        // the latest balance can't be vouched for after the certification time
+        let mut info = certified_shares_info(100);
+        info.timestamp = CERTIFIED_AT + 1;
+        assert!(!is_valid(&info));
+    }
+
+    #[test]
+    fn tampered_witness_is_rejected() {
+        let mut info = certified_shares_info(50);
+        let signature = info.signature.as_mut().unwrap();
+
+        let forged = fork(
+            labeled(
+                &balance_snapshot_key(1, voter(), 10),
+                snapshot(1_000, Some(100)),
+            ),
+            HashTree::Empty,
+        );
+        signature.balance_witness = forged.to_cbor();
+
+        assert!(!is_valid(&info));
+    }
+
+    #[test]
+    fn foreign_ledger_is_rejected() {
+        let info = certified_shares_info(50);
+
+        assert!(!info.is_signature_valid_with(&voter(), |_| true));
+    }
+
+    #[test]
+    fn invalid_or_missing_certificate_is_rejected() {
+        let info = certified_shares_info(50);
+        assert!(!info.is_signature_valid_with(&ledger_id(), |_| false));
+
+        let mut info = certified_shares_info(50);
+        info.signature = None;
+        assert!(!is_valid(&info));
+
+        let mut info = certified_shares_info(50);
+        info.signature.as_mut().unwrap().certificate = vec![1, 2, 3];
+        assert!(!is_valid(&info));
+    }
+}
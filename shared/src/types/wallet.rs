@@ -36,6 +36,13 @@ pub struct ProgramExecutedEvent_2 {
     pub result: ProgramExecutionResult,
 }
 
+// only emitted for programs approved in the multisig mode of an access config
+#[derive(Event)]
+pub struct ProgramExecutedEvent_3 {
+    pub timestamp: u64,
+    pub approvers: Vec<Principal>,
+}
+
 #[derive(Clone, CandidType, Deserialize)]
 pub enum Shareholder {
     Void,
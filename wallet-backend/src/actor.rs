@@ -16,6 +16,7 @@ use ic_cron::implement_cron;
 use ic_event_hub::{implement_event_emitter, implement_subscribe, implement_unsubscribe};
 use shared::time::secs;
 use shared::types::wallet::Shares;
+use shared::types::Blob;
 
 pub mod client;
 pub mod common;
@@ -31,6 +32,7 @@ pub struct InitRequest {
     pub wallet_creator: Principal,
     pub union_name: String,
     pub union_description: String,
+    pub ic_root_key: Option<Blob>,
 }
 
 #[init]
@@ -39,6 +41,7 @@ fn init(req: InitRequest) {
         req.history_ledger,
         req.union_name,
         req.union_description,
+        req.ic_root_key,
         time(),
     );
 
@@ -53,7 +56,7 @@ fn init(req: InitRequest) {
 
     PermissionService::init_allow_write_permissions(id());
     VotingConfigService::init_default_voting_configs();
-    AccessConfigService::init_default_access_configs(req.wallet_creator);
+    AccessConfigService::init_default_access_configs(req.wallet_creator, time());
 }
 
 #[post_upgrade]
@@ -64,6 +67,9 @@ fn post_upgrade_hook() {
     set_settings(settings);
     _put_cron_state(cron);
     _put_event_hub_state(events);
+
+    AccessConfigService::rebuild_query_delegation_certification(id());
+    PermissionService::refresh_generated_permissions(id());
 }
 
 #[pre_upgrade]
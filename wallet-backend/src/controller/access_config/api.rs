@@ -1,10 +1,19 @@
 use crate::repository::access_config::model::AccessConfig;
-use crate::repository::access_config::types::{AccessConfigFilter, AlloweeConstraint};
-use crate::repository::permission::types::{PermissionId, PermissionTarget};
-use crate::service::access_config::types::QueryDelegationProof;
-use candid::{CandidType, Deserialize};
+use crate::repository::access_config::types::{
+    AccessConfigFilter, AccessConfigQuota, AccessConfigValidity, AlloweeConstraint,
+    MultisigSettings,
+};
+use crate::repository::multisig_proposal::model::MultisigProposal;
+use crate::repository::multisig_proposal::types::{MultisigProposalFilter, MultisigProposalId};
+use crate::repository::permission::types::{CyclesLimits, PermissionId, PermissionTarget};
+use crate::repository::query_delegation::types::QueryDelegationId;
+use crate::service::access_config::types::{
+    EndpointAccessExplanation, ExecutionCheck, PrincipalAccessExplanation, QueryDelegationProof,
+    RemainingQuota,
+};
+use candid::{CandidType, Deserialize, Principal};
 use shared::pageable::{Page, PageRequest};
-use shared::remote_call::{Program, ProgramExecutionResult};
+use shared::remote_call::{Program, ProgramExecutionResult, RemoteCallEndpoint};
 use shared::types::wallet::AccessConfigId;
 use std::collections::BTreeSet;
 
@@ -19,12 +28,73 @@ pub struct ExecuteResponse {
     pub result: ProgramExecutionResult,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct ProposeMultisigProgramRequest {
+    pub access_config_id: AccessConfigId,
+    pub program: Program,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ProposeMultisigProgramResponse {
+    pub id: MultisigProposalId,
+    // set, if the proposal got executed right away
+    pub result: Option<ProgramExecutionResult>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ApproveMultisigProposalRequest {
+    pub id: MultisigProposalId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ApproveMultisigProposalResponse {
+    // set, if this approval was the last one needed
+    pub result: Option<ProgramExecutionResult>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct RejectMultisigProposalRequest {
+    pub id: MultisigProposalId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CancelMultisigProposalRequest {
+    pub id: MultisigProposalId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetMultisigProposalRequest {
+    pub id: MultisigProposalId,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetMultisigProposalResponse {
+    pub proposal: MultisigProposal,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListMultisigProposalsRequest {
+    pub page_req: PageRequest<MultisigProposalFilter, ()>,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListMultisigProposalsResponse {
+    pub page: Page<MultisigProposal>,
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct CreateAccessConfigRequest {
     pub name: String,
     pub description: String,
     pub permissions: BTreeSet<PermissionId>,
     pub allowees: BTreeSet<AlloweeConstraint>,
+    pub query_delegation_expiry: Option<u64>,
+    pub cycles_limits: CyclesLimits,
+    pub quota: AccessConfigQuota,
+    pub multisig: Option<MultisigSettings>,
+    pub validity: AccessConfigValidity,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -39,6 +109,11 @@ pub struct UpdateAccessConfigRequest {
     pub new_description: Option<String>,
     pub new_permissions: Option<BTreeSet<PermissionId>>,
     pub new_allowees: Option<BTreeSet<AlloweeConstraint>>,
+    pub new_query_delegation_expiry: Option<Option<u64>>,
+    pub new_cycles_limits: Option<CyclesLimits>,
+    pub new_quota: Option<AccessConfigQuota>,
+    pub new_multisig: Option<Option<MultisigSettings>>,
+    pub new_validity: Option<AccessConfigValidity>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -68,14 +143,83 @@ pub struct ListAccessConfigsResponse {
     pub page: Page<AccessConfig>,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct ExplainEndpointAccessRequest {
+    pub endpoint: RemoteCallEndpoint,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ExplainEndpointAccessResponse {
+    pub explanation: EndpointAccessExplanation,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ExplainPrincipalAccessRequest {
+    pub principal: Principal,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ExplainPrincipalAccessResponse {
+    pub explanation: PrincipalAccessExplanation,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct RevokeQueryDelegateRequest {
+    pub delegate_id: Principal,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListRevokedQueryDelegatesRequest {
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListRevokedQueryDelegatesResponse {
+    pub revoked_delegates: Vec<(Principal, u64)>,
+}
+
 // ----------- PERSONAL ------------
 
 #[derive(CandidType, Deserialize)]
-pub struct GetMyQueryDelegationProofRequest {
+pub struct IssueMyQueryDelegationProofRequest {
     pub requested_targets: BTreeSet<PermissionTarget>,
+    pub expires_in_opt: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct IssueMyQueryDelegationProofResponse {
+    pub proof: QueryDelegationProof,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetMyQueryDelegationProofRequest {
+    pub id: QueryDelegationId,
 }
 
 #[derive(CandidType, Deserialize)]
 pub struct GetMyQueryDelegationProofResponse {
     pub proof: QueryDelegationProof,
 }
+
+#[derive(CandidType, Deserialize)]
+pub struct GetMyRemainingQuotaRequest {
+    pub access_config_id: AccessConfigId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetMyRemainingQuotaResponse {
+    pub remaining_quota: RemainingQuota,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CheckMyExecutionRequest {
+    pub access_config_id: AccessConfigId,
+    pub program: Program,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CheckMyExecutionResponse {
+    pub check: ExecutionCheck,
+}
@@ -1,8 +1,17 @@
 use crate::controller::access_config::api::{
-    CreateAccessConfigRequest, CreateAccessConfigResponse, DeleteAccessConfigRequest,
-    ExecuteRequest, ExecuteResponse, GetAccessConfigRequest, GetAccessConfigResponse,
-    GetMyQueryDelegationProofRequest, GetMyQueryDelegationProofResponse, ListAccessConfigsRequest,
-    ListAccessConfigsResponse, UpdateAccessConfigRequest,
+    ApproveMultisigProposalRequest, ApproveMultisigProposalResponse, CancelMultisigProposalRequest,
+    CheckMyExecutionRequest, CheckMyExecutionResponse, CreateAccessConfigRequest,
+    CreateAccessConfigResponse, DeleteAccessConfigRequest, ExecuteRequest, ExecuteResponse,
+    ExplainEndpointAccessRequest, ExplainEndpointAccessResponse, ExplainPrincipalAccessRequest,
+    ExplainPrincipalAccessResponse, GetAccessConfigRequest, GetAccessConfigResponse,
+    GetMultisigProposalRequest, GetMultisigProposalResponse, GetMyQueryDelegationProofRequest,
+    GetMyQueryDelegationProofResponse, GetMyRemainingQuotaRequest, GetMyRemainingQuotaResponse,
+    IssueMyQueryDelegationProofRequest, IssueMyQueryDelegationProofResponse,
+    ListAccessConfigsRequest, ListAccessConfigsResponse, ListMultisigProposalsRequest,
+    ListMultisigProposalsResponse, ListRevokedQueryDelegatesRequest,
+    ListRevokedQueryDelegatesResponse, ProposeMultisigProgramRequest,
+    ProposeMultisigProgramResponse, RejectMultisigProposalRequest, RevokeQueryDelegateRequest,
+    UpdateAccessConfigRequest,
 };
 use crate::guards::{only_self, only_self_or_with_access};
 use crate::service::access_config::types::AccessConfigService;
@@ -21,6 +30,62 @@ async fn execute(req: ExecuteRequest) -> ExecuteResponse {
     ExecuteResponse { result }
 }
 
+#[update]
+async fn propose_multisig_program(
+    req: ProposeMultisigProgramRequest,
+) -> ProposeMultisigProgramResponse {
+    let (id, result) = AccessConfigService::propose_multisig_program(
+        &req.access_config_id,
+        req.program,
+        caller(),
+        time(),
+    )
+    .await
+    .expect("Unable to propose multisig program");
+
+    ProposeMultisigProgramResponse { id, result }
+}
+
+#[update]
+async fn approve_multisig_proposal(
+    req: ApproveMultisigProposalRequest,
+) -> ApproveMultisigProposalResponse {
+    let result = AccessConfigService::approve_multisig_proposal(&req.id, caller(), time())
+        .await
+        .expect("Unable to approve multisig proposal");
+
+    ApproveMultisigProposalResponse { result }
+}
+
+#[update]
+fn reject_multisig_proposal(req: RejectMultisigProposalRequest) {
+    AccessConfigService::reject_multisig_proposal(&req.id, caller(), time())
+        .expect("Unable to reject multisig proposal");
+}
+
+#[update]
+fn cancel_multisig_proposal(req: CancelMultisigProposalRequest) {
+    AccessConfigService::cancel_multisig_proposal(&req.id, caller(), time())
+        .expect("Unable to cancel multisig proposal");
+}
+
+#[query]
+fn get_multisig_proposal(req: GetMultisigProposalRequest) -> GetMultisigProposalResponse {
+    only_self_or_with_access("get_multisig_proposal", req.query_delegation_proof_opt);
+
+    let proposal = AccessConfigService::get_multisig_proposal(&req.id, time())
+        .expect("Unable to get multisig proposal");
+    GetMultisigProposalResponse { proposal }
+}
+
+#[query]
+fn list_multisig_proposals(req: ListMultisigProposalsRequest) -> ListMultisigProposalsResponse {
+    only_self_or_with_access("list_multisig_proposals", req.query_delegation_proof_opt);
+
+    let page = AccessConfigService::list_multisig_proposals(&req.page_req, time());
+    ListMultisigProposalsResponse { page }
+}
+
 #[update]
 fn create_access_config(req: CreateAccessConfigRequest) -> CreateAccessConfigResponse {
     only_self();
@@ -30,6 +95,12 @@ fn create_access_config(req: CreateAccessConfigRequest) -> CreateAccessConfigRes
         req.description,
         req.permissions,
         req.allowees,
+        req.query_delegation_expiry,
+        req.cycles_limits,
+        req.quota,
+        req.multisig,
+        req.validity,
+        time(),
     )
     .expect("Unable to create access config");
     CreateAccessConfigResponse { id }
@@ -45,6 +116,12 @@ fn update_access_config(req: UpdateAccessConfigRequest) {
         req.new_description,
         req.new_permissions,
         req.new_allowees,
+        req.new_query_delegation_expiry,
+        req.new_cycles_limits,
+        req.new_quota,
+        req.new_multisig,
+        req.new_validity,
+        time(),
     )
     .expect("Unable to update access config");
 }
@@ -73,17 +150,82 @@ fn list_access_configs(req: ListAccessConfigsRequest) -> ListAccessConfigsRespon
     ListAccessConfigsResponse { page }
 }
 
-// ------------- PERSONAL --------------
+#[query]
+fn explain_endpoint_access(req: ExplainEndpointAccessRequest) -> ExplainEndpointAccessResponse {
+    only_self_or_with_access("explain_endpoint_access", req.query_delegation_proof_opt);
+
+    let explanation = AccessConfigService::explain_endpoint_access(id(), &req.endpoint, time());
+    ExplainEndpointAccessResponse { explanation }
+}
 
 #[query]
-fn get_my_query_delegation_proof(
-    req: GetMyQueryDelegationProofRequest,
-) -> GetMyQueryDelegationProofResponse {
-    let proof = AccessConfigService::get_query_delegation_proof(
+fn explain_principal_access(req: ExplainPrincipalAccessRequest) -> ExplainPrincipalAccessResponse {
+    only_self_or_with_access("explain_principal_access", req.query_delegation_proof_opt);
+
+    let explanation = AccessConfigService::explain_principal_access(id(), req.principal, time());
+    ExplainPrincipalAccessResponse { explanation }
+}
+
+#[update]
+fn revoke_query_delegate(req: RevokeQueryDelegateRequest) {
+    only_self();
+
+    AccessConfigService::revoke_query_delegate(req.delegate_id, time());
+}
+
+#[query]
+fn list_revoked_query_delegates(
+    req: ListRevokedQueryDelegatesRequest,
+) -> ListRevokedQueryDelegatesResponse {
+    only_self_or_with_access(
+        "list_revoked_query_delegates",
+        req.query_delegation_proof_opt,
+    );
+
+    let revoked_delegates = AccessConfigService::list_revoked_query_delegates();
+    ListRevokedQueryDelegatesResponse { revoked_delegates }
+}
+
+// ------------- PERSONAL --------------
+
+#[update]
+fn issue_my_query_delegation_proof(
+    req: IssueMyQueryDelegationProofRequest,
+) -> IssueMyQueryDelegationProofResponse {
+    let proof = AccessConfigService::issue_query_delegation_proof(
         id(),
         caller(),
         req.requested_targets,
+        req.expires_in_opt,
         time(),
-    );
+    )
+    .expect("Unable to issue query delegation proof");
+    IssueMyQueryDelegationProofResponse { proof }
+}
+
+#[query]
+fn get_my_query_delegation_proof(
+    req: GetMyQueryDelegationProofRequest,
+) -> GetMyQueryDelegationProofResponse {
+    let proof = AccessConfigService::get_query_delegation_proof(id(), &req.id, caller())
+        .expect("Unable to get query delegation proof");
     GetMyQueryDelegationProofResponse { proof }
 }
+
+#[query]
+fn get_my_remaining_quota(req: GetMyRemainingQuotaRequest) -> GetMyRemainingQuotaResponse {
+    let access_config = AccessConfigService::get_access_config(&req.access_config_id)
+        .expect("Unable to get access config");
+    let remaining_quota =
+        AccessConfigService::get_remaining_quota(&access_config, caller(), time());
+    GetMyRemainingQuotaResponse { remaining_quota }
+}
+
+#[update]
+async fn check_my_execution(req: CheckMyExecutionRequest) -> CheckMyExecutionResponse {
+    let check =
+        AccessConfigService::check_execution(&req.access_config_id, &req.program, caller(), time())
+            .await
+            .expect("Unable to check execution");
+    CheckMyExecutionResponse { check }
+}
@@ -0,0 +1,58 @@
+use crate::repository::comment::model::Comment;
+use crate::repository::comment::types::{CommentFilter, CommentId};
+use crate::service::access_config::types::QueryDelegationProof;
+use candid::{CandidType, Deserialize};
+use shared::pageable::{Page, PageRequest};
+use shared::types::wallet::VotingId;
+
+#[derive(CandidType, Deserialize)]
+pub struct ModerateVotingCommentRequest {
+    pub id: CommentId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetVotingCommentRequest {
+    pub id: CommentId,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetVotingCommentResponse {
+    pub comment: Comment,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVotingCommentsRequest {
+    pub page_req: PageRequest<CommentFilter, ()>,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVotingCommentsResponse {
+    pub page: Page<Comment>,
+}
+
+// ------------------- PERSONAL ------------------
+
+#[derive(CandidType, Deserialize)]
+pub struct CreateMyVotingCommentRequest {
+    pub voting_id: VotingId,
+    pub parent_id: Option<CommentId>,
+    pub text: String,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CreateMyVotingCommentResponse {
+    pub id: CommentId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct UpdateMyVotingCommentRequest {
+    pub id: CommentId,
+    pub new_text: String,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct DeleteMyVotingCommentRequest {
+    pub id: CommentId,
+}
@@ -0,0 +1,58 @@
+use crate::controller::comment::api::{
+    CreateMyVotingCommentRequest, CreateMyVotingCommentResponse, DeleteMyVotingCommentRequest,
+    GetVotingCommentRequest, GetVotingCommentResponse, ListVotingCommentsRequest,
+    ListVotingCommentsResponse, ModerateVotingCommentRequest, UpdateMyVotingCommentRequest,
+};
+use crate::guards::{only_self, only_self_or_with_access};
+use crate::service::comment::types::CommentService;
+use ic_cdk::api::time;
+use ic_cdk::caller;
+use ic_cdk_macros::{query, update};
+
+pub mod api;
+
+#[update]
+fn moderate_voting_comment(req: ModerateVotingCommentRequest) {
+    only_self();
+
+    CommentService::moderate_comment(&req.id, time()).expect("Unable to moderate voting comment");
+}
+
+#[query]
+fn get_voting_comment(req: GetVotingCommentRequest) -> GetVotingCommentResponse {
+    only_self_or_with_access("get_voting_comment", req.query_delegation_proof_opt);
+
+    let comment = CommentService::get_comment(&req.id).expect("Unable to get voting comment");
+    GetVotingCommentResponse { comment }
+}
+
+#[query]
+fn list_voting_comments(req: ListVotingCommentsRequest) -> ListVotingCommentsResponse {
+    only_self_or_with_access("list_voting_comments", req.query_delegation_proof_opt);
+
+    let page = CommentService::list_comments(&req.page_req);
+    ListVotingCommentsResponse { page }
+}
+
+// ------------------- PERSONAL ----------------------
+
+#[update]
+fn create_my_voting_comment(req: CreateMyVotingCommentRequest) -> CreateMyVotingCommentResponse {
+    let id =
+        CommentService::create_comment(req.voting_id, req.parent_id, caller(), req.text, time())
+            .expect("Unable to create voting comment");
+
+    CreateMyVotingCommentResponse { id }
+}
+
+#[update]
+fn update_my_voting_comment(req: UpdateMyVotingCommentRequest) {
+    CommentService::update_comment(&req.id, caller(), req.new_text, time())
+        .expect("Unable to update voting comment");
+}
+
+#[update]
+fn delete_my_voting_comment(req: DeleteMyVotingCommentRequest) {
+    CommentService::delete_comment(&req.id, caller(), time())
+        .expect("Unable to delete voting comment");
+}
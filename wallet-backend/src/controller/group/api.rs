@@ -1,4 +1,11 @@
 use crate::repository::group::model::Group;
+use crate::repository::group::types::{GroupRule, VoteEscrowSettings};
+use crate::repository::vesting_grant::model::VestingGrant;
+use crate::repository::vesting_grant::types::{
+    VestingGrantFilter, VestingGrantId, VestingSchedule,
+};
+use crate::repository::vote_escrow::model::VoteEscrowLock;
+use crate::repository::vote_escrow::types::{VoteEscrowLockFilter, VoteEscrowLockId};
 use crate::service::access_config::types::QueryDelegationProof;
 use candid::{CandidType, Deserialize, Principal};
 use shared::pageable::{Page, PageRequest};
@@ -16,6 +23,7 @@ pub struct CreateGroupRequest {
     pub description: String,
     pub private: bool,
     pub transferable: bool,
+    pub parent: Option<GroupId>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -23,11 +31,26 @@ pub struct CreateGroupResponse {
     pub group_id: GroupId,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct CreateComputedGroupRequest {
+    pub name: String,
+    pub description: String,
+    pub rule: GroupRule,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct UpdateComputedGroupRuleRequest {
+    pub group_id: GroupId,
+    pub new_rule: GroupRule,
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct UpdateGroupRequest {
     pub group_id: GroupId,
     pub new_name: Option<String>,
     pub new_description: Option<String>,
+    pub new_vote_escrow: Option<Option<VoteEscrowSettings>>,
+    pub new_parent: Option<Option<GroupId>>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -35,6 +58,11 @@ pub struct DeleteGroupRequest {
     pub group_id: GroupId,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct ConvertGroupRequest {
+    pub group_id: GroupId,
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct GetGroupRequest {
     pub group_id: GroupId,
@@ -46,6 +74,17 @@ pub struct GetGroupResponse {
     pub group: GroupExt,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct ListGroupRelativesRequest {
+    pub group_id: GroupId,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListGroupRelativesResponse {
+    pub group_ids: Vec<GroupId>,
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct ListGroupsRequest {
     pub page_req: PageRequest<(), ()>,
@@ -159,3 +198,84 @@ pub struct GetGroupsOfRequest {
 pub struct GetGroupsResponse {
     pub groups: Vec<GroupExt>,
 }
+
+#[derive(CandidType, Deserialize)]
+pub struct VestingGrantExt {
+    pub it: VestingGrant,
+    pub vested: Shares,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CreateVestingGrantRequest {
+    pub group_id: GroupId,
+    pub beneficiary: Principal,
+    pub qty: Shares,
+    pub schedule: VestingSchedule,
+    pub revocable: bool,
+    pub voting_power: bool,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CreateVestingGrantResponse {
+    pub id: VestingGrantId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct RevokeVestingGrantRequest {
+    pub id: VestingGrantId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetVestingGrantRequest {
+    pub id: VestingGrantId,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct GetVestingGrantResponse {
+    pub grant: VestingGrantExt,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVestingGrantsRequest {
+    pub page_req: PageRequest<VestingGrantFilter, ()>,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVestingGrantsResponse {
+    pub page: Page<VestingGrantExt>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListMyVestingGrantsRequest {
+    pub page_req: PageRequest<(), ()>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct LockMyGroupSharesForVotingPowerRequest {
+    pub group_id: GroupId,
+    pub qty: Shares,
+    pub duration: u64,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct LockMyGroupSharesForVotingPowerResponse {
+    pub id: VoteEscrowLockId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVoteEscrowLocksRequest {
+    pub page_req: PageRequest<VoteEscrowLockFilter, ()>,
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListVoteEscrowLocksResponse {
+    pub page: Page<VoteEscrowLock>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListMyVoteEscrowLocksRequest {
+    pub page_req: PageRequest<(), ()>,
+}
@@ -1,18 +1,27 @@
 use crate::controller::group::api::{
     AcceptMyGroupSharesRequest, BurnGroupSharesRequest, BurnMyGroupSharesRequest,
-    CreateGroupRequest, CreateGroupResponse, DeclineMyGroupSharesRequest, DeleteGroupRequest,
-    GetGroupRequest, GetGroupResponse, GetGroupSharesBalanceOfRequest,
+    ConvertGroupRequest, CreateComputedGroupRequest, CreateGroupRequest, CreateGroupResponse,
+    CreateVestingGrantRequest, CreateVestingGrantResponse, DeclineMyGroupSharesRequest,
+    DeleteGroupRequest, GetGroupRequest, GetGroupResponse, GetGroupSharesBalanceOfRequest,
     GetGroupSharesBalanceOfResponse, GetGroupsOfRequest, GetGroupsResponse,
     GetMyGroupSharesBalanceRequest, GetMyGroupSharesBalanceResponse, GetTotalGroupSharesRequest,
-    GetTotalGroupSharesResponse, ListGroupSharesRequest, ListGroupSharesResponse,
-    ListGroupsRequest, ListGroupsResponse, MintGroupSharesRequest, TransferGroupSharesRequest,
-    TransferMyGroupSharesRequest, UpdateGroupRequest,
+    GetTotalGroupSharesResponse, GetVestingGrantRequest, GetVestingGrantResponse,
+    ListGroupRelativesRequest, ListGroupRelativesResponse, ListGroupSharesRequest,
+    ListGroupSharesResponse, ListGroupsRequest, ListGroupsResponse, ListMyVestingGrantsRequest,
+    ListMyVoteEscrowLocksRequest, ListVestingGrantsRequest, ListVestingGrantsResponse,
+    ListVoteEscrowLocksRequest, ListVoteEscrowLocksResponse,
+    LockMyGroupSharesForVotingPowerRequest, LockMyGroupSharesForVotingPowerResponse,
+    MintGroupSharesRequest, RevokeVestingGrantRequest, TransferGroupSharesRequest,
+    TransferMyGroupSharesRequest, UpdateComputedGroupRuleRequest, UpdateGroupRequest,
 };
 use crate::guards::{only_self, only_self_or_with_access};
+use crate::repository::vesting_grant::types::VestingGrantFilter;
+use crate::repository::vote_escrow::types::VoteEscrowLockFilter;
 use crate::service::group::types::GroupService;
 use ic_cdk::api::time;
 use ic_cdk::caller;
 use ic_cdk_macros::{query, update};
+use shared::pageable::PageRequest;
 
 pub mod api;
 
@@ -20,19 +29,48 @@ pub mod api;
 fn create_group(req: CreateGroupRequest) -> CreateGroupResponse {
     only_self();
 
-    let group_id =
-        GroupService::create_group(req.name, req.description, req.private, req.transferable)
-            .expect("Unable to create group");
+    let group_id = GroupService::create_group(
+        req.name,
+        req.description,
+        req.private,
+        req.transferable,
+        req.parent,
+    )
+    .expect("Unable to create group");
 
     CreateGroupResponse { group_id }
 }
 
+#[update]
+fn create_computed_group(req: CreateComputedGroupRequest) -> CreateGroupResponse {
+    only_self();
+
+    let group_id = GroupService::create_computed_group(req.name, req.description, req.rule, time())
+        .expect("Unable to create computed group");
+
+    CreateGroupResponse { group_id }
+}
+
+#[update]
+fn update_computed_group_rule(req: UpdateComputedGroupRuleRequest) {
+    only_self();
+
+    GroupService::update_computed_group_rule(req.group_id, req.new_rule, time())
+        .expect("Unable to update computed group rule");
+}
+
 #[update]
 fn update_group(req: UpdateGroupRequest) {
     only_self();
 
-    GroupService::update_group(req.group_id, req.new_name, req.new_description)
-        .expect("Unable to update group");
+    GroupService::update_group(
+        req.group_id,
+        req.new_name,
+        req.new_description,
+        req.new_vote_escrow,
+        req.new_parent,
+    )
+    .expect("Unable to update group");
 }
 
 #[update]
@@ -42,6 +80,22 @@ fn delete_group(req: DeleteGroupRequest) {
     GroupService::delete_group(req.group_id).expect("Unable to delete group");
 }
 
+#[update]
+fn convert_group_to_private(req: ConvertGroupRequest) {
+    only_self();
+
+    GroupService::convert_to_private(req.group_id, time())
+        .expect("Unable to convert group to private");
+}
+
+#[update]
+fn convert_group_to_public(req: ConvertGroupRequest) {
+    only_self();
+
+    GroupService::convert_to_public(req.group_id, time())
+        .expect("Unable to convert group to public");
+}
+
 #[query]
 fn get_group(req: GetGroupRequest) -> GetGroupResponse {
     only_self_or_with_access("get_group", req.query_delegation_proof_opt);
@@ -58,6 +112,24 @@ fn list_groups(req: ListGroupsRequest) -> ListGroupsResponse {
     ListGroupsResponse { page }
 }
 
+#[query]
+fn list_group_descendants(req: ListGroupRelativesRequest) -> ListGroupRelativesResponse {
+    only_self_or_with_access("list_group_descendants", req.query_delegation_proof_opt);
+
+    let group_ids = GroupService::list_group_descendants(req.group_id)
+        .expect("Unable to list group descendants");
+    ListGroupRelativesResponse { group_ids }
+}
+
+#[query]
+fn list_group_ancestors(req: ListGroupRelativesRequest) -> ListGroupRelativesResponse {
+    only_self_or_with_access("list_group_ancestors", req.query_delegation_proof_opt);
+
+    let group_ids =
+        GroupService::list_group_ancestors(req.group_id).expect("Unable to list group ancestors");
+    ListGroupRelativesResponse { group_ids }
+}
+
 #[update]
 fn mint_group_shares(req: MintGroupSharesRequest) {
     only_self();
@@ -120,6 +192,33 @@ fn get_unaccepted_group_shares_balance_of(
     GetGroupSharesBalanceOfResponse { balance }
 }
 
+#[query]
+fn get_locked_group_shares_balance_of(
+    req: GetGroupSharesBalanceOfRequest,
+) -> GetGroupSharesBalanceOfResponse {
+    only_self_or_with_access(
+        "get_locked_group_shares_balance_of",
+        req.query_delegation_proof_opt,
+    );
+
+    let balance = GroupService::get_locked_group_shares_balance_of(req.group_id, &req.owner)
+        .expect("Unable to get locked group shares balance of");
+
+    GetGroupSharesBalanceOfResponse { balance }
+}
+
+#[query]
+fn get_group_voting_power_of(
+    req: GetGroupSharesBalanceOfRequest,
+) -> GetGroupSharesBalanceOfResponse {
+    only_self_or_with_access("get_group_voting_power_of", req.query_delegation_proof_opt);
+
+    let balance = GroupService::get_group_voting_power_of(req.group_id, &req.owner, time())
+        .expect("Unable to get group voting power of");
+
+    GetGroupSharesBalanceOfResponse { balance }
+}
+
 #[query]
 fn get_total_group_shares(req: GetTotalGroupSharesRequest) -> GetTotalGroupSharesResponse {
     only_self_or_with_access("get_total_group_shares", req.query_delegation_proof_opt);
@@ -145,6 +244,19 @@ fn get_total_unaccepted_group_shares(
     GetTotalGroupSharesResponse { total }
 }
 
+#[query]
+fn get_total_group_voting_power(req: GetTotalGroupSharesRequest) -> GetTotalGroupSharesResponse {
+    only_self_or_with_access(
+        "get_total_group_voting_power",
+        req.query_delegation_proof_opt,
+    );
+
+    let total = GroupService::get_total_group_voting_power(req.group_id, time())
+        .expect("Unable to get total group voting power");
+
+    GetTotalGroupSharesResponse { total }
+}
+
 #[query]
 fn list_group_shares(req: ListGroupSharesRequest) -> ListGroupSharesResponse {
     only_self_or_with_access("list_group_shares", req.query_delegation_proof_opt);
@@ -176,6 +288,57 @@ fn get_groups_of(req: GetGroupsOfRequest) -> GetGroupsResponse {
     GetGroupsResponse { groups }
 }
 
+#[update]
+fn create_vesting_grant(req: CreateVestingGrantRequest) -> CreateVestingGrantResponse {
+    only_self();
+
+    let id = GroupService::create_vesting_grant(
+        req.group_id,
+        req.beneficiary,
+        req.qty,
+        req.schedule,
+        req.revocable,
+        req.voting_power,
+        time(),
+    )
+    .expect("Unable to create vesting grant");
+
+    CreateVestingGrantResponse { id }
+}
+
+#[update]
+fn revoke_vesting_grant(req: RevokeVestingGrantRequest) {
+    only_self();
+
+    GroupService::revoke_vesting_grant(&req.id, time()).expect("Unable to revoke vesting grant");
+}
+
+#[query]
+fn get_vesting_grant(req: GetVestingGrantRequest) -> GetVestingGrantResponse {
+    only_self_or_with_access("get_vesting_grant", req.query_delegation_proof_opt);
+
+    let grant =
+        GroupService::get_vesting_grant_ext(&req.id, time()).expect("Unable to get vesting grant");
+
+    GetVestingGrantResponse { grant }
+}
+
+#[query]
+fn list_vesting_grants(req: ListVestingGrantsRequest) -> ListVestingGrantsResponse {
+    only_self_or_with_access("list_vesting_grants", req.query_delegation_proof_opt);
+
+    let page = GroupService::list_vesting_grants(&req.page_req, time());
+    ListVestingGrantsResponse { page }
+}
+
+#[query]
+fn list_vote_escrow_locks(req: ListVoteEscrowLocksRequest) -> ListVoteEscrowLocksResponse {
+    only_self_or_with_access("list_vote_escrow_locks", req.query_delegation_proof_opt);
+
+    let page = GroupService::list_vote_escrow_locks(&req.page_req);
+    ListVoteEscrowLocksResponse { page }
+}
+
 // ------------------ PERSONAL -----------------------
 
 #[update]
@@ -222,8 +385,76 @@ fn get_my_unaccepted_group_shares_balance(
     GetMyGroupSharesBalanceResponse { balance }
 }
 
+#[query]
+fn get_my_locked_group_shares_balance(
+    req: GetMyGroupSharesBalanceRequest,
+) -> GetMyGroupSharesBalanceResponse {
+    let balance = GroupService::get_locked_group_shares_balance_of(req.group_id, &caller())
+        .expect("Unable to get my locked group shares balance");
+
+    GetMyGroupSharesBalanceResponse { balance }
+}
+
+#[query]
+fn get_my_group_voting_power(
+    req: GetMyGroupSharesBalanceRequest,
+) -> GetMyGroupSharesBalanceResponse {
+    let balance = GroupService::get_group_voting_power_of(req.group_id, &caller(), time())
+        .expect("Unable to get my group voting power");
+
+    GetMyGroupSharesBalanceResponse { balance }
+}
+
 #[query]
 fn get_my_groups() -> GetGroupsResponse {
     let groups = GroupService::get_groups_of(&caller());
     GetGroupsResponse { groups }
 }
+
+#[query]
+fn list_my_vesting_grants(req: ListMyVestingGrantsRequest) -> ListVestingGrantsResponse {
+    let page_req = PageRequest {
+        page_index: req.page_req.page_index,
+        page_size: req.page_req.page_size,
+        filter: VestingGrantFilter {
+            group_id: None,
+            beneficiary: Some(caller()),
+        },
+        sort: (),
+    };
+
+    let page = GroupService::list_vesting_grants(&page_req, time());
+    ListVestingGrantsResponse { page }
+}
+
+#[update]
+fn lock_my_group_shares_for_voting_power(
+    req: LockMyGroupSharesForVotingPowerRequest,
+) -> LockMyGroupSharesForVotingPowerResponse {
+    let id = GroupService::lock_shares_for_voting_power(
+        req.group_id,
+        caller(),
+        req.qty,
+        req.duration,
+        time(),
+    )
+    .expect("Unable to lock my group shares for voting power");
+
+    LockMyGroupSharesForVotingPowerResponse { id }
+}
+
+#[query]
+fn list_my_vote_escrow_locks(req: ListMyVoteEscrowLocksRequest) -> ListVoteEscrowLocksResponse {
+    let page_req = PageRequest {
+        page_index: req.page_req.page_index,
+        page_size: req.page_req.page_size,
+        filter: VoteEscrowLockFilter {
+            token_id: None,
+            owner: Some(caller()),
+        },
+        sort: (),
+    };
+
+    let page = GroupService::list_vote_escrow_locks(&page_req);
+    ListVoteEscrowLocksResponse { page }
+}
@@ -1,4 +1,5 @@
 pub mod access_config;
+pub mod comment;
 pub mod group;
 pub mod history_ledger;
 pub mod nested_voting;
@@ -1,6 +1,9 @@
 use crate::repository::permission::model::Permission;
-use crate::repository::permission::types::{PermissionFilter, PermissionId, PermissionTarget};
+use crate::repository::permission::types::{
+    CyclesLimits, PermissionFilter, PermissionId, PermissionTarget, PermissionTemplate,
+};
 use crate::service::access_config::types::QueryDelegationProof;
+use crate::service::permission::types::PermissionTemplateInfo;
 use candid::{CandidType, Deserialize};
 use shared::pageable::{Page, PageRequest};
 use std::collections::BTreeSet;
@@ -10,6 +13,7 @@ pub struct CreatePermissionRequest {
     pub name: String,
     pub description: String,
     pub targets: Vec<PermissionTarget>,
+    pub cycles_limits: CyclesLimits,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -23,6 +27,7 @@ pub struct UpdatePermissionRequest {
     pub new_name: Option<String>,
     pub new_description: Option<String>,
     pub new_targets: Option<BTreeSet<PermissionTarget>>,
+    pub new_cycles_limits: Option<CyclesLimits>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -51,3 +56,23 @@ pub struct ListPermissionsRequest {
 pub struct ListPermissionsResponse {
     pub page: Page<Permission>,
 }
+
+#[derive(CandidType, Deserialize)]
+pub struct CreatePermissionFromTemplateRequest {
+    pub template: PermissionTemplate,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct CreatePermissionFromTemplateResponse {
+    pub id: PermissionId,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListPermissionTemplatesRequest {
+    pub query_delegation_proof_opt: Option<QueryDelegationProof>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct ListPermissionTemplatesResponse {
+    pub templates: Vec<PermissionTemplateInfo>,
+}
@@ -1,10 +1,13 @@
 use crate::controller::permission::api::{
+    CreatePermissionFromTemplateRequest, CreatePermissionFromTemplateResponse,
     CreatePermissionRequest, CreatePermissionResponse, DeletePermissionRequest,
-    GetPermissionRequest, GetPermissionResponse, ListPermissionsRequest, ListPermissionsResponse,
+    GetPermissionRequest, GetPermissionResponse, ListPermissionTemplatesRequest,
+    ListPermissionTemplatesResponse, ListPermissionsRequest, ListPermissionsResponse,
     UpdatePermissionRequest,
 };
 use crate::guards::{only_self, only_self_or_with_access};
 use crate::service::permission::types::PermissionService;
+use ic_cdk::id;
 use ic_cdk_macros::{query, update};
 
 pub mod api;
@@ -13,8 +16,13 @@ pub mod api;
 fn create_permission(req: CreatePermissionRequest) -> CreatePermissionResponse {
     only_self();
 
-    let id = PermissionService::create_permission(req.name, req.description, req.targets)
-        .expect("Unable to create permission");
+    let id = PermissionService::create_permission(
+        req.name,
+        req.description,
+        req.targets,
+        req.cycles_limits,
+    )
+    .expect("Unable to create permission");
     CreatePermissionResponse { id }
 }
 
@@ -27,6 +35,7 @@ fn update_permission(req: UpdatePermissionRequest) {
         req.new_name,
         req.new_description,
         req.new_targets,
+        req.new_cycles_limits,
     )
     .expect("Unable to update permission");
 }
@@ -53,3 +62,24 @@ fn list_permissions(req: ListPermissionsRequest) -> ListPermissionsResponse {
     let page = PermissionService::list_permissions(&req.page_req);
     ListPermissionsResponse { page }
 }
+
+#[update]
+fn create_permission_from_template(
+    req: CreatePermissionFromTemplateRequest,
+) -> CreatePermissionFromTemplateResponse {
+    only_self();
+
+    let id = PermissionService::create_permission_from_template(req.template, id())
+        .expect("Unable to create permission from template");
+    CreatePermissionFromTemplateResponse { id }
+}
+
+#[query]
+fn list_permission_templates(
+    req: ListPermissionTemplatesRequest,
+) -> ListPermissionTemplatesResponse {
+    only_self_or_with_access("list_permission_templates", req.query_delegation_proof_opt);
+
+    let templates = PermissionService::list_permission_templates(id());
+    ListPermissionTemplatesResponse { templates }
+}
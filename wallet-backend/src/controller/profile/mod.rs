@@ -4,6 +4,7 @@ use crate::controller::profile::api::{
 };
 use crate::guards::{only_self, only_self_or_with_access};
 use crate::ProfileService;
+use ic_cdk::api::time;
 use ic_cdk::caller;
 use ic_cdk_macros::{query, update};
 
@@ -21,7 +22,7 @@ fn create_profile(req: CreateProfileRequest) {
 fn delete_profile(req: DeleteProfileRequest) {
     only_self();
 
-    ProfileService::delete_profile(req.id).expect("Unable to delete profile");
+    ProfileService::delete_profile(req.id, time()).expect("Unable to delete profile");
 }
 
 #[update]
@@ -1,11 +1,14 @@
 use crate::service::access_config::types::QueryDelegationProof;
-use crate::settings::Settings;
+use crate::settings::{CyclesBudget, Settings};
 use candid::{CandidType, Deserialize};
+use shared::types::Blob;
 
 #[derive(CandidType, Deserialize)]
 pub struct UpdateSettingsRequest {
     pub new_name: Option<String>,
     pub new_description: Option<String>,
+    pub new_cycles_budget: Option<Option<CyclesBudget>>,
+    pub new_ic_root_key: Option<Option<Blob>>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -12,7 +12,12 @@ fn update_settings(req: UpdateSettingsRequest) {
     only_self();
 
     Settings::get()
-        .update(req.new_name, req.new_description)
+        .update(
+            req.new_name,
+            req.new_description,
+            req.new_cycles_budget,
+            req.new_ic_root_key,
+        )
         .expect("Unable to update settings");
 }
 
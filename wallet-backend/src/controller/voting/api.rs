@@ -2,10 +2,10 @@ use crate::repository::choice::model::Choice;
 use crate::repository::choice::types::ChoiceFilter;
 use crate::repository::nested_voting::types::RemoteVotingId;
 use crate::repository::voting::model::Voting;
-use crate::repository::voting::types::VotingSort;
+use crate::repository::voting::types::{VotingFilter, VotingSort};
 use crate::service::access_config::types::QueryDelegationProof;
 use crate::service::voting::types::Vote;
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use shared::pageable::{Page, PageRequest};
 use shared::remote_call::Program;
 use shared::types::wallet::{ChoiceId, GroupId, Shares, VotingConfigId, VotingId};
@@ -17,6 +17,7 @@ pub struct CreateVotingRequest {
     pub name: String,
     pub description: String,
     pub winners_need: u32,
+    pub proposer: Option<Principal>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -41,6 +42,7 @@ pub struct DeleteVotingRequest {
 pub struct CastMyVoteRequest {
     pub id: VotingId,
     pub vote: Vote,
+    pub rationale: Option<String>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -56,10 +58,15 @@ pub struct GetVotingResponse {
 
 #[derive(CandidType, Deserialize)]
 pub struct ListVotingsRequest {
-    pub page_req: PageRequest<(), VotingSort>,
+    pub page_req: PageRequest<VotingFilter, VotingSort>,
     pub query_delegation_proof_opt: Option<QueryDelegationProof>,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct ListMyVotingsRequest {
+    pub page_req: PageRequest<(), ()>,
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct ListVotingsResponse {
     pub page: Page<Voting>,
@@ -124,6 +131,7 @@ pub struct GetVotingResultsRequest {
 #[derive(CandidType, Deserialize)]
 pub struct GetVotingResultsResponse {
     pub results: BTreeMap<ChoiceId, BTreeMap<GroupId, Shares>>,
+    pub rationales: BTreeMap<GroupId, BTreeMap<Principal, String>>,
 }
 
 // ------------------- PERSONAL ------------------
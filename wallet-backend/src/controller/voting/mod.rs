@@ -2,9 +2,9 @@ use crate::controller::voting::api::{
     CastMyVoteRequest, CreateVotingChoiceRequest, CreateVotingChoiceResponse, CreateVotingRequest,
     CreateVotingResponse, DeleteVotingChoiceRequest, DeleteVotingRequest, GetMyVoteRequest,
     GetMyVoteResponse, GetVotingChoiceRequest, GetVotingChoiceResponse, GetVotingRequest,
-    GetVotingResponse, GetVotingResultsRequest, GetVotingResultsResponse, ListVotingChoicesRequest,
-    ListVotingChoicesResponse, ListVotingsRequest, ListVotingsResponse, UpdateVotingChoiceRequest,
-    UpdateVotingRequest,
+    GetVotingResponse, GetVotingResultsRequest, GetVotingResultsResponse, ListMyVotingsRequest,
+    ListVotingChoicesRequest, ListVotingChoicesResponse, ListVotingsRequest, ListVotingsResponse,
+    UpdateVotingChoiceRequest, UpdateVotingRequest,
 };
 use crate::guards::{only_self, only_self_or_with_access};
 use crate::service::choice::types::ChoiceService;
@@ -19,12 +19,15 @@ pub mod api;
 fn create_voting(req: CreateVotingRequest) -> CreateVotingResponse {
     only_self();
 
+    // checked against the program initiator before the program is executed
+    let proposer = req.proposer.unwrap_or_else(caller);
+
     let id = VotingService::create_voting(
         req.voting_config_id,
         req.name,
         req.description,
         req.winners_need,
-        caller(),
+        proposer,
         time(),
     )
     .expect("Unable to create voting");
@@ -47,7 +50,7 @@ fn update_voting(req: UpdateVotingRequest) {
 }
 
 #[update]
-fn create_voting_choice(req: CreateVotingChoiceRequest) -> CreateVotingChoiceResponse {
+async fn create_voting_choice(req: CreateVotingChoiceRequest) -> CreateVotingChoiceResponse {
     only_self();
 
     let choice_id = ChoiceService::create_choice(
@@ -57,12 +60,13 @@ fn create_voting_choice(req: CreateVotingChoiceRequest) -> CreateVotingChoiceRes
         req.voting_id,
         time(),
     )
+    .await
     .expect("Unable to create voting choice");
     CreateVotingChoiceResponse { choice_id }
 }
 
 #[update]
-fn update_voting_choice(req: UpdateVotingChoiceRequest) {
+async fn update_voting_choice(req: UpdateVotingChoiceRequest) {
     only_self();
 
     ChoiceService::update_choice(
@@ -71,6 +75,7 @@ fn update_voting_choice(req: UpdateVotingChoiceRequest) {
         req.new_description,
         req.new_program,
     )
+    .await
     .expect("Unable to update voting choice");
 }
 
@@ -105,6 +110,12 @@ fn list_votings(req: ListVotingsRequest) -> ListVotingsResponse {
     ListVotingsResponse { page }
 }
 
+#[query]
+fn list_my_votings(req: ListMyVotingsRequest) -> ListVotingsResponse {
+    let page = VotingService::list_votings_by_proposer(&caller(), &req.page_req);
+    ListVotingsResponse { page }
+}
+
 #[query]
 fn get_voting_choice(req: GetVotingChoiceRequest) -> GetVotingChoiceResponse {
     only_self_or_with_access("get_voting_choice", req.query_delegation_proof_opt);
@@ -128,14 +139,22 @@ fn get_voting_results(req: GetVotingResultsRequest) -> GetVotingResultsResponse
 
     let results =
         VotingService::get_voting_results(&req.voting_id).expect("Unable to get voting results");
-    GetVotingResultsResponse { results }
+    let rationales =
+        VotingService::get_vote_rationales(&req.voting_id).expect("Unable to get vote rationales");
+
+    GetVotingResultsResponse {
+        results,
+        rationales,
+    }
 }
 
 // ------------------- PERSONAL ----------------------
 
 #[update]
-fn cast_my_vote(req: CastMyVoteRequest) {
-    VotingService::cast_vote(&req.id, req.vote, caller(), time()).expect("Unable to cast vote");
+async fn cast_my_vote(req: CastMyVoteRequest) {
+    VotingService::cast_vote(&req.id, req.vote, req.rationale, caller(), time())
+        .await
+        .expect("Unable to cast vote");
 }
 
 #[query]
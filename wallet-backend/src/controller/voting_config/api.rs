@@ -1,7 +1,7 @@
 use crate::repository::permission::types::PermissionId;
 use crate::repository::voting_config::model::VotingConfig;
 use crate::repository::voting_config::types::{
-    LenInterval, RoundSettings, ThresholdValue, VotingConfigFilter,
+    LenInterval, ProposalBond, ProposerLimits, RoundSettings, ThresholdValue, VotingConfigFilter,
 };
 use crate::service::access_config::types::QueryDelegationProof;
 use candid::{CandidType, Deserialize};
@@ -22,6 +22,9 @@ pub struct CreateVotingConfigRequest {
     pub rejection: ThresholdValue,
     pub win: ThresholdValue,
     pub next_round: ThresholdValue,
+    pub allow_abstain: bool,
+    pub proposal_bond: Option<ProposalBond>,
+    pub proposer_limits: ProposerLimits,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -43,6 +46,9 @@ pub struct UpdateVotingConfigRequest {
     pub rejection_opt: Option<ThresholdValue>,
     pub win_opt: Option<ThresholdValue>,
     pub next_round_opt: Option<ThresholdValue>,
+    pub allow_abstain_opt: Option<bool>,
+    pub proposal_bond_opt: Option<Option<ProposalBond>>,
+    pub proposer_limits_opt: Option<ProposerLimits>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -25,6 +25,9 @@ fn create_voting_config(req: CreateVotingConfigRequest) -> CreateVotingConfigRes
         req.rejection,
         req.win,
         req.next_round,
+        req.allow_abstain,
+        req.proposal_bond,
+        req.proposer_limits,
     )
     .expect("Unable to create voting config");
     CreateVotingConfigResponse { id }
@@ -47,6 +50,9 @@ fn update_voting_config(req: UpdateVotingConfigRequest) {
         req.rejection_opt,
         req.win_opt,
         req.next_round_opt,
+        req.allow_abstain_opt,
+        req.proposal_bond_opt,
+        req.proposer_limits_opt,
     )
     .expect("Unable to update voting config");
 }
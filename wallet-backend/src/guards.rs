@@ -1,4 +1,3 @@
-use crate::repository::permission::types::PermissionTarget;
 use crate::service::access_config::types::{AccessConfigService, QueryDelegationProof};
 use ic_cdk::api::time;
 use ic_cdk::{caller, id, trap};
@@ -13,12 +12,18 @@ pub fn only_self() {
 pub fn only_self_or_with_access(method_name: &str, proof_opt: Option<QueryDelegationProof>) {
     let caller = caller();
     let this_union_id = id();
+    let timestamp = time();
 
     if caller == this_union_id {
         return;
     }
 
-    if AccessConfigService::caller_has_access_to_method(this_union_id, method_name, caller) {
+    if AccessConfigService::caller_has_access_to_method(
+        this_union_id,
+        method_name,
+        caller,
+        timestamp,
+    ) {
         return;
     }
 
@@ -27,18 +32,16 @@ pub fn only_self_or_with_access(method_name: &str, proof_opt: Option<QueryDelega
             this_union_id,
             method_name,
             proof.union_id,
+            timestamp,
         ) {
-            // FIXME: same targets created twice here and inside the method above
-            let target_exact =
-                PermissionTarget::Endpoint(RemoteCallEndpoint::new(this_union_id, method_name));
-            let target_wide =
-                PermissionTarget::Endpoint(RemoteCallEndpoint::new(this_union_id, "*"));
-            let targets = vec![target_exact, target_wide];
+            let endpoint = RemoteCallEndpoint::new(this_union_id, method_name);
 
             proof
-                .validate(caller, time(), &targets)
+                .validate(caller, timestamp, &endpoint)
                 .expect("Access denied");
 
+            AccessConfigService::assert_query_delegate_not_revoked(&proof).expect("Access denied");
+
             return;
         } else {
             trap(
@@ -1,5 +1,5 @@
 use crate::repository::access_config::model::AccessConfig;
-use crate::repository::access_config::types::{AccessConfigFilter, AlloweeConstraint};
+use crate::repository::access_config::types::AccessConfigFilter;
 use crate::repository::permission::types::PermissionId;
 use candid::{CandidType, Deserialize};
 use ic_cdk::print;
@@ -159,22 +159,20 @@ impl AccessConfigRepository {
                 .insert(id);
         }
 
-        for allowee in query_config.get_allowees() {
-            match allowee {
-                AlloweeConstraint::Group(gc) => {
-                    self.access_configs_by_group_index
-                        .entry(gc.id)
-                        .or_default()
-                        .insert(id);
-                }
-                AlloweeConstraint::Profile(p) => {
-                    self.access_configs_by_profile_index
-                        .entry(*p)
-                        .or_default()
-                        .insert(id);
-                }
-                _ => {}
-            };
+        let (groups, profiles) = Self::get_mentioned_allowees(query_config);
+
+        for group_id in groups {
+            self.access_configs_by_group_index
+                .entry(group_id)
+                .or_default()
+                .insert(id);
+        }
+
+        for profile_id in profiles {
+            self.access_configs_by_profile_index
+                .entry(profile_id)
+                .or_default()
+                .insert(id);
         }
     }
 
@@ -188,22 +186,35 @@ impl AccessConfigRepository {
                 .remove(&id);
         }
 
+        let (groups, profiles) = Self::get_mentioned_allowees(query_config);
+
+        for group_id in groups {
+            self.access_configs_by_group_index
+                .get_mut(&group_id)
+                .unwrap()
+                .remove(&id);
+        }
+
+        for profile_id in profiles {
+            self.access_configs_by_profile_index
+                .get_mut(&profile_id)
+                .unwrap()
+                .remove(&id);
+        }
+    }
+
+    // including ones mentioned inside of nested constraints
+    fn get_mentioned_allowees(
+        query_config: &AccessConfig,
+    ) -> (BTreeSet<GroupId>, BTreeSet<ProfileId>) {
+        let mut groups = BTreeSet::new();
+        let mut profiles = BTreeSet::new();
+
         for allowee in query_config.get_allowees() {
-            match allowee {
-                AlloweeConstraint::Group(gc) => {
-                    self.access_configs_by_group_index
-                        .get_mut(&gc.id)
-                        .unwrap()
-                        .remove(&id);
-                }
-                AlloweeConstraint::Profile(p) => {
-                    self.access_configs_by_profile_index
-                        .get_mut(&p)
-                        .unwrap()
-                        .remove(&id);
-                }
-                _ => {}
-            };
+            groups.extend(allowee.get_mentioned_groups());
+            profiles.extend(allowee.get_mentioned_profiles());
         }
+
+        (groups, profiles)
     }
 }
@@ -1,9 +1,11 @@
 use crate::repository::access_config::types::{
-    AlloweeConstraint, QUERY_CONFIG_DESCRIPTION_MAX_LEN, QUERY_CONFIG_DESCRIPTION_MIN_LEN,
-    QUERY_CONFIG_NAME_MAX_LEN, QUERY_CONFIG_NAME_MIN_LEN,
+    AccessConfigQuota, AccessConfigValidity, AlloweeConstraint, MultisigSettings,
+    QUERY_CONFIG_DESCRIPTION_MAX_LEN, QUERY_CONFIG_DESCRIPTION_MIN_LEN, QUERY_CONFIG_NAME_MAX_LEN,
+    QUERY_CONFIG_NAME_MIN_LEN,
 };
-use crate::repository::permission::types::PermissionId;
+use crate::repository::permission::types::{CyclesLimits, PermissionId};
 use candid::{CandidType, Deserialize};
+use ic_cron::types::TaskId;
 use shared::mvc::Model;
 use shared::types::wallet::AccessConfigId;
 use shared::validation::{validate_and_trim_str, ValidationError};
@@ -17,6 +19,17 @@ pub struct AccessConfig {
 
     permissions: BTreeSet<PermissionId>,
     allowees: BTreeSet<AlloweeConstraint>,
+    // max lifetime of query delegation proofs issued with this config, the default one if none
+    query_delegation_expiry: Option<u64>,
+    cycles_limits: CyclesLimits,
+    quota: AccessConfigQuota,
+    // programs are only executed through multisig proposals, if set
+    multisig: Option<MultisigSettings>,
+
+    validity: AccessConfigValidity,
+    // unset by the cron, once the config expires
+    is_active: bool,
+    expiration_task: Option<TaskId>,
 }
 
 impl AccessConfig {
@@ -25,13 +38,27 @@ impl AccessConfig {
         description: String,
         permissions: BTreeSet<PermissionId>,
         allowees: BTreeSet<AlloweeConstraint>,
+        query_delegation_expiry: Option<u64>,
+        cycles_limits: CyclesLimits,
+        quota: AccessConfigQuota,
+        multisig: Option<MultisigSettings>,
+        validity: AccessConfigValidity,
     ) -> Result<Self, ValidationError> {
         Ok(Self {
             id: None,
             name: Self::process_name(name)?,
             description: Self::process_description(description)?,
             permissions,
-            allowees,
+            allowees: Self::process_allowees(allowees)?,
+            query_delegation_expiry: Self::process_query_delegation_expiry(
+                query_delegation_expiry,
+            )?,
+            cycles_limits,
+            quota: Self::process_quota(quota)?,
+            multisig: Self::process_multisig(multisig)?,
+            validity: Self::process_validity(validity)?,
+            is_active: true,
+            expiration_task: None,
         })
     }
 
@@ -41,6 +68,11 @@ impl AccessConfig {
         new_description: Option<String>,
         new_permissions: Option<BTreeSet<PermissionId>>,
         new_allowees: Option<BTreeSet<AlloweeConstraint>>,
+        new_query_delegation_expiry: Option<Option<u64>>,
+        new_cycles_limits: Option<CyclesLimits>,
+        new_quota: Option<AccessConfigQuota>,
+        new_multisig: Option<Option<MultisigSettings>>,
+        new_validity: Option<AccessConfigValidity>,
     ) -> Result<(), ValidationError> {
         if let Some(name) = new_name {
             self.name = Self::process_name(name)?;
@@ -55,7 +87,30 @@ impl AccessConfig {
         }
 
         if let Some(allowees) = new_allowees {
-            self.allowees = allowees;
+            self.allowees = Self::process_allowees(allowees)?;
+        }
+
+        if let Some(query_delegation_expiry) = new_query_delegation_expiry {
+            self.query_delegation_expiry =
+                Self::process_query_delegation_expiry(query_delegation_expiry)?;
+        }
+
+        if let Some(cycles_limits) = new_cycles_limits {
+            self.cycles_limits = cycles_limits;
+        }
+
+        if let Some(quota) = new_quota {
+            self.quota = Self::process_quota(quota)?;
+        }
+
+        if let Some(multisig) = new_multisig {
+            self.multisig = Self::process_multisig(multisig)?;
+        }
+
+        // a new validity re-activates an expired config
+        if let Some(validity) = new_validity {
+            self.validity = Self::process_validity(validity)?;
+            self.is_active = true;
         }
 
         Ok(())
@@ -69,6 +124,93 @@ impl AccessConfig {
         &self.allowees
     }
 
+    pub fn get_query_delegation_expiry(&self) -> Option<u64> {
+        self.query_delegation_expiry
+    }
+
+    pub fn get_cycles_limits(&self) -> &CyclesLimits {
+        &self.cycles_limits
+    }
+
+    pub fn get_quota(&self) -> &AccessConfigQuota {
+        &self.quota
+    }
+
+    pub fn get_multisig(&self) -> Option<&MultisigSettings> {
+        self.multisig.as_ref()
+    }
+
+    pub fn get_validity(&self) -> &AccessConfigValidity {
+        &self.validity
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn is_active_at(&self, timestamp: u64) -> bool {
+        self.is_active && self.validity.is_valid_at(timestamp)
+    }
+
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+        self.expiration_task = None;
+    }
+
+    pub fn get_expiration_task(&self) -> Option<TaskId> {
+        self.expiration_task
+    }
+
+    pub fn set_expiration_task(&mut self, task_id: Option<TaskId>) {
+        self.expiration_task = task_id;
+    }
+
+    fn process_quota(quota: AccessConfigQuota) -> Result<AccessConfigQuota, ValidationError> {
+        quota.validate()?;
+
+        Ok(quota)
+    }
+
+    fn process_multisig(
+        multisig: Option<MultisigSettings>,
+    ) -> Result<Option<MultisigSettings>, ValidationError> {
+        if let Some(it) = &multisig {
+            it.validate()?;
+        }
+
+        Ok(multisig)
+    }
+
+    fn process_validity(
+        validity: AccessConfigValidity,
+    ) -> Result<AccessConfigValidity, ValidationError> {
+        validity.validate()?;
+
+        Ok(validity)
+    }
+
+    fn process_allowees(
+        allowees: BTreeSet<AlloweeConstraint>,
+    ) -> Result<BTreeSet<AlloweeConstraint>, ValidationError> {
+        for allowee in &allowees {
+            allowee.validate()?;
+        }
+
+        Ok(allowees)
+    }
+
+    fn process_query_delegation_expiry(
+        query_delegation_expiry: Option<u64>,
+    ) -> Result<Option<u64>, ValidationError> {
+        if query_delegation_expiry == Some(0) {
+            return Err(ValidationError(String::from(
+                "Query delegation expiry should be positive",
+            )));
+        }
+
+        Ok(query_delegation_expiry)
+    }
+
     fn process_name(name: String) -> Result<String, ValidationError> {
         validate_and_trim_str(
             name,
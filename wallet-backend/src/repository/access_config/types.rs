@@ -1,11 +1,14 @@
 use crate::repository::permission::types::PermissionId;
 use candid::{CandidType, Deserialize};
 use shared::types::wallet::{GroupId, ProfileId, Shares};
+use shared::validation::ValidationError;
+use std::collections::BTreeSet;
 
 pub const QUERY_CONFIG_NAME_MIN_LEN: usize = 1;
 pub const QUERY_CONFIG_NAME_MAX_LEN: usize = 200;
 pub const QUERY_CONFIG_DESCRIPTION_MIN_LEN: usize = 0;
 pub const QUERY_CONFIG_DESCRIPTION_MAX_LEN: usize = 2000;
+pub const ALLOWEE_CONSTRAINT_MAX_DEPTH: usize = 8;
 
 #[derive(Debug, Clone, CandidType, Deserialize, Eq, PartialEq, Ord, PartialOrd)]
 pub struct GroupCondition {
@@ -17,12 +20,228 @@ pub struct GroupCondition {
 pub enum AlloweeConstraint {
     Everyone,
     Group(GroupCondition),
+    // shares of the group and all of its descendants are summed up
+    GroupTree(GroupCondition),
     Profile(ProfileId),
+    And(Vec<AlloweeConstraint>),
+    Or(Vec<AlloweeConstraint>),
+    Not(Box<AlloweeConstraint>),
+}
+
+impl AlloweeConstraint {
+    pub fn get_mentioned_groups(&self) -> BTreeSet<GroupId> {
+        let mut result = BTreeSet::new();
+        self.visit_leaves(&mut |it| {
+            if let AlloweeConstraint::Group(gc) | AlloweeConstraint::GroupTree(gc) = it {
+                result.insert(gc.id);
+            }
+        });
+
+        result
+    }
+
+    pub fn get_mentioned_profiles(&self) -> BTreeSet<ProfileId> {
+        let mut result = BTreeSet::new();
+        self.visit_leaves(&mut |it| {
+            if let AlloweeConstraint::Profile(p) = it {
+                result.insert(*p);
+            }
+        });
+
+        result
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_on_depth(1)
+    }
+
+    fn validate_on_depth(&self, depth: usize) -> Result<(), ValidationError> {
+        if depth > ALLOWEE_CONSTRAINT_MAX_DEPTH {
+            return Err(ValidationError(format!(
+                "Allowee constraints can't be nested deeper than {} levels",
+                ALLOWEE_CONSTRAINT_MAX_DEPTH
+            )));
+        }
+
+        match self {
+            AlloweeConstraint::And(constraints) | AlloweeConstraint::Or(constraints) => {
+                if constraints.is_empty() {
+                    return Err(ValidationError(String::from(
+                        "Allowee constraint combinators should not be empty",
+                    )));
+                }
+
+                for it in constraints {
+                    it.validate_on_depth(depth + 1)?;
+                }
+
+                Ok(())
+            }
+            AlloweeConstraint::Not(constraint) => constraint.validate_on_depth(depth + 1),
+            _ => Ok(()),
+        }
+    }
+
+    fn visit_leaves<F: FnMut(&AlloweeConstraint)>(&self, f: &mut F) {
+        match self {
+            AlloweeConstraint::And(constraints) | AlloweeConstraint::Or(constraints) => {
+                for it in constraints {
+                    it.visit_leaves(f);
+                }
+            }
+            AlloweeConstraint::Not(constraint) => constraint.visit_leaves(f),
+            _ => f(self),
+        }
+    }
+}
+
+// A window repeating each period, counted from the unix epoch (in UTC), e.g. business hours
+// are { period: days(1), offset: hours(9), duration: hours(8) }
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct RecurringWindow {
+    pub period: u64,
+    pub offset: u64,
+    pub duration: u64,
+}
+
+impl RecurringWindow {
+    pub fn contains(&self, timestamp: u64) -> bool {
+        let position = timestamp % self.period;
+        let end = self.offset + self.duration;
+
+        if end <= self.period {
+            self.offset <= position && position < end
+        } else {
+            // the window wraps around the end of the period
+            self.offset <= position || position < end - self.period
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.period == 0 || self.duration == 0 {
+            return Err(ValidationError(String::from(
+                "Recurring window period and duration should be positive",
+            )));
+        }
+
+        if self.duration > self.period || self.offset >= self.period {
+            return Err(ValidationError(String::from(
+                "Recurring window should fit into its period",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, Default)]
+pub struct AccessConfigValidity {
+    pub not_before: Option<u64>,
+    // the config is deactivated by the cron after this timestamp
+    pub not_after: Option<u64>,
+    // if not empty, the config is only valid inside of one of these windows
+    pub recurring_windows: Vec<RecurringWindow>,
+}
+
+impl AccessConfigValidity {
+    pub fn is_valid_at(&self, timestamp: u64) -> bool {
+        if matches!(self.not_before, Some(not_before) if timestamp < not_before) {
+            return false;
+        }
+
+        if self.is_expired(timestamp) {
+            return false;
+        }
+
+        self.recurring_windows.is_empty()
+            || self
+                .recurring_windows
+                .iter()
+                .any(|it| it.contains(timestamp))
+    }
+
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        matches!(self.not_after, Some(not_after) if timestamp > not_after)
+    }
+
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let (Some(not_before), Some(not_after)) = (self.not_before, self.not_after) {
+            if not_before > not_after {
+                return Err(ValidationError(String::from(
+                    "Access config validity should not end before it starts",
+                )));
+            }
+        }
+
+        for window in &self.recurring_windows {
+            window.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+// Programs are only executed once approved by 'threshold' distinct allowees within 'timeout'
+#[derive(Debug, Clone, Copy, CandidType, Deserialize)]
+pub struct MultisigSettings {
+    pub threshold: u32,
+    pub timeout: u64,
+    // rejections needed to reject a proposal, regardless of how many allowees could still approve it
+    pub veto_threshold: Option<u32>,
+}
+
+impl MultisigSettings {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.threshold == 0 || self.timeout == 0 || self.veto_threshold == Some(0) {
+            return Err(ValidationError(String::from(
+                "Multisig thresholds and timeout should be positive",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_veto_threshold(&self) -> u32 {
+        self.veto_threshold.unwrap_or(self.threshold)
+    }
+}
+
+#[derive(Debug, Clone, Copy, CandidType, Deserialize)]
+pub struct PeriodicLimit {
+    pub max_executions: u64,
+    // periods are aligned by the unix epoch
+    pub period: u64,
+}
+
+// No limit, if none
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, Default)]
+pub struct AccessConfigQuota {
+    // executions by each principal during each period
+    pub per_principal: Option<PeriodicLimit>,
+    // executions by everyone during the whole lifetime of the config
+    pub max_total_executions: Option<u64>,
+    // time between executions of the same principal
+    pub min_interval: Option<u64>,
+}
+
+impl AccessConfigQuota {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(limit) = &self.per_principal {
+            if limit.period == 0 {
+                return Err(ValidationError(String::from(
+                    "Access config quota period should be positive",
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(CandidType, Deserialize)]
 pub struct AccessConfigFilter {
     pub permission: Option<PermissionId>,
+    // groups and profiles also match if mentioned inside of nested constraints
     pub group: Option<GroupId>,
     pub profile: Option<ProfileId>,
 }
@@ -0,0 +1,38 @@
+use crate::repository::access_config_usage::model::AccessConfigUsage;
+use candid::{CandidType, Deserialize};
+use shared::mvc::{Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use shared::types::wallet::AccessConfigId;
+use std::collections::HashMap;
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct AccessConfigUsageRepository {
+    usages: HashMap<AccessConfigId, AccessConfigUsage>,
+}
+
+impl Repository<AccessConfigUsage, AccessConfigId, (), ()> for AccessConfigUsageRepository {
+    fn save(&mut self, it: AccessConfigUsage) -> AccessConfigId {
+        let id = it.get_id().unwrap();
+        self.usages.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &AccessConfigId) -> Option<AccessConfigUsage> {
+        self.usages.remove(id)
+    }
+
+    fn get(&self, id: &AccessConfigId) -> Option<AccessConfigUsage> {
+        self.usages.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<(), ()>) -> Page<AccessConfigUsage> {
+        let (has_next, iter) = self.usages.iter().get_page(page_req);
+        let data = iter.map(|(_, it)| it.clone()).collect();
+
+        Page::new(data, has_next)
+    }
+}
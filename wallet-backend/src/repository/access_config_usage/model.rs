@@ -0,0 +1,79 @@
+use crate::repository::access_config_usage::types::PrincipalUsage;
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Model;
+use shared::types::wallet::AccessConfigId;
+use std::collections::BTreeMap;
+
+// Executions made with an access config, tracked to enforce its quota
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct AccessConfigUsage {
+    access_config_id: AccessConfigId,
+    total_executions: u64,
+    by_principal: BTreeMap<Principal, PrincipalUsage>,
+}
+
+impl AccessConfigUsage {
+    pub fn new(access_config_id: AccessConfigId) -> Self {
+        Self {
+            access_config_id,
+            total_executions: 0,
+            by_principal: BTreeMap::new(),
+        }
+    }
+
+    // periods are aligned by the unix epoch
+    pub fn record_execution(&mut self, principal: Principal, period: Option<u64>, timestamp: u64) {
+        let executions_in_period = self.get_executions_in_period(&principal, period, timestamp);
+        let period_start = period
+            .map(|it| timestamp - timestamp % it)
+            .unwrap_or_default();
+
+        self.by_principal.insert(
+            principal,
+            PrincipalUsage {
+                period_start,
+                executions_in_period: executions_in_period + 1,
+                last_executed_at: Some(timestamp),
+            },
+        );
+        self.total_executions += 1;
+    }
+
+    pub fn get_executions_in_period(
+        &self,
+        principal: &Principal,
+        period: Option<u64>,
+        timestamp: u64,
+    ) -> u64 {
+        let period_start = period
+            .map(|it| timestamp - timestamp % it)
+            .unwrap_or_default();
+
+        match self.by_principal.get(principal) {
+            Some(usage) if usage.period_start == period_start => usage.executions_in_period,
+            _ => 0,
+        }
+    }
+
+    pub fn get_last_executed_at(&self, principal: &Principal) -> Option<u64> {
+        self.by_principal
+            .get(principal)
+            .and_then(|it| it.last_executed_at)
+    }
+
+    pub fn get_total_executions(&self) -> u64 {
+        self.total_executions
+    }
+}
+
+impl Model<AccessConfigId> for AccessConfigUsage {
+    fn get_id(&self) -> Option<AccessConfigId> {
+        Some(self.access_config_id)
+    }
+
+    fn _init_id(&mut self, _: AccessConfigId) {}
+
+    fn is_transient(&self) -> bool {
+        false
+    }
+}
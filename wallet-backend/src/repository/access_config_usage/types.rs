@@ -0,0 +1,8 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, Default)]
+pub struct PrincipalUsage {
+    pub period_start: u64,
+    pub executions_in_period: u64,
+    pub last_executed_at: Option<u64>,
+}
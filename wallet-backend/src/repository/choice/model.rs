@@ -58,6 +58,16 @@ impl Choice {
         .unwrap()
     }
 
+    pub fn new_abstain(voting_id: VotingId) -> Self {
+        Self::new(
+            String::from("Abstain"),
+            String::from("I don't support any side, but my vote should count toward the quorum"),
+            Program::Empty,
+            RemoteVotingId::Common(voting_id),
+        )
+        .unwrap()
+    }
+
     pub fn update(
         &mut self,
         new_name: Option<String>,
@@ -0,0 +1,88 @@
+use crate::repository::comment::model::Comment;
+use crate::repository::comment::types::{CommentFilter, CommentId};
+use candid::{CandidType, Deserialize};
+use shared::mvc::{IdGenerator, Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use shared::types::wallet::VotingId;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct CommentRepository {
+    comments: HashMap<CommentId, Comment>,
+    id_gen: IdGenerator,
+
+    comments_by_thread_index: BTreeMap<(VotingId, Option<CommentId>), BTreeSet<CommentId>>,
+}
+
+impl Repository<Comment, CommentId, CommentFilter, ()> for CommentRepository {
+    fn save(&mut self, mut it: Comment) -> CommentId {
+        if it.is_transient() {
+            it._init_id(self.id_gen.generate());
+        }
+
+        let id = it.get_id().unwrap();
+        self.comments_by_thread_index
+            .entry((*it.get_voting_id(), it.get_parent_id()))
+            .or_default()
+            .insert(id);
+        self.comments.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &CommentId) -> Option<Comment> {
+        let it = self.comments.remove(id)?;
+
+        let key = (*it.get_voting_id(), it.get_parent_id());
+        let index = self.comments_by_thread_index.get_mut(&key).unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.comments_by_thread_index.remove(&key);
+        }
+
+        Some(it)
+    }
+
+    fn get(&self, id: &CommentId) -> Option<Comment> {
+        self.comments.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<CommentFilter, ()>) -> Page<Comment> {
+        let key = (page_req.filter.voting_id, page_req.filter.parent_id);
+
+        if let Some(index) = self.comments_by_thread_index.get(&key) {
+            let (has_next, iter) = index.iter().get_page(page_req);
+            let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+            Page::new(data, has_next)
+        } else {
+            Page::empty()
+        }
+    }
+}
+
+impl CommentRepository {
+    pub fn has_replies(&self, voting_id: VotingId, id: CommentId) -> bool {
+        self.comments_by_thread_index
+            .contains_key(&(voting_id, Some(id)))
+    }
+
+    pub fn delete_all_by_voting(&mut self, voting_id: &VotingId) {
+        let keys: Vec<_> = self
+            .comments_by_thread_index
+            .range((*voting_id, None)..)
+            .take_while(|((it, _), _)| it == voting_id)
+            .map(|(key, _)| *key)
+            .collect();
+
+        for key in keys {
+            for id in self.comments_by_thread_index.remove(&key).unwrap() {
+                self.comments.remove(&id);
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::repository::comment::types::{CommentId, COMMENT_TEXT_MAX_LEN, COMMENT_TEXT_MIN_LEN};
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Model;
+use shared::types::wallet::VotingId;
+use shared::validation::{validate_and_trim_str, ValidationError};
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct Comment {
+    id: Option<CommentId>,
+    voting_id: VotingId,
+    parent_id: Option<CommentId>,
+
+    author: Principal,
+    text: String,
+    removed: bool,
+
+    created_at: u64,
+    updated_at: u64,
+}
+
+impl Comment {
+    pub fn new(
+        voting_id: VotingId,
+        parent_id: Option<CommentId>,
+        author: Principal,
+        text: String,
+        timestamp: u64,
+    ) -> Result<Self, ValidationError> {
+        let comment = Self {
+            id: None,
+            voting_id,
+            parent_id,
+            author,
+            text: Self::process_text(text)?,
+            removed: false,
+            created_at: timestamp,
+            updated_at: timestamp,
+        };
+
+        Ok(comment)
+    }
+
+    pub fn update(&mut self, new_text: String, timestamp: u64) -> Result<(), ValidationError> {
+        self.text = Self::process_text(new_text)?;
+        self.updated_at = timestamp;
+
+        Ok(())
+    }
+
+    // the comment stays in place, so the replies don't lose their thread
+    pub fn remove(&mut self, timestamp: u64) {
+        self.text = String::new();
+        self.removed = true;
+        self.updated_at = timestamp;
+    }
+
+    pub fn get_voting_id(&self) -> &VotingId {
+        &self.voting_id
+    }
+
+    pub fn get_parent_id(&self) -> Option<CommentId> {
+        self.parent_id
+    }
+
+    pub fn get_author(&self) -> Principal {
+        self.author
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.removed
+    }
+
+    fn process_text(text: String) -> Result<String, ValidationError> {
+        validate_and_trim_str(
+            text,
+            COMMENT_TEXT_MIN_LEN,
+            COMMENT_TEXT_MAX_LEN,
+            "Comment text",
+        )
+    }
+}
+
+impl Model<CommentId> for Comment {
+    fn get_id(&self) -> Option<CommentId> {
+        self.id
+    }
+
+    fn _init_id(&mut self, id: CommentId) {
+        assert!(self.is_transient());
+        self.id = Some(id);
+    }
+
+    fn is_transient(&self) -> bool {
+        self.id.is_none()
+    }
+}
@@ -0,0 +1,15 @@
+use candid::{CandidType, Deserialize};
+use shared::mvc::Id;
+use shared::types::wallet::VotingId;
+
+pub const COMMENT_TEXT_MIN_LEN: usize = 1;
+pub const COMMENT_TEXT_MAX_LEN: usize = 2000;
+
+pub type CommentId = Id;
+
+#[derive(CandidType, Deserialize)]
+pub struct CommentFilter {
+    pub voting_id: VotingId,
+    // replies to this comment are listed, or top-level comments of the voting if none
+    pub parent_id: Option<CommentId>,
+}
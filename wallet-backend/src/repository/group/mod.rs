@@ -3,7 +3,7 @@ use candid::{CandidType, Deserialize};
 use shared::mvc::{IdGenerator, Model, Repository};
 use shared::pageable::{Page, PageRequest, Pageable};
 use shared::types::wallet::GroupId;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 pub mod model;
 pub mod types;
@@ -41,3 +41,52 @@ impl Repository<Group, GroupId, (), ()> for GroupRepository {
         Page::new(data, has_next)
     }
 }
+
+impl GroupRepository {
+    pub fn get_children(&self, group_id: &GroupId) -> BTreeSet<GroupId> {
+        self.groups
+            .iter()
+            .filter(|(_, it)| it.get_parent() == Some(*group_id))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    pub fn get_descendants(&self, group_id: &GroupId) -> BTreeSet<GroupId> {
+        let mut result = BTreeSet::new();
+        let mut queue = vec![*group_id];
+
+        while let Some(id) = queue.pop() {
+            for child in self.get_children(&id) {
+                if result.insert(child) {
+                    queue.push(child);
+                }
+            }
+        }
+
+        result
+    }
+
+    // the closest ancestor goes first
+    pub fn get_ancestors(&self, group_id: &GroupId) -> Vec<GroupId> {
+        let mut result = Vec::new();
+        let mut parent = self.groups.get(group_id).and_then(|it| it.get_parent());
+
+        while let Some(id) = parent {
+            result.push(id);
+            parent = self.groups.get(&id).and_then(|it| it.get_parent());
+        }
+
+        result
+    }
+
+    pub fn get_computed_groups_depending_on(&self, group_id: &GroupId) -> BTreeSet<GroupId> {
+        self.groups
+            .iter()
+            .filter(|(_, it)| {
+                it.get_rule()
+                    .map_or(false, |rule| rule.list_groups().contains(group_id))
+            })
+            .map(|(id, _)| *id)
+            .collect()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::repository::group::types::{
-    GROUP_DESCRIPTION_MAX_LEN, GROUP_DESCRIPTION_MIN_LEN, GROUP_NAME_MAX_LEN, GROUP_NAME_MIN_LEN,
+    GroupRule, VoteEscrowSettings, GROUP_DESCRIPTION_MAX_LEN, GROUP_DESCRIPTION_MIN_LEN,
+    GROUP_NAME_MAX_LEN, GROUP_NAME_MIN_LEN,
 };
 use crate::repository::token::types::TokenId;
 use candid::{CandidType, Deserialize};
@@ -14,6 +15,12 @@ pub struct Group {
     description: String,
     private: bool,
     token: Option<TokenId>,
+    // vote escrow is disabled, if none
+    vote_escrow: Option<VoteEscrowSettings>,
+    // the group is computed, if some
+    rule: Option<GroupRule>,
+    // the group is a root, if none
+    parent: Option<GroupId>,
 }
 
 impl Group {
@@ -22,6 +29,9 @@ impl Group {
             id: None,
             private,
             token: None,
+            vote_escrow: None,
+            rule: None,
+            parent: None,
             name: Self::process_name(name)?,
             description: Self::process_description(description)?,
         };
@@ -29,10 +39,32 @@ impl Group {
         Ok(group)
     }
 
+    // computed groups are always public
+    pub fn new_computed(
+        name: String,
+        description: String,
+        rule: GroupRule,
+    ) -> Result<Self, ValidationError> {
+        let mut group = Self::new(name, description, false)?;
+        group.set_rule(rule)?;
+
+        Ok(group)
+    }
+
+    pub fn set_rule(&mut self, rule: GroupRule) -> Result<(), ValidationError> {
+        assert!(!self.private);
+        rule.validate()?;
+
+        self.rule = Some(rule);
+
+        Ok(())
+    }
+
     pub fn update(
         &mut self,
         new_name: Option<String>,
         new_description: Option<String>,
+        new_vote_escrow: Option<Option<VoteEscrowSettings>>,
     ) -> Result<(), ValidationError> {
         if let Some(name) = new_name {
             self.name = Self::process_name(name)?;
@@ -42,6 +74,14 @@ impl Group {
             self.description = Self::process_description(description)?;
         }
 
+        if let Some(vote_escrow) = new_vote_escrow {
+            if let Some(settings) = &vote_escrow {
+                settings.validate()?;
+            }
+
+            self.vote_escrow = vote_escrow;
+        }
+
         Ok(())
     }
 
@@ -62,6 +102,30 @@ impl Group {
         self.private
     }
 
+    pub fn set_parent(&mut self, parent: Option<GroupId>) {
+        if parent.is_some() {
+            assert_ne!(parent, self.id);
+        }
+
+        self.parent = parent;
+    }
+
+    pub fn get_parent(&self) -> Option<GroupId> {
+        self.parent
+    }
+
+    pub fn get_rule(&self) -> Option<&GroupRule> {
+        self.rule.as_ref()
+    }
+
+    pub fn is_computed(&self) -> bool {
+        self.rule.is_some()
+    }
+
+    pub fn get_vote_escrow(&self) -> Option<&VoteEscrowSettings> {
+        self.vote_escrow.as_ref()
+    }
+
     fn process_name(name: String) -> Result<String, ValidationError> {
         validate_and_trim_str(name, GROUP_NAME_MIN_LEN, GROUP_NAME_MAX_LEN, "Group name")
     }
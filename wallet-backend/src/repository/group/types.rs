@@ -1,4 +1,122 @@
+use crate::repository::access_config::types::GroupCondition;
+use crate::repository::voting_config::types::Fraction;
+use bigdecimal::{BigDecimal, One, Zero};
+use candid::{CandidType, Deserialize};
+use shared::types::wallet::{GroupId, Shares};
+use shared::validation::ValidationError;
+use std::collections::{BTreeMap, BTreeSet};
+
 pub const GROUP_NAME_MIN_LEN: usize = 1;
 pub const GROUP_NAME_MAX_LEN: usize = 100;
 pub const GROUP_DESCRIPTION_MIN_LEN: usize = 0;
-pub const GROUP_DESCRIPTION_MAX_LEN: usize = 300;
\ No newline at end of file
+pub const GROUP_DESCRIPTION_MAX_LEN: usize = 300;
+// Locking shares for the max duration boosts their voting power by the max multiplier,
+// shorter locks get a proportionally smaller boost
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct VoteEscrowSettings {
+    pub max_duration: u64,
+    pub max_multiplier: Fraction,
+}
+
+impl VoteEscrowSettings {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.max_duration == 0 {
+            return Err(ValidationError(String::from(
+                "Vote escrow max duration should be positive",
+            )));
+        }
+
+        if self.max_multiplier < Fraction(BigDecimal::one()) {
+            return Err(ValidationError(String::from(
+                "Vote escrow max multiplier should not be less than 1",
+            )));
+        }
+
+        Ok(())
+    }
+
+    // the extra voting power, on top of the locked shares themselves
+    pub fn calc_boost(&self, qty: &Shares, duration: u64) -> Result<Shares, ValidationError> {
+        if duration == 0 || duration > self.max_duration {
+            return Err(ValidationError(String::from(
+                "Vote escrow duration should be positive and not exceed the max duration",
+            )));
+        }
+
+        let extra = (self.max_multiplier.0.clone() - BigDecimal::one()) * BigDecimal::from(duration)
+            / BigDecimal::from(self.max_duration);
+
+        Ok((Fraction::from(qty.clone()) * Fraction(extra)).into())
+    }
+}
+
+// Membership and balances of a computed group, derived from other groups
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub enum GroupRule {
+    // members of all the listed groups
+    Intersection(Vec<GroupCondition>),
+    // members of any of the listed groups
+    Union(Vec<GroupCondition>),
+    // balances in the listed groups, multiplied by their weights
+    WeightedSum(BTreeMap<GroupId, Fraction>),
+}
+
+impl GroupRule {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let is_empty = match self {
+            GroupRule::Intersection(conditions) | GroupRule::Union(conditions) => {
+                conditions.is_empty()
+            }
+            GroupRule::WeightedSum(weights) => {
+                if weights.values().any(|it| it.0 <= BigDecimal::zero()) {
+                    return Err(ValidationError(String::from(
+                        "Group rule weights should be positive",
+                    )));
+                }
+
+                weights.is_empty()
+            }
+        };
+
+        if is_empty {
+            return Err(ValidationError(String::from(
+                "Group rule should list at least one group",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn list_groups(&self) -> BTreeSet<GroupId> {
+        match self {
+            GroupRule::Intersection(conditions) | GroupRule::Union(conditions) => {
+                conditions.iter().map(|it| it.id).collect()
+            }
+            GroupRule::WeightedSum(weights) => weights.keys().cloned().collect(),
+        }
+    }
+
+    // members of intersections and unions get the same balance each
+    pub fn compute<F: Fn(&GroupId) -> Shares>(&self, member_balance: &Shares, balance_of: F) -> Shares {
+        let is_satisfied = |it: &GroupCondition| balance_of(&it.id) >= it.min_shares;
+
+        let is_member = match self {
+            GroupRule::Intersection(conditions) => conditions.iter().all(is_satisfied),
+            GroupRule::Union(conditions) => conditions.iter().any(is_satisfied),
+            GroupRule::WeightedSum(weights) => {
+                let mut sum = Fraction::default();
+                for (group_id, weight) in weights {
+                    sum += Fraction::from(balance_of(group_id)) * weight.clone();
+                }
+
+                return sum.into();
+            }
+        };
+
+        if is_member {
+            member_balance.clone()
+        } else {
+            Shares::default()
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::repository::access_config::model::AccessConfig;
 use crate::repository::access_config::types::AccessConfigFilter;
 use crate::repository::access_config::AccessConfigRepository;
+use crate::repository::access_config_usage::model::AccessConfigUsage;
+use crate::repository::access_config_usage::AccessConfigUsageRepository;
 use crate::repository::batch::model::Batch;
 use crate::repository::batch::types::BatchId;
 use crate::repository::batch::BatchRepository;
@@ -10,8 +12,14 @@ use crate::repository::choice::ChoiceRepository;
 use crate::repository::chunk::model::Chunk;
 use crate::repository::chunk::types::{ChunkFilter, ChunkId};
 use crate::repository::chunk::ChunkRepository;
+use crate::repository::comment::model::Comment;
+use crate::repository::comment::types::{CommentFilter, CommentId};
+use crate::repository::comment::CommentRepository;
 use crate::repository::group::model::Group;
 use crate::repository::group::GroupRepository;
+use crate::repository::multisig_proposal::model::MultisigProposal;
+use crate::repository::multisig_proposal::types::{MultisigProposalFilter, MultisigProposalId};
+use crate::repository::multisig_proposal::MultisigProposalRepository;
 use crate::repository::nested_voting::model::NestedVoting;
 use crate::repository::nested_voting::types::{NestedVotingFilter, NestedVotingId};
 use crate::repository::nested_voting::NestedVotingRepository;
@@ -25,11 +33,20 @@ use crate::repository::permission::types::{PermissionFilter, PermissionId};
 use crate::repository::permission::PermissionRepository;
 use crate::repository::profile::model::Profile;
 use crate::repository::profile::ProfileRepository;
+use crate::repository::query_delegation::model::QueryDelegation;
+use crate::repository::query_delegation::types::{QueryDelegationFilter, QueryDelegationId};
+use crate::repository::query_delegation::QueryDelegationRepository;
 use crate::repository::token::model::Token;
 use crate::repository::token::types::{TokenFilter, TokenId};
 use crate::repository::token::TokenRepository;
+use crate::repository::vesting_grant::model::VestingGrant;
+use crate::repository::vesting_grant::types::{VestingGrantFilter, VestingGrantId};
+use crate::repository::vesting_grant::VestingGrantRepository;
+use crate::repository::vote_escrow::model::VoteEscrowLock;
+use crate::repository::vote_escrow::types::{VoteEscrowLockFilter, VoteEscrowLockId};
+use crate::repository::vote_escrow::VoteEscrowLockRepository;
 use crate::repository::voting::model::Voting;
-use crate::repository::voting::types::VotingSort;
+use crate::repository::voting::types::{VotingFilter, VotingSort};
 use crate::repository::voting::VotingRepository;
 use crate::repository::voting_config::model::VotingConfig;
 use crate::repository::voting_config::types::VotingConfigFilter;
@@ -41,30 +58,42 @@ use shared::types::wallet::{
 };
 
 pub mod access_config;
+pub mod access_config_usage;
 pub mod batch;
 pub mod choice;
 pub mod chunk;
+pub mod comment;
 pub mod group;
+pub mod multisig_proposal;
 pub mod nested_voting;
 pub mod nested_voting_config;
 pub mod permission;
 pub mod profile;
+pub mod query_delegation;
 pub mod token;
+pub mod vesting_grant;
+pub mod vote_escrow;
 pub mod voting;
 pub mod voting_config;
 
 #[derive(Default, CandidType, Deserialize)]
 pub struct Repositories {
     access_config: AccessConfigRepository,
+    access_config_usage: AccessConfigUsageRepository,
     batch: BatchRepository,
     choice: ChoiceRepository,
     chunk: ChunkRepository,
+    comment: CommentRepository,
     group: GroupRepository,
+    multisig_proposal: MultisigProposalRepository,
     nested_voting: NestedVotingRepository,
     nested_voting_config: NestedVotingConfigRepository,
     profile: ProfileRepository,
     permission: PermissionRepository,
+    query_delegation: QueryDelegationRepository,
     token: TokenRepository,
+    vesting_grant: VestingGrantRepository,
+    vote_escrow: VoteEscrowLockRepository,
     voting_config: VotingConfigRepository,
     voting: VotingRepository,
 }
@@ -109,6 +138,12 @@ impl HasRepository<Chunk, ChunkId, ChunkFilter, (), ChunkRepository> for Chunk {
     }
 }
 
+impl HasRepository<Comment, CommentId, CommentFilter, (), CommentRepository> for Comment {
+    fn repo() -> &'static mut CommentRepository {
+        &mut get_repositories().comment
+    }
+}
+
 impl HasRepository<Group, GroupId, (), (), GroupRepository> for Group {
     fn repo() -> &'static mut GroupRepository {
         &mut get_repositories().group
@@ -137,6 +172,14 @@ impl HasRepository<AccessConfig, AccessConfigId, AccessConfigFilter, (), AccessC
     }
 }
 
+impl HasRepository<AccessConfigUsage, AccessConfigId, (), (), AccessConfigUsageRepository>
+    for AccessConfigUsage
+{
+    fn repo() -> &'static mut AccessConfigUsageRepository {
+        &mut get_repositories().access_config_usage
+    }
+}
+
 impl HasRepository<Token, TokenId, TokenFilter, (), TokenRepository> for Token {
     fn repo() -> &'static mut TokenRepository {
         &mut get_repositories().token
@@ -151,7 +194,7 @@ impl HasRepository<VotingConfig, VotingConfigId, VotingConfigFilter, (), VotingC
     }
 }
 
-impl HasRepository<Voting, VotingId, (), VotingSort, VotingRepository> for Voting {
+impl HasRepository<Voting, VotingId, VotingFilter, VotingSort, VotingRepository> for Voting {
     fn repo() -> &'static mut VotingRepository {
         &mut get_repositories().voting
     }
@@ -178,3 +221,53 @@ impl HasRepository<NestedVoting, NestedVotingId, NestedVotingFilter, (), NestedV
         &mut get_repositories().nested_voting
     }
 }
+
+impl
+    HasRepository<
+        QueryDelegation,
+        QueryDelegationId,
+        QueryDelegationFilter,
+        (),
+        QueryDelegationRepository,
+    > for QueryDelegation
+{
+    fn repo() -> &'static mut QueryDelegationRepository {
+        &mut get_repositories().query_delegation
+    }
+}
+
+impl
+    HasRepository<
+        MultisigProposal,
+        MultisigProposalId,
+        MultisigProposalFilter,
+        (),
+        MultisigProposalRepository,
+    > for MultisigProposal
+{
+    fn repo() -> &'static mut MultisigProposalRepository {
+        &mut get_repositories().multisig_proposal
+    }
+}
+
+impl HasRepository<VestingGrant, VestingGrantId, VestingGrantFilter, (), VestingGrantRepository>
+    for VestingGrant
+{
+    fn repo() -> &'static mut VestingGrantRepository {
+        &mut get_repositories().vesting_grant
+    }
+}
+
+impl
+    HasRepository<
+        VoteEscrowLock,
+        VoteEscrowLockId,
+        VoteEscrowLockFilter,
+        (),
+        VoteEscrowLockRepository,
+    > for VoteEscrowLock
+{
+    fn repo() -> &'static mut VoteEscrowLockRepository {
+        &mut get_repositories().vote_escrow
+    }
+}
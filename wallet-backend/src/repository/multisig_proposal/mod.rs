@@ -0,0 +1,88 @@
+use crate::repository::multisig_proposal::model::MultisigProposal;
+use crate::repository::multisig_proposal::types::{MultisigProposalFilter, MultisigProposalId};
+use candid::{CandidType, Deserialize};
+use shared::mvc::{IdGenerator, Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use shared::types::wallet::AccessConfigId;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct MultisigProposalRepository {
+    proposals: HashMap<MultisigProposalId, MultisigProposal>,
+    id_gen: IdGenerator,
+
+    proposals_by_access_config_index: BTreeMap<AccessConfigId, BTreeSet<MultisigProposalId>>,
+}
+
+impl Repository<MultisigProposal, MultisigProposalId, MultisigProposalFilter, ()>
+    for MultisigProposalRepository
+{
+    fn save(&mut self, mut it: MultisigProposal) -> MultisigProposalId {
+        if it.is_transient() {
+            it._init_id(self.id_gen.generate());
+        }
+
+        let id = it.get_id().unwrap();
+        self.proposals_by_access_config_index
+            .entry(*it.get_access_config_id())
+            .or_default()
+            .insert(id);
+        self.proposals.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &MultisigProposalId) -> Option<MultisigProposal> {
+        let it = self.proposals.remove(id)?;
+
+        let index = self
+            .proposals_by_access_config_index
+            .get_mut(it.get_access_config_id())
+            .unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.proposals_by_access_config_index
+                .remove(it.get_access_config_id());
+        }
+
+        Some(it)
+    }
+
+    fn get(&self, id: &MultisigProposalId) -> Option<MultisigProposal> {
+        self.proposals.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<MultisigProposalFilter, ()>) -> Page<MultisigProposal> {
+        if let Some(access_config_id) = &page_req.filter.access_config_id {
+            if let Some(index) = self.proposals_by_access_config_index.get(access_config_id) {
+                let (has_next, iter) = index.iter().get_page(page_req);
+                let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+                Page::new(data, has_next)
+            } else {
+                Page::empty()
+            }
+        } else {
+            let (has_next, iter) = self.proposals.iter().get_page(page_req);
+            let data = iter.map(|(_, it)| it.clone()).collect();
+
+            Page::new(data, has_next)
+        }
+    }
+}
+
+impl MultisigProposalRepository {
+    pub fn get_proposals_by_access_config(
+        &self,
+        access_config_id: &AccessConfigId,
+    ) -> BTreeSet<MultisigProposalId> {
+        self.proposals_by_access_config_index
+            .get(access_config_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
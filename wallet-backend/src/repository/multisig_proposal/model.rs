@@ -0,0 +1,120 @@
+use crate::repository::multisig_proposal::types::{MultisigProposalId, MultisigProposalStatus};
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Model;
+use shared::remote_call::Program;
+use shared::types::wallet::AccessConfigId;
+use std::collections::BTreeSet;
+
+// A program, waiting for enough allowees of a multisig access config to approve it
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct MultisigProposal {
+    id: Option<MultisigProposalId>,
+    access_config_id: AccessConfigId,
+    proposer: Principal,
+    program: Program,
+    created_at: u64,
+    expires_at: u64,
+
+    approvers: BTreeSet<Principal>,
+    rejecters: BTreeSet<Principal>,
+    status: MultisigProposalStatus,
+}
+
+impl MultisigProposal {
+    pub fn new(
+        access_config_id: AccessConfigId,
+        proposer: Principal,
+        program: Program,
+        created_at: u64,
+        expires_at: u64,
+    ) -> Self {
+        // the proposer approves the program by proposing it
+        let approvers = vec![proposer].into_iter().collect();
+
+        Self {
+            id: None,
+            access_config_id,
+            proposer,
+            program,
+            created_at,
+            expires_at,
+            approvers,
+            rejecters: BTreeSet::new(),
+            status: MultisigProposalStatus::Pending,
+        }
+    }
+
+    // returns false if the principal has already approved the proposal
+    pub fn approve(&mut self, approver: Principal) -> bool {
+        self.rejecters.remove(&approver);
+        self.approvers.insert(approver)
+    }
+
+    // returns false if the principal has already rejected the proposal
+    pub fn reject(&mut self, rejecter: Principal) -> bool {
+        self.approvers.remove(&rejecter);
+        self.rejecters.insert(rejecter)
+    }
+
+    pub fn set_status(&mut self, status: MultisigProposalStatus) {
+        self.status = status;
+    }
+
+    // proposals are expired lazily, without any cron task
+    pub fn refresh_status(&mut self, timestamp: u64) {
+        if self.is_pending() && self.expires_at < timestamp {
+            self.status = MultisigProposalStatus::Expired;
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self.status, MultisigProposalStatus::Pending)
+    }
+
+    pub fn get_access_config_id(&self) -> &AccessConfigId {
+        &self.access_config_id
+    }
+
+    pub fn get_proposer(&self) -> &Principal {
+        &self.proposer
+    }
+
+    pub fn get_program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    pub fn get_approvers(&self) -> &BTreeSet<Principal> {
+        &self.approvers
+    }
+
+    pub fn get_rejecters(&self) -> &BTreeSet<Principal> {
+        &self.rejecters
+    }
+
+    pub fn get_status(&self) -> &MultisigProposalStatus {
+        &self.status
+    }
+}
+
+impl Model<MultisigProposalId> for MultisigProposal {
+    fn get_id(&self) -> Option<MultisigProposalId> {
+        self.id
+    }
+
+    fn _init_id(&mut self, id: MultisigProposalId) {
+        assert!(self.is_transient());
+        self.id = Some(id);
+    }
+
+    fn is_transient(&self) -> bool {
+        self.id.is_none()
+    }
+}
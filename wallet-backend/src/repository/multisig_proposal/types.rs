@@ -0,0 +1,19 @@
+use candid::{CandidType, Deserialize};
+use shared::mvc::Id;
+use shared::types::wallet::AccessConfigId;
+
+pub type MultisigProposalId = Id;
+
+#[derive(CandidType, Deserialize)]
+pub struct MultisigProposalFilter {
+    pub access_config_id: Option<AccessConfigId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, CandidType, Deserialize)]
+pub enum MultisigProposalStatus {
+    Pending,
+    Executed,
+    Rejected,
+    Cancelled,
+    Expired,
+}
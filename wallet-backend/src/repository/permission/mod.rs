@@ -1,9 +1,13 @@
 use crate::repository::permission::model::Permission;
-use crate::repository::permission::types::{PermissionFilter, PermissionId, PermissionTarget};
-use candid::{CandidType, Deserialize};
+use crate::repository::permission::types::{
+    PermissionFilter, PermissionId, PermissionTarget, PermissionTemplate,
+};
+use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::{IdGenerator, Model, Repository};
 use shared::pageable::{Page, PageRequest, Pageable};
-use std::collections::{BTreeSet, HashMap};
+use shared::remote_call::RemoteCallEndpoint;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::{iter, mem};
 
 pub mod model;
 pub mod types;
@@ -14,6 +18,12 @@ pub struct PermissionRepository {
     id_gen: IdGenerator,
 
     permissions_by_permission_target_index: HashMap<PermissionTarget, BTreeSet<PermissionId>>,
+    // pattern targets by their canister and literal method name prefix, so matching patterns
+    // of a method are found with a lookup per each prefix of its name
+    pattern_targets_by_prefix_index: BTreeMap<(Principal, String), BTreeSet<PermissionTarget>>,
+
+    // permissions, which targets are refreshed from their template on each upgrade
+    template_permissions: BTreeMap<PermissionTemplate, PermissionId>,
 }
 
 impl Repository<Permission, PermissionId, PermissionFilter, ()> for PermissionRepository {
@@ -44,6 +54,8 @@ impl Repository<Permission, PermissionId, PermissionFilter, ()> for PermissionRe
             self.remove_from_target_index(id, target);
         }
 
+        self.template_permissions.retain(|_, it| it != id);
+
         Some(it)
     }
 
@@ -53,38 +65,11 @@ impl Repository<Permission, PermissionId, PermissionFilter, ()> for PermissionRe
 
     fn list(&self, page_req: &PageRequest<PermissionFilter, ()>) -> Page<Permission> {
         if let Some(target) = &page_req.filter.target {
-            let ids_opt = self.permissions_by_permission_target_index.get(target);
-            let ids_wide_opt = if let PermissionTarget::Endpoint(e) = target {
-                if e.is_wildcard() {
-                    None
-                } else {
-                    self.permissions_by_permission_target_index
-                        .get(&PermissionTarget::Endpoint(e.to_wildcard()))
-                }
-            } else {
-                None
-            };
+            let ids = self.get_permissions_by_matching_target(target);
+            let (has_next, iter) = ids.iter().get_page(page_req);
+            let data = iter.map(|id| self.get(id).unwrap()).collect();
 
-            if let Some(ids) = ids_opt {
-                if let Some(ids_wide) = ids_wide_opt {
-                    let (has_next, iter) = ids.union(ids_wide).get_page(page_req);
-                    let data = iter.map(|id| self.get(id).unwrap()).collect();
-
-                    Page::new(data, has_next)
-                } else {
-                    let (has_next, iter) = ids.iter().get_page(page_req);
-                    let data = iter.map(|id| self.get(id).unwrap()).collect();
-
-                    Page::new(data, has_next)
-                }
-            } else if let Some(ids_wide) = ids_wide_opt {
-                let (has_next, iter) = ids_wide.iter().get_page(page_req);
-                let data = iter.map(|id| self.get(id).unwrap()).collect();
-
-                Page::new(data, has_next)
-            } else {
-                Page::empty()
-            }
+            Page::new(data, has_next)
         } else {
             let (has_next, iter) = self.permissions.iter().get_page(page_req);
             let data = iter.map(|(_, it)| it.clone()).collect();
@@ -102,7 +87,82 @@ impl PermissionRepository {
             .unwrap_or_default()
     }
 
+    pub fn set_template_permission(&mut self, template: PermissionTemplate, id: PermissionId) {
+        self.template_permissions.insert(template, id);
+    }
+
+    pub fn get_template_permission(&self, template: &PermissionTemplate) -> Option<PermissionId> {
+        self.template_permissions.get(template).cloned()
+    }
+
+    pub fn get_all(&self) -> Vec<Permission> {
+        self.permissions.values().cloned().collect()
+    }
+
+    // permissions allowing calls to the endpoint - exactly, by a pattern or by the wildcard
+    pub fn get_permissions_by_endpoint(
+        &self,
+        endpoint: &RemoteCallEndpoint,
+    ) -> BTreeSet<PermissionId> {
+        self.get_permissions_by_matching_target(&PermissionTarget::Endpoint(endpoint.clone()))
+    }
+
+    // for exact endpoint and deny targets also includes permissions with matching pattern ones
+    pub fn get_permissions_by_matching_target(
+        &self,
+        target: &PermissionTarget,
+    ) -> BTreeSet<PermissionId> {
+        let mut result = self.get_permissions_by_target(target);
+
+        let endpoint = match target {
+            PermissionTarget::Endpoint(e) | PermissionTarget::Deny(e) if !e.is_pattern() => e,
+            _ => return result,
+        };
+
+        for pattern_target in self.get_pattern_targets_matching(endpoint) {
+            if mem::discriminant(pattern_target) == mem::discriminant(target) {
+                result.extend(self.get_permissions_by_target(pattern_target));
+            }
+        }
+
+        result
+    }
+
+    fn get_pattern_targets_matching(
+        &self,
+        endpoint: &RemoteCallEndpoint,
+    ) -> Vec<&PermissionTarget> {
+        let method_name = &endpoint.method_name;
+        let mut result = vec![];
+
+        let prefix_ends = method_name
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(iter::once(method_name.len()));
+
+        for prefix_end in prefix_ends {
+            let key = (endpoint.canister_id, method_name[..prefix_end].to_string());
+
+            if let Some(targets) = self.pattern_targets_by_prefix_index.get(&key) {
+                result.extend(
+                    targets
+                        .iter()
+                        .filter(|it| it.get_endpoint().unwrap().matches(endpoint)),
+                );
+            }
+        }
+
+        result
+    }
+
     fn add_to_target_index(&mut self, id: PermissionId, target: PermissionTarget) {
+        if let Some(key) = Self::pattern_key(&target) {
+            self.pattern_targets_by_prefix_index
+                .entry(key)
+                .or_default()
+                .insert(target.clone());
+        }
+
         self.permissions_by_permission_target_index
             .entry(target)
             .or_default()
@@ -112,6 +172,35 @@ impl PermissionRepository {
     fn remove_from_target_index(&mut self, id: &PermissionId, target: &PermissionTarget) {
         if let Some(permissions) = self.permissions_by_permission_target_index.get_mut(target) {
             permissions.remove(id);
+
+            if !permissions.is_empty() {
+                return;
+            }
+
+            self.permissions_by_permission_target_index.remove(target);
+        }
+
+        if let Some(key) = Self::pattern_key(target) {
+            if let Some(targets) = self.pattern_targets_by_prefix_index.get_mut(&key) {
+                targets.remove(target);
+
+                if targets.is_empty() {
+                    self.pattern_targets_by_prefix_index.remove(&key);
+                }
+            }
+        }
+    }
+
+    fn pattern_key(target: &PermissionTarget) -> Option<(Principal, String)> {
+        let endpoint = target.get_endpoint()?;
+
+        if endpoint.is_pattern() {
+            Some((
+                endpoint.canister_id,
+                endpoint.get_pattern_prefix().to_string(),
+            ))
+        } else {
+            None
         }
     }
 }
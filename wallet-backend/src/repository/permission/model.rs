@@ -1,10 +1,10 @@
 use crate::repository::permission::types::{
-    PermissionId, PermissionTarget, PERMISSION_DESCRIPTION_MAX_LEN, PERMISSION_DESCRIPTION_MIN_LEN,
-    PERMISSION_NAME_MAX_LEN, PERMISSION_NAME_MIN_LEN,
+    CyclesLimits, PermissionId, PermissionTarget, TargetMatch, PERMISSION_DESCRIPTION_MAX_LEN,
+    PERMISSION_DESCRIPTION_MIN_LEN, PERMISSION_NAME_MAX_LEN, PERMISSION_NAME_MIN_LEN,
 };
 use candid::{CandidType, Deserialize};
 use shared::mvc::Model;
-use shared::remote_call::{Program, RemoteCallEndpoint};
+use shared::remote_call::{Program, RemoteCallEndpoint, RemoteCallPayload};
 use shared::validation::{validate_and_trim_str, ValidationError};
 use std::collections::BTreeSet;
 
@@ -14,32 +14,22 @@ pub struct Permission {
     name: String,
     description: String,
     targets: BTreeSet<PermissionTarget>,
+    cycles_limits: CyclesLimits,
 }
 
 impl Permission {
     pub fn new(
         name: String,
         description: String,
-        mut targets: Vec<PermissionTarget>,
+        targets: Vec<PermissionTarget>,
+        cycles_limits: CyclesLimits,
     ) -> Result<Self, ValidationError> {
-        for target in &mut targets {
-            if let PermissionTarget::Endpoint(endpoint) = target {
-                endpoint.method_name = endpoint.method_name.trim().to_string();
-
-                if endpoint.method_name.is_empty() {
-                    return Err(ValidationError(format!(
-                        "Invalid target endpoint {:?}",
-                        endpoint
-                    )));
-                }
-            }
-        }
-
         let permission = Permission {
             id: None,
             name: Self::process_name(name)?,
             description: Self::process_description(description)?,
-            targets: targets.into_iter().collect(),
+            targets: Self::process_targets(targets)?,
+            cycles_limits,
         };
 
         Ok(permission)
@@ -50,6 +40,7 @@ impl Permission {
         new_name: Option<String>,
         new_description: Option<String>,
         new_targets: Option<BTreeSet<PermissionTarget>>,
+        new_cycles_limits: Option<CyclesLimits>,
     ) -> Result<(), ValidationError> {
         if let Some(name) = new_name {
             self.name = Self::process_name(name)?;
@@ -60,40 +51,31 @@ impl Permission {
         }
 
         if let Some(targets) = new_targets {
-            let mut result = BTreeSet::new();
-
-            for mut target in targets {
-                if let PermissionTarget::Endpoint(endpoint) = &mut target {
-                    endpoint.method_name = endpoint.method_name.trim().to_string();
-
-                    if endpoint.method_name.is_empty() {
-                        return Err(ValidationError(format!(
-                            "Invalid target endpoint {:?}",
-                            endpoint
-                        )));
-                    }
-                }
-
-                result.insert(target);
-            }
+            self.targets = Self::process_targets(targets)?;
+        }
 
-            self.targets = result;
+        if let Some(cycles_limits) = new_cycles_limits {
+            self.cycles_limits = cycles_limits;
         }
 
         Ok(())
     }
 
     pub fn is_program_allowed(&self, program: &Program) -> bool {
+        if !self.cycles_limits.is_program_within(program) {
+            return false;
+        }
+
         match program {
             Program::RemoteCallSequence(sequence) => {
                 for call in sequence {
-                    if !self.is_target(Some(call.endpoint.clone())) {
+                    if !self.match_call(call).is_allowed() {
                         return false;
                     }
                 }
             }
             Program::Empty => {
-                if !self.is_target(None) {
+                if !self.targets.contains(&PermissionTarget::SelfEmptyProgram) {
                     return false;
                 }
             }
@@ -102,30 +84,157 @@ impl Permission {
         true
     }
 
+    // which target of this permission decides on the call
+    pub fn match_call(&self, call: &RemoteCallPayload) -> TargetMatch {
+        self.resolve_endpoint(&call.endpoint, |endpoint| {
+            let target = PermissionTarget::Endpoint(endpoint.clone());
+
+            if self.targets.contains(&target) {
+                Some(target)
+            } else {
+                self.find_constrained_target(endpoint, call).cloned()
+            }
+        })
+    }
+
+    pub fn is_endpoint_allowed(&self, endpoint: &RemoteCallEndpoint) -> bool {
+        self.resolve_endpoint(endpoint, |it| {
+            let target = PermissionTarget::Endpoint(it.clone());
+
+            if self.targets.contains(&target) {
+                Some(target)
+            } else {
+                None
+            }
+        })
+        .is_allowed()
+    }
+
+    // a pattern is only granted as a whole, if nothing on the canister is denied
+    pub fn is_target_granted(&self, target: &PermissionTarget) -> bool {
+        match target {
+            PermissionTarget::Endpoint(endpoint) if endpoint.is_pattern() => {
+                self.targets.contains(target)
+                    && !self.targets.iter().any(|it| match it {
+                        PermissionTarget::Deny(denied) => {
+                            denied.canister_id == endpoint.canister_id
+                        }
+                        _ => false,
+                    })
+            }
+            PermissionTarget::Endpoint(endpoint) => self.is_endpoint_allowed(endpoint),
+            _ => self.targets.contains(target),
+        }
+    }
+
     pub fn get_targets(&self) -> &BTreeSet<PermissionTarget> {
         &self.targets
     }
 
-    fn is_target(&self, endpoint_opt: Option<RemoteCallEndpoint>) -> bool {
-        match endpoint_opt {
-            Some(endpoint) => {
-                let target = PermissionTarget::Endpoint(endpoint.clone());
-                let mut is_target = false;
+    pub fn get_cycles_limits(&self) -> &CyclesLimits {
+        &self.cycles_limits
+    }
+
+    // checks the exact endpoint first, then matching patterns and then the wildcard,
+    // stopping at the first level with a deny or an allow
+    fn resolve_endpoint<F: Fn(&RemoteCallEndpoint) -> Option<PermissionTarget>>(
+        &self,
+        endpoint: &RemoteCallEndpoint,
+        allowing_target: F,
+    ) -> TargetMatch {
+        let levels = [
+            vec![endpoint.clone()],
+            self.get_matching_patterns(endpoint),
+            vec![endpoint.to_wildcard()],
+        ];
+
+        for level in levels {
+            if let Some(denied) = level
+                .iter()
+                .map(|it| PermissionTarget::Deny(it.clone()))
+                .find(|it| self.targets.contains(it))
+            {
+                return TargetMatch::Denied(denied);
+            }
+
+            if let Some(allowed) = level.iter().find_map(&allowing_target) {
+                return TargetMatch::Allowed(allowed);
+            }
+        }
+
+        TargetMatch::NotMatched
+    }
+
+    fn get_matching_patterns(&self, endpoint: &RemoteCallEndpoint) -> Vec<RemoteCallEndpoint> {
+        self.targets
+            .iter()
+            .filter_map(|it| it.get_endpoint())
+            .filter(|it| it.is_pattern() && !it.is_wildcard() && it.matches(endpoint))
+            .cloned()
+            .collect()
+    }
+
+    fn find_constrained_target(
+        &self,
+        endpoint: &RemoteCallEndpoint,
+        call: &RemoteCallPayload,
+    ) -> Option<&PermissionTarget> {
+        self.targets.iter().find(|target| match target {
+            PermissionTarget::ConstrainedEndpoint(c) => {
+                c.endpoint == *endpoint && c.is_call_allowed(call)
+            }
+            _ => false,
+        })
+    }
 
-                if self.targets.contains(&target) {
-                    is_target = true;
-                } else {
-                    let wildcard_target = PermissionTarget::Endpoint(endpoint.to_wildcard());
+    fn process_targets(
+        targets: impl IntoIterator<Item = PermissionTarget>,
+    ) -> Result<BTreeSet<PermissionTarget>, ValidationError> {
+        let mut result = BTreeSet::new();
 
-                    if self.targets.contains(&wildcard_target) {
-                        is_target = true;
+        for mut target in targets {
+            let endpoint = match &mut target {
+                PermissionTarget::Endpoint(endpoint) => Some(endpoint),
+                PermissionTarget::ConstrainedEndpoint(c) => {
+                    if c.predicates.is_empty() {
+                        return Err(ValidationError(format!(
+                            "Constrained target {:?} has no predicates",
+                            c.endpoint
+                        )));
                     }
+
+                    Some(&mut c.endpoint)
+                }
+                PermissionTarget::Deny(endpoint) => Some(endpoint),
+                PermissionTarget::SelfEmptyProgram => None,
+            };
+
+            if let Some(endpoint) = endpoint {
+                endpoint.method_name = endpoint.method_name.trim().to_string();
+
+                if endpoint.method_name.is_empty() {
+                    return Err(ValidationError(format!(
+                        "Invalid target endpoint {:?}",
+                        endpoint
+                    )));
                 }
+            }
+
+            result.insert(target);
+        }
 
-                is_target
+        for target in &result {
+            if let PermissionTarget::Deny(endpoint) = target {
+                if result.contains(&PermissionTarget::Endpoint(endpoint.clone())) {
+                    return Err(ValidationError(format!(
+                        "Target endpoint {:?} is both allowed and denied",
+                        endpoint
+                    )));
+                }
             }
-            None => self.targets.contains(&PermissionTarget::SelfEmptyProgram),
         }
+
+        Ok(result)
     }
 
     fn process_name(name: String) -> Result<String, ValidationError> {
@@ -161,3 +270,265 @@ impl Model<PermissionId> for Permission {
         self.id.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::controller::group::api::MintGroupSharesRequest;
+    use crate::repository::permission::model::Permission;
+    use crate::repository::permission::types::{
+        ArgPredicate, ArgSelector, ArgValue, ConstrainedEndpoint, CyclesLimits, PermissionTarget,
+        TargetMatch,
+    };
+    use candid::parser::value::IDLValue;
+    use candid::{Int, Nat, Principal};
+    use shared::remote_call::{Program, RemoteCallArgs, RemoteCallEndpoint, RemoteCallPayload};
+
+    fn canister() -> Principal {
+        Principal::from_slice(&[1, 2, 3, 4, 5])
+    }
+
+    fn endpoint(method_name: &str) -> RemoteCallEndpoint {
+        RemoteCallEndpoint::new(canister(), method_name)
+    }
+
+    fn permission(targets: Vec<PermissionTarget>) -> Permission {
+        Permission::new(
+            String::from("Test"),
+            String::new(),
+            targets,
+            CyclesLimits::default(),
+        )
+        .unwrap()
+    }
+
+    fn selector(arg_index: u32, path: &[&str]) -> ArgSelector {
+        ArgSelector {
+            arg_index,
+            path: path.iter().map(|it| String::from(*it)).collect(),
+        }
+    }
+
+    // mint into group 5 only, at most 1000 per call
+    fn mint_into_group_5() -> ConstrainedEndpoint {
+        ConstrainedEndpoint {
+            endpoint: endpoint("mint_group_shares"),
+            predicates: vec![
+                ArgPredicate::Equals(selector(0, &["group_id"]), ArgValue::Number(Int::from(5))),
+                ArgPredicate::AtMost(selector(0, &["qty"]), Int::from(1000)),
+            ],
+        }
+    }
+
+    fn mint_candid_string(group_id: u64, qty: u64) -> RemoteCallPayload {
+        let arg = format!(
+            "record {{ group_id = {} : nat64; owner = principal \"aaaaa-aa\"; qty = {} : nat }}",
+            group_id, qty
+        );
+
+        RemoteCallPayload::new(
+            canister(),
+            "mint_group_shares",
+            RemoteCallArgs::CandidString(vec![arg]),
+            0,
+        )
+    }
+
+    fn mint_encoded(group_id: u64, qty: u64) -> RemoteCallPayload {
+        RemoteCallPayload::new_encode(
+            canister(),
+            "mint_group_shares",
+            (MintGroupSharesRequest {
+                group_id,
+                owner: Principal::management_canister(),
+                qty: Nat::from(qty),
+            },),
+            0,
+        )
+    }
+
+    fn resolve(permission: &Permission, method_name: &str) -> TargetMatch {
+        permission.resolve_endpoint(&endpoint(method_name), |it| {
+            let target = PermissionTarget::Endpoint(it.clone());
+
+            if permission.get_targets().contains(&target) {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    #[test]
+    fn exact_beats_pattern_beats_wildcard() {
+        let p = permission(vec![
+            PermissionTarget::Deny(endpoint("*")),
+            PermissionTarget::Endpoint(endpoint("get_*")),
+            PermissionTarget::Deny(endpoint("get_secret_*")),
+            PermissionTarget::Endpoint(endpoint("get_secret_key")),
+        ]);
+
+        assert!(matches!(
+            resolve(&p, "get_secret_key"),
+            TargetMatch::Allowed(PermissionTarget::Endpoint(it)) if it == endpoint("get_secret_key")
+        ));
+        assert!(matches!(
+            resolve(&p, "get_secret_phrase"),
+            TargetMatch::Denied(PermissionTarget::Deny(it)) if it == endpoint("get_secret_*")
+        ));
+        assert!(matches!(
+            resolve(&p, "get_balance"),
+            TargetMatch::Allowed(PermissionTarget::Endpoint(it)) if it == endpoint("get_*")
+        ));
+        assert!(matches!(
+            resolve(&p, "transfer"),
+            TargetMatch::Denied(PermissionTarget::Deny(it)) if it == endpoint("*")
+        ));
+
+        let other =
+            RemoteCallEndpoint::new(Principal::from_slice(&[6, 7, 8, 9, 10]), "get_balance");
+        assert!(!p.is_endpoint_allowed(&other));
+    }
+
+    #[test]
+    fn deny_wins_at_each_level() {
+        let p = permission(vec![
+            PermissionTarget::Deny(endpoint("transfer")),
+            PermissionTarget::Endpoint(endpoint("trans*")),
+            PermissionTarget::Endpoint(endpoint("*")),
+        ]);
+        assert!(matches!(
+            resolve(&p, "transfer"),
+            TargetMatch::Denied(PermissionTarget::Deny(it)) if it == endpoint("transfer")
+        ));
+        assert!(matches!(
+            resolve(&p, "transfer_from"),
+            TargetMatch::Allowed(_)
+        ));
+
+        let p = permission(vec![
+            PermissionTarget::Endpoint(endpoint("get_*")),
+            PermissionTarget::Deny(endpoint("get_?alance")),
+            PermissionTarget::Endpoint(endpoint("*")),
+        ]);
+        assert!(matches!(
+            resolve(&p, "get_balance"),
+            TargetMatch::Denied(PermissionTarget::Deny(it)) if it == endpoint("get_?alance")
+        ));
+        assert!(matches!(resolve(&p, "get_name"), TargetMatch::Allowed(_)));
+
+        let p = permission(vec![
+            PermissionTarget::Endpoint(endpoint("transfer")),
+            PermissionTarget::Deny(endpoint("*")),
+        ]);
+        assert!(matches!(resolve(&p, "transfer"), TargetMatch::Allowed(_)));
+        assert!(matches!(
+            resolve(&p, "burn"),
+            TargetMatch::Denied(PermissionTarget::Deny(it)) if it == endpoint("*")
+        ));
+        assert!(!p.is_target_granted(&PermissionTarget::Endpoint(endpoint("*"))));
+    }
+
+    #[test]
+    fn nothing_matches_without_targets_on_the_canister() {
+        let p = permission(vec![PermissionTarget::SelfEmptyProgram]);
+
+        assert!(matches!(resolve(&p, "transfer"), TargetMatch::NotMatched));
+    }
+
+    #[test]
+    fn same_endpoint_cannot_be_allowed_and_denied() {
+        let result = Permission::new(
+            String::from("Test"),
+            String::new(),
+            vec![
+                PermissionTarget::Endpoint(endpoint("get_*")),
+                PermissionTarget::Deny(endpoint("get_*")),
+            ],
+            CyclesLimits::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn constrained_endpoint_checks_candid_string_args() {
+        let target = mint_into_group_5();
+
+        assert!(target.is_call_allowed(&mint_candid_string(5, 1000)));
+        assert!(target.is_call_allowed(&mint_candid_string(5, 1)));
+        assert!(!target.is_call_allowed(&mint_candid_string(5, 1001)));
+        assert!(!target.is_call_allowed(&mint_candid_string(6, 1000)));
+    }
+
+    #[test]
+    fn constrained_endpoint_checks_encoded_args() {
+        let target = mint_into_group_5();
+
+        assert!(target.is_call_allowed(&mint_encoded(5, 1000)));
+        assert!(!target.is_call_allowed(&mint_encoded(5, 1001)));
+        assert!(!target.is_call_allowed(&mint_encoded(6, 1000)));
+    }
+
+    #[test]
+    fn constrained_endpoint_checks_the_endpoint_first() {
+        let target = mint_into_group_5();
+        let mut call = mint_candid_string(5, 1000);
+        call.endpoint = endpoint("burn_group_shares");
+
+        assert!(!target.is_call_allowed(&call));
+
+        let p = permission(vec![PermissionTarget::ConstrainedEndpoint(target)]);
+        assert!(p.is_program_allowed(&Program::RemoteCallSequence(vec![mint_encoded(5, 1000)])));
+        assert!(!p.is_program_allowed(&Program::RemoteCallSequence(vec![
+            mint_encoded(5, 1000),
+            mint_encoded(5, 1001),
+        ])));
+    }
+
+    #[test]
+    fn selector_walks_records_variants_and_vectors() {
+        let args = vec![
+            "record { items = vec { variant { Some = 1 : nat8 }; variant { None } } }"
+                .parse::<IDLValue>()
+                .unwrap(),
+        ];
+
+        assert!(matches!(
+            selector(0, &["items", "0", "Some"]).select(&args),
+            Some(IDLValue::Nat8(1))
+        ));
+        assert!(selector(0, &["items", "1", "Some"]).select(&args).is_none());
+    }
+
+    #[test]
+    fn predicates_fail_on_missing_fields_wrong_types_and_bad_indexes() {
+        let args = vec![
+            "record { group_id = 5 : nat64; name = \"test\"; owners = vec { principal \"aaaaa-aa\" } }"
+                .parse::<IDLValue>()
+                .unwrap(),
+        ];
+        let owners = vec![Principal::management_canister()].into_iter().collect();
+
+        // missing field or argument
+        assert!(!ArgPredicate::AtMost(selector(0, &["qty"]), Int::from(1000)).check(&args));
+        assert!(!ArgPredicate::AtMost(selector(1, &["group_id"]), Int::from(1000)).check(&args));
+
+        // wrong type
+        assert!(!ArgPredicate::AtMost(selector(0, &["name"]), Int::from(1000)).check(&args));
+        assert!(!ArgPredicate::PrincipalIn(selector(0, &["group_id"]), owners).check(&args));
+        assert!(!ArgPredicate::Equals(
+            selector(0, &["group_id"]),
+            ArgValue::Text(String::from("5"))
+        )
+        .check(&args));
+        assert!(selector(0, &["group_id", "0"]).select(&args).is_none());
+
+        // out of range or non-numeric vector index
+        assert!(selector(0, &["owners", "1"]).select(&args).is_none());
+        assert!(selector(0, &["owners", "first"]).select(&args).is_none());
+
+        let owners = vec![Principal::management_canister()].into_iter().collect();
+        assert!(ArgPredicate::PrincipalIn(selector(0, &["owners", "0"]), owners).check(&args));
+        assert!(ArgPredicate::AtLeast(selector(0, &["group_id"]), Int::from(5)).check(&args));
+    }
+}
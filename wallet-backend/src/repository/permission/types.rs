@@ -1,6 +1,11 @@
-use ic_cdk::export::candid::{CandidType, Deserialize};
+use candid::parser::value::{IDLValue, VariantValue};
+use candid::types::Label;
+use candid::{idl_hash, Int};
+use ic_cdk::export::candid::{CandidType, Deserialize, Principal};
 use shared::mvc::Id;
-use shared::remote_call::RemoteCallEndpoint;
+use shared::remote_call::{Program, RemoteCallEndpoint, RemoteCallPayload};
+use std::collections::BTreeSet;
+use std::str::FromStr;
 
 pub const PERMISSION_NAME_MIN_LEN: usize = 1;
 pub const PERMISSION_NAME_MAX_LEN: usize = 100;
@@ -13,9 +18,247 @@ pub type PermissionId = Id;
 pub enum PermissionTarget {
     SelfEmptyProgram,
     Endpoint(RemoteCallEndpoint),
+    ConstrainedEndpoint(ConstrainedEndpoint),
+    // Forbids calls to the endpoint (or to matching ones, if its method name is a glob pattern)
+    // within the permission. An exact target beats a pattern one, a pattern beats the wildcard
+    // and a deny beats an allow of the same specificity.
+    Deny(RemoteCallEndpoint),
+}
+
+// A predefined set of wallet methods, generated from the wallet interface
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum PermissionTemplate {
+    ReadOnly,
+    GroupAdmin,
+    VotingAdmin,
+    StreamingAdmin,
+}
+
+impl PermissionTemplate {
+    pub const ALL: [PermissionTemplate; 4] = [
+        PermissionTemplate::ReadOnly,
+        PermissionTemplate::GroupAdmin,
+        PermissionTemplate::VotingAdmin,
+        PermissionTemplate::StreamingAdmin,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PermissionTemplate::ReadOnly => "Read-only",
+            PermissionTemplate::GroupAdmin => "Group admin",
+            PermissionTemplate::VotingAdmin => "Voting admin",
+            PermissionTemplate::StreamingAdmin => "Streaming admin",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            PermissionTemplate::ReadOnly => {
+                "Allows to call any non-personal read method of this union."
+            }
+            PermissionTemplate::GroupAdmin => "Allows to manage groups and their shares.",
+            PermissionTemplate::VotingAdmin => {
+                "Allows to manage votings, voting configs, nested votings and comments."
+            }
+            PermissionTemplate::StreamingAdmin => "Allows to manage batches and chunks.",
+        }
+    }
+}
+
+// A target of a permission deciding on a call, if any
+#[derive(CandidType, Deserialize, Clone, Debug)]
+pub enum TargetMatch {
+    Allowed(PermissionTarget),
+    Denied(PermissionTarget),
+    NotMatched,
+}
+
+impl TargetMatch {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, TargetMatch::Allowed(_))
+    }
+}
+
+impl PermissionTarget {
+    pub fn get_endpoint(&self) -> Option<&RemoteCallEndpoint> {
+        match self {
+            PermissionTarget::SelfEmptyProgram => None,
+            PermissionTarget::Endpoint(endpoint) => Some(endpoint),
+            PermissionTarget::ConstrainedEndpoint(c) => Some(&c.endpoint),
+            PermissionTarget::Deny(endpoint) => Some(endpoint),
+        }
+    }
+}
+
+// Limits on cycles attached to calls of a program, no limit if none
+#[derive(CandidType, Deserialize, Clone, Copy, Default, Debug)]
+pub struct CyclesLimits {
+    pub max_per_call: Option<u64>,
+    pub max_per_program: Option<u64>,
+}
+
+impl CyclesLimits {
+    pub fn is_program_within(&self, program: &Program) -> bool {
+        if let Some(max) = self.max_per_call {
+            if program.get_max_cycles_per_call() > max {
+                return false;
+            }
+        }
+
+        if let Some(max) = self.max_per_program {
+            if program.get_total_cycles() > max {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 #[derive(CandidType, Deserialize)]
 pub struct PermissionFilter {
     pub target: Option<PermissionTarget>,
 }
+
+// Points to a value inside call arguments: an argument index, then record field names,
+// variant tags or vector/tuple element indexes, e.g. (0, ["group_id"])
+#[derive(CandidType, Deserialize, Clone, PartialEq, Ord, PartialOrd, Eq, Hash, Debug)]
+pub struct ArgSelector {
+    pub arg_index: u32,
+    pub path: Vec<String>,
+}
+
+impl ArgSelector {
+    pub fn select<'a>(&self, args: &'a [IDLValue]) -> Option<&'a IDLValue> {
+        let mut value = args.get(self.arg_index as usize)?;
+
+        for segment in &self.path {
+            value = Self::unwrap_opt(value);
+
+            value = match value {
+                IDLValue::Record(fields) => {
+                    &fields.iter().find(|f| Self::is_label(&f.id, segment))?.val
+                }
+                IDLValue::Variant(VariantValue(field, _)) if Self::is_label(&field.id, segment) => {
+                    &field.val
+                }
+                IDLValue::Vec(items) => items.get(segment.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        Some(Self::unwrap_opt(value))
+    }
+
+    // labels are hashed when args are decoded without type info, so comparing by ids
+    fn is_label(label: &Label, segment: &str) -> bool {
+        match segment.parse::<u32>() {
+            Ok(id) => label.get_id() == id,
+            Err(_) => label.get_id() == idl_hash(segment),
+        }
+    }
+
+    fn unwrap_opt(mut value: &IDLValue) -> &IDLValue {
+        while let IDLValue::Opt(inner) = value {
+            value = inner;
+        }
+
+        value
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Ord, PartialOrd, Eq, Hash, Debug)]
+pub enum ArgValue {
+    Bool(bool),
+    Number(Int),
+    Text(String),
+    Principal(Principal),
+}
+
+impl ArgValue {
+    pub fn from_idl_value(value: &IDLValue) -> Option<Self> {
+        match value {
+            IDLValue::Bool(b) => Some(ArgValue::Bool(*b)),
+            IDLValue::Text(t) => Some(ArgValue::Text(t.clone())),
+            IDLValue::Principal(p) => Some(ArgValue::Principal(*p)),
+            _ => Self::number_from_idl_value(value).map(ArgValue::Number),
+        }
+    }
+
+    fn number_from_idl_value(value: &IDLValue) -> Option<Int> {
+        let str = match value {
+            IDLValue::Number(n) => n.clone(),
+            IDLValue::Nat(n) => n.0.to_string(),
+            IDLValue::Int(i) => i.0.to_string(),
+            IDLValue::Nat8(n) => n.to_string(),
+            IDLValue::Nat16(n) => n.to_string(),
+            IDLValue::Nat32(n) => n.to_string(),
+            IDLValue::Nat64(n) => n.to_string(),
+            IDLValue::Int8(i) => i.to_string(),
+            IDLValue::Int16(i) => i.to_string(),
+            IDLValue::Int32(i) => i.to_string(),
+            IDLValue::Int64(i) => i.to_string(),
+            _ => return None,
+        };
+
+        Int::from_str(&str).ok()
+    }
+}
+
+// A condition on call arguments - all conditions of a target should hold for a call to match
+#[derive(CandidType, Deserialize, Clone, PartialEq, Ord, PartialOrd, Eq, Hash, Debug)]
+pub enum ArgPredicate {
+    Equals(ArgSelector, ArgValue),
+    AtMost(ArgSelector, Int),
+    AtLeast(ArgSelector, Int),
+    PrincipalIn(ArgSelector, BTreeSet<Principal>),
+}
+
+impl ArgPredicate {
+    pub fn check(&self, args: &[IDLValue]) -> bool {
+        let actual = match self
+            .get_selector()
+            .select(args)
+            .and_then(ArgValue::from_idl_value)
+        {
+            Some(it) => it,
+            None => return false,
+        };
+
+        match (self, actual) {
+            (ArgPredicate::Equals(_, expected), actual) => actual == *expected,
+            (ArgPredicate::AtMost(_, max), ArgValue::Number(n)) => n <= *max,
+            (ArgPredicate::AtLeast(_, min), ArgValue::Number(n)) => n >= *min,
+            (ArgPredicate::PrincipalIn(_, set), ArgValue::Principal(p)) => set.contains(&p),
+            _ => false,
+        }
+    }
+
+    pub fn get_selector(&self) -> &ArgSelector {
+        match self {
+            ArgPredicate::Equals(s, _)
+            | ArgPredicate::AtMost(s, _)
+            | ArgPredicate::AtLeast(s, _)
+            | ArgPredicate::PrincipalIn(s, _) => s,
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize, Clone, PartialEq, Ord, PartialOrd, Eq, Hash, Debug)]
+pub struct ConstrainedEndpoint {
+    pub endpoint: RemoteCallEndpoint,
+    pub predicates: Vec<ArgPredicate>,
+}
+
+impl ConstrainedEndpoint {
+    pub fn is_call_allowed(&self, call: &RemoteCallPayload) -> bool {
+        if !self.endpoint.matches(&call.endpoint) {
+            return false;
+        }
+
+        match call.args.decode_args() {
+            Ok(args) => self.predicates.iter().all(|it| it.check(&args)),
+            Err(_) => false,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::repository::query_delegation::model::QueryDelegation;
+use crate::repository::query_delegation::types::{QueryDelegationFilter, QueryDelegationId};
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::{IdGenerator, Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct QueryDelegationRepository {
+    delegations: HashMap<QueryDelegationId, QueryDelegation>,
+    id_gen: IdGenerator,
+
+    delegations_by_delegate_index: BTreeMap<Principal, BTreeSet<QueryDelegationId>>,
+
+    // delegate -> revocation timestamp; proofs issued to the delegate before it are rejected
+    revoked_delegates: BTreeMap<Principal, u64>,
+}
+
+impl Repository<QueryDelegation, QueryDelegationId, QueryDelegationFilter, ()>
+    for QueryDelegationRepository
+{
+    fn save(&mut self, mut it: QueryDelegation) -> QueryDelegationId {
+        if it.is_transient() {
+            it._init_id(self.id_gen.generate());
+        }
+
+        let id = it.get_id().unwrap();
+        self.delegations_by_delegate_index
+            .entry(*it.get_delegate_id())
+            .or_default()
+            .insert(id);
+        self.delegations.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &QueryDelegationId) -> Option<QueryDelegation> {
+        let it = self.delegations.remove(id)?;
+
+        let index = self
+            .delegations_by_delegate_index
+            .get_mut(it.get_delegate_id())
+            .unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.delegations_by_delegate_index
+                .remove(it.get_delegate_id());
+        }
+
+        Some(it)
+    }
+
+    fn get(&self, id: &QueryDelegationId) -> Option<QueryDelegation> {
+        self.delegations.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<QueryDelegationFilter, ()>) -> Page<QueryDelegation> {
+        if let Some(delegate_id) = &page_req.filter.delegate_id {
+            if let Some(index) = self.delegations_by_delegate_index.get(delegate_id) {
+                let (has_next, iter) = index.iter().get_page(page_req);
+                let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+                Page::new(data, has_next)
+            } else {
+                Page::empty()
+            }
+        } else {
+            let (has_next, iter) = self.delegations.iter().get_page(page_req);
+            let data = iter.map(|(_, it)| it.clone()).collect();
+
+            Page::new(data, has_next)
+        }
+    }
+}
+
+impl QueryDelegationRepository {
+    pub fn get_delegations_by_delegate(&self, delegate_id: &Principal) -> Vec<QueryDelegationId> {
+        self.delegations_by_delegate_index
+            .get(delegate_id)
+            .map(|it| it.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn get_all(&self) -> Vec<QueryDelegation> {
+        self.delegations.values().cloned().collect()
+    }
+
+    pub fn revoke_delegate(&mut self, delegate_id: Principal, timestamp: u64) {
+        self.revoked_delegates.insert(delegate_id, timestamp);
+    }
+
+    pub fn get_revoked_at(&self, delegate_id: &Principal) -> Option<u64> {
+        self.revoked_delegates.get(delegate_id).cloned()
+    }
+
+    pub fn list_revoked_delegates(&self) -> Vec<(Principal, u64)> {
+        self.revoked_delegates
+            .iter()
+            .map(|(delegate_id, timestamp)| (*delegate_id, *timestamp))
+            .collect()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::repository::permission::types::PermissionTarget;
+use crate::repository::query_delegation::types::QueryDelegationId;
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Model;
+
+// A query delegation issued by this union
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct QueryDelegation {
+    id: Option<QueryDelegationId>,
+    delegate_id: Principal,
+    allowed_query_targets: Vec<PermissionTarget>,
+    issued_at: u64,
+    expires_at: u64,
+}
+
+impl QueryDelegation {
+    pub fn new(
+        delegate_id: Principal,
+        allowed_query_targets: Vec<PermissionTarget>,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> Self {
+        Self {
+            id: None,
+            delegate_id,
+            allowed_query_targets,
+            issued_at,
+            expires_at,
+        }
+    }
+
+    pub fn is_expired(&self, timestamp: u64) -> bool {
+        self.expires_at < timestamp
+    }
+
+    pub fn get_delegate_id(&self) -> &Principal {
+        &self.delegate_id
+    }
+
+    pub fn get_allowed_query_targets(&self) -> &Vec<PermissionTarget> {
+        &self.allowed_query_targets
+    }
+
+    pub fn get_issued_at(&self) -> u64 {
+        self.issued_at
+    }
+
+    pub fn get_expires_at(&self) -> u64 {
+        self.expires_at
+    }
+}
+
+impl Model<QueryDelegationId> for QueryDelegation {
+    fn get_id(&self) -> Option<QueryDelegationId> {
+        self.id
+    }
+
+    fn _init_id(&mut self, id: QueryDelegationId) {
+        assert!(self.is_transient());
+        self.id = Some(id);
+    }
+
+    fn is_transient(&self) -> bool {
+        self.id.is_none()
+    }
+}
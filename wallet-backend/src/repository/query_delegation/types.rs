@@ -0,0 +1,9 @@
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Id;
+
+pub type QueryDelegationId = Id;
+
+#[derive(CandidType, Deserialize)]
+pub struct QueryDelegationFilter {
+    pub delegate_id: Option<Principal>,
+}
@@ -1,10 +1,10 @@
-use crate::repository::token::types::{ChoiceOrGroup, TokenId};
+use crate::repository::token::types::{BalanceChange, ChoiceOrGroup, TokenId};
 use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::Model;
 use shared::pageable::{Page, PageRequest, Pageable};
 use shared::types::wallet::Shares;
 use shared::validation::ValidationError;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 #[derive(Clone, CandidType, Deserialize)]
 pub struct Token {
@@ -19,6 +19,8 @@ pub struct Token {
 
     unaccepted_total_supply: Shares,
     unaccepted_balances: HashMap<Principal, Shares>,
+
+    locked_balances: HashMap<Principal, Shares>,
 }
 
 impl Token {
@@ -32,6 +34,7 @@ impl Token {
             balances: HashMap::default(),
             unaccepted_total_supply: Shares::default(),
             unaccepted_balances: HashMap::default(),
+            locked_balances: HashMap::default(),
         }
     }
 
@@ -40,6 +43,7 @@ impl Token {
         self.total_supply = Shares::default();
         self.unaccepted_balances = HashMap::new();
         self.unaccepted_total_supply = Shares::default();
+        self.locked_balances = HashMap::new();
     }
 
     pub fn mint(&mut self, to: Principal, qty: Shares) {
@@ -61,6 +65,8 @@ impl Token {
         let balance = self.balance_of(&from);
         if balance < qty {
             Err(ValidationError(String::from("Insufficient balance")))
+        } else if self.unlocked_balance_of(&from) < qty {
+            Err(ValidationError(String::from("Insufficient unlocked balance")))
         } else {
             self.balances.insert(from, balance - qty.clone());
             self.total_supply -= qty;
@@ -68,6 +74,37 @@ impl Token {
         }
     }
 
+    pub fn lock(&mut self, of: Principal, qty: Shares) -> Result<(), ValidationError> {
+        if self.unlocked_balance_of(&of) < qty {
+            Err(ValidationError(String::from("Insufficient unlocked balance")))
+        } else {
+            self.locked_balances
+                .insert(of, self.locked_balance_of(&of) + qty);
+            Ok(())
+        }
+    }
+
+    pub fn unlock(&mut self, of: Principal, qty: Shares) -> Result<(), ValidationError> {
+        let locked_balance = self.locked_balance_of(&of);
+        if locked_balance < qty {
+            Err(ValidationError(String::from("Insufficient locked balance")))
+        } else {
+            self.locked_balances.insert(of, locked_balance - qty);
+            Ok(())
+        }
+    }
+
+    // removes locked shares regardless of transferability (e.g. when a proposal bond is forfeited)
+    pub fn burn_locked(&mut self, from: Principal, qty: Shares) -> Result<(), ValidationError> {
+        self.unlock(from, qty.clone())?;
+
+        self.balances
+            .insert(from, self.balance_of(&from) - qty.clone());
+        self.total_supply -= qty;
+
+        Ok(())
+    }
+
     pub fn burn_unaccepted(&mut self, from: Principal, qty: Shares) -> Result<(), ValidationError> {
         assert!(self.acceptable);
 
@@ -97,28 +134,90 @@ impl Token {
         Ok(())
     }
 
-    pub fn make_acceptable(&mut self) {
+    // moves unlocked balances back to unaccepted, locked shares stay where they are;
+    // returns how much was moved from each holder
+    pub fn make_acceptable(&mut self) -> Vec<BalanceChange> {
         assert!(!self.acceptable);
         assert!(self.unaccepted_balances.is_empty());
         assert_eq!(self.unaccepted_total_supply, Shares::default());
 
         self.acceptable = true;
 
-        std::mem::swap(&mut self.balances, &mut self.unaccepted_balances);
-        std::mem::swap(&mut self.total_supply, &mut self.unaccepted_total_supply);
+        let mut changes = Vec::new();
+
+        for of in self.list_holders() {
+            let qty = self.unlocked_balance_of(&of);
+            if qty == Shares::default() {
+                continue;
+            }
+
+            let new_balance = self.balance_of(&of) - qty.clone();
+            if new_balance == Shares::default() {
+                self.balances.remove(&of);
+            } else {
+                self.balances.insert(of, new_balance.clone());
+            }
+            self.total_supply -= qty.clone();
+
+            self.mint_unaccepted(of, qty.clone());
+
+            changes.push(BalanceChange {
+                owner: of,
+                qty,
+                new_balance,
+                total_supply: self.total_supply(),
+            });
+        }
+
+        changes
     }
 
-    pub fn make_not_acceptable(&mut self) {
+    // returns how much was minted to each holder of unaccepted shares
+    pub fn make_not_acceptable(&mut self) -> Vec<BalanceChange> {
         assert!(self.acceptable);
 
         self.acceptable = false;
 
-        let all_unaccepted = std::mem::take(&mut self.unaccepted_balances);
+        let mut unaccepted = std::mem::take(&mut self.unaccepted_balances)
+            .into_iter()
+            .filter(|(_, qty)| *qty != Shares::default())
+            .collect::<Vec<_>>();
+        unaccepted.sort_by_key(|(of, _)| *of);
+
         self.unaccepted_total_supply = Shares::default();
 
-        for (of, qty) in all_unaccepted {
-            self.mint(of, qty);
+        let mut changes = Vec::new();
+
+        for (of, qty) in unaccepted {
+            self.mint(of, qty.clone());
+
+            changes.push(BalanceChange {
+                owner: of,
+                qty,
+                new_balance: self.balance_of(&of),
+                total_supply: self.total_supply(),
+            });
         }
+
+        changes
+    }
+
+    // balances of computed groups follow their rule, regardless of transferability
+    pub fn rebalance(&mut self, of: Principal, new_balance: Shares) {
+        let balance = self.balance_of(&of);
+
+        if new_balance > balance {
+            self.total_supply += new_balance.clone() - balance;
+        } else {
+            self.total_supply -= balance - new_balance.clone();
+        }
+
+        // locks can't outlive the shares they hold, so they shrink with the balance
+        if self.locked_balance_of(&of) > new_balance {
+            self.locked_balances.insert(of, new_balance.clone());
+        }
+
+        self.balances.insert(of, new_balance);
     }
 
     pub fn set_transferable(&mut self, value: bool) {
@@ -150,6 +249,14 @@ impl Token {
         self.balances.get(of).cloned().unwrap_or_default()
     }
 
+    pub fn locked_balance_of(&self, of: &Principal) -> Shares {
+        self.locked_balances.get(of).cloned().unwrap_or_default()
+    }
+
+    pub fn unlocked_balance_of(&self, of: &Principal) -> Shares {
+        self.balance_of(of) - self.locked_balance_of(of)
+    }
+
     pub fn unaccepted_balance_of(&self, of: &Principal) -> Shares {
         self.unaccepted_balances
             .get(of)
@@ -165,6 +272,10 @@ impl Token {
         self.unaccepted_total_supply.clone()
     }
 
+    pub fn list_holders(&self) -> BTreeSet<Principal> {
+        self.balances.keys().cloned().collect()
+    }
+
     pub fn balances(&self, page_req: &PageRequest<(), ()>) -> Page<(Principal, Shares)> {
         let (has_next, iter) = self.balances.iter().get_page(page_req);
         let data = iter.map(|(id, it)| (*id, it.clone())).collect();
@@ -194,3 +305,132 @@ impl Model<TokenId> for Token {
         self.id.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::token::model::Token;
+    use crate::repository::token::types::ChoiceOrGroup;
+    use candid::Principal;
+    use shared::types::wallet::Shares;
+    use std::collections::BTreeSet;
+
+    fn member() -> Principal {
+        Principal::from_slice(&[1, 2, 3, 4, 5])
+    }
+
+    fn other_member() -> Principal {
+        Principal::from_slice(&[6, 7, 8, 9, 10])
+    }
+
+    fn public_token() -> Token {
+        let mut token = Token::new(ChoiceOrGroup::Group(1), false, true);
+
+        token.mint(member(), Shares::from(100));
+        token.mint(other_member(), Shares::from(50));
+
+        token
+    }
+
+    fn computed_token() -> Token {
+        Token::new(ChoiceOrGroup::Group(1), false, false)
+    }
+
+    #[test]
+    fn rebalance_below_locked_clamps_the_lock() {
+        let mut token = computed_token();
+
+        token.rebalance(member(), Shares::from(100));
+        token.lock(member(), Shares::from(80)).unwrap();
+
+        token.rebalance(member(), Shares::from(30));
+
+        assert_eq!(token.balance_of(&member()), Shares::from(30));
+        assert_eq!(token.locked_balance_of(&member()), Shares::from(30));
+        assert_eq!(token.unlocked_balance_of(&member()), Shares::default());
+        assert_eq!(token.total_supply(), Shares::from(30));
+
+        token.rebalance(member(), Shares::default());
+
+        assert_eq!(token.locked_balance_of(&member()), Shares::default());
+        assert_eq!(token.unlocked_balance_of(&member()), Shares::default());
+        assert_eq!(token.total_supply(), Shares::default());
+    }
+
+    #[test]
+    fn rebalance_above_locked_keeps_the_lock() {
+        let mut token = computed_token();
+
+        token.rebalance(member(), Shares::from(100));
+        token.lock(member(), Shares::from(20)).unwrap();
+
+        token.rebalance(member(), Shares::from(60));
+
+        assert_eq!(token.locked_balance_of(&member()), Shares::from(20));
+        assert_eq!(token.unlocked_balance_of(&member()), Shares::from(40));
+        assert_eq!(token.total_supply(), Shares::from(60));
+    }
+
+    #[test]
+    fn make_acceptable_moves_unlocked_shares_with_running_total_supply() {
+        let mut token = public_token();
+        token.lock(member(), Shares::from(30)).unwrap();
+
+        let changes = token.make_acceptable();
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].owner, member());
+        assert_eq!(changes[0].qty, Shares::from(70));
+        assert_eq!(changes[0].new_balance, Shares::from(30));
+        assert_eq!(changes[0].total_supply, Shares::from(80));
+        assert_eq!(changes[1].owner, other_member());
+        assert_eq!(changes[1].qty, Shares::from(50));
+        assert_eq!(changes[1].new_balance, Shares::default());
+        assert_eq!(changes[1].total_supply, Shares::from(30));
+
+        for change in &changes {
+            assert_eq!(change.new_balance, token.balance_of(&change.owner));
+        }
+        assert_eq!(changes[1].total_supply, token.total_supply());
+
+        assert_eq!(token.locked_balance_of(&member()), Shares::from(30));
+        assert_eq!(token.unaccepted_balance_of(&member()), Shares::from(70));
+        assert_eq!(
+            token.unaccepted_balance_of(&other_member()),
+            Shares::from(50)
+        );
+        assert_eq!(token.unaccepted_total_supply(), Shares::from(120));
+
+        // holders without any accepted shares left are gone
+        assert_eq!(
+            token.list_holders(),
+            vec![member()].into_iter().collect::<BTreeSet<_>>()
+        );
+    }
+
+    #[test]
+    fn make_not_acceptable_accepts_everything_with_running_total_supply() {
+        let mut token = public_token();
+        token.lock(member(), Shares::from(30)).unwrap();
+        token.make_acceptable();
+
+        let changes = token.make_not_acceptable();
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].owner, member());
+        assert_eq!(changes[0].qty, Shares::from(70));
+        assert_eq!(changes[0].new_balance, Shares::from(100));
+        assert_eq!(changes[0].total_supply, Shares::from(100));
+        assert_eq!(changes[1].owner, other_member());
+        assert_eq!(changes[1].qty, Shares::from(50));
+        assert_eq!(changes[1].new_balance, Shares::from(50));
+        assert_eq!(changes[1].total_supply, Shares::from(150));
+
+        for change in &changes {
+            assert_eq!(change.new_balance, token.balance_of(&change.owner));
+        }
+        assert_eq!(changes[1].total_supply, token.total_supply());
+
+        assert_eq!(token.unaccepted_total_supply(), Shares::default());
+        assert_eq!(token.locked_balance_of(&member()), Shares::from(30));
+    }
+}
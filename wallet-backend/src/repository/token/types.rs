@@ -1,6 +1,6 @@
 use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::Id;
-use shared::types::wallet::{ChoiceId, GroupId};
+use shared::types::wallet::{ChoiceId, GroupId, Shares};
 
 pub type TokenId = Id;
 
@@ -14,3 +14,13 @@ pub enum ChoiceOrGroup {
     Choice(ChoiceId, GroupId),
     Group(GroupId),
 }
+
+// Accepted balance of a holder changed by qty, the resulting balance and total supply are
+// captured right after the change
+#[derive(Clone, Debug)]
+pub struct BalanceChange {
+    pub owner: Principal,
+    pub qty: Shares,
+    pub new_balance: Shares,
+    pub total_supply: Shares,
+}
@@ -0,0 +1,103 @@
+use crate::repository::vesting_grant::model::VestingGrant;
+use crate::repository::vesting_grant::types::{VestingGrantFilter, VestingGrantId};
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::{IdGenerator, Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use shared::types::wallet::GroupId;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct VestingGrantRepository {
+    grants: HashMap<VestingGrantId, VestingGrant>,
+    id_gen: IdGenerator,
+
+    grants_by_group_index: BTreeMap<GroupId, BTreeSet<VestingGrantId>>,
+    grants_by_beneficiary_index: BTreeMap<Principal, BTreeSet<VestingGrantId>>,
+}
+
+impl Repository<VestingGrant, VestingGrantId, VestingGrantFilter, ()> for VestingGrantRepository {
+    fn save(&mut self, mut it: VestingGrant) -> VestingGrantId {
+        if it.is_transient() {
+            it._init_id(self.id_gen.generate());
+        }
+
+        let id = it.get_id().unwrap();
+        self.grants_by_group_index
+            .entry(*it.get_group_id())
+            .or_default()
+            .insert(id);
+        self.grants_by_beneficiary_index
+            .entry(*it.get_beneficiary())
+            .or_default()
+            .insert(id);
+        self.grants.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &VestingGrantId) -> Option<VestingGrant> {
+        let it = self.grants.remove(id)?;
+
+        let index = self
+            .grants_by_group_index
+            .get_mut(it.get_group_id())
+            .unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.grants_by_group_index.remove(it.get_group_id());
+        }
+
+        let index = self
+            .grants_by_beneficiary_index
+            .get_mut(it.get_beneficiary())
+            .unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.grants_by_beneficiary_index
+                .remove(it.get_beneficiary());
+        }
+
+        Some(it)
+    }
+
+    fn get(&self, id: &VestingGrantId) -> Option<VestingGrant> {
+        self.grants.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<VestingGrantFilter, ()>) -> Page<VestingGrant> {
+        // the group filter takes precedence over the beneficiary filter
+        let index = if let Some(group_id) = &page_req.filter.group_id {
+            self.grants_by_group_index.get(group_id)
+        } else if let Some(beneficiary) = &page_req.filter.beneficiary {
+            self.grants_by_beneficiary_index.get(beneficiary)
+        } else {
+            let (has_next, iter) = self.grants.iter().get_page(page_req);
+            let data = iter.map(|(_, it)| it.clone()).collect();
+
+            return Page::new(data, has_next);
+        };
+
+        if let Some(index) = index {
+            let (has_next, iter) = index.iter().get_page(page_req);
+            let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+            Page::new(data, has_next)
+        } else {
+            Page::empty()
+        }
+    }
+}
+
+impl VestingGrantRepository {
+    pub fn get_grants_by_group(&self, group_id: &GroupId) -> BTreeSet<VestingGrantId> {
+        self.grants_by_group_index
+            .get(group_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
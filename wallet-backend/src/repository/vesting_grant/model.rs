@@ -0,0 +1,212 @@
+use crate::repository::vesting_grant::types::{VestingGrantId, VestingSchedule};
+use candid::{CandidType, Deserialize, Principal};
+use ic_cron::types::TaskId;
+use shared::mvc::Model;
+use shared::types::wallet::{GroupId, Shares};
+use shared::validation::ValidationError;
+
+// Group shares, granted to the beneficiary, but unlocked only over time.
+// Grants with voting power are minted upfront and locked until vested,
+// other grants are minted only once vested.
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct VestingGrant {
+    id: Option<VestingGrantId>,
+    group_id: GroupId,
+    beneficiary: Principal,
+    total: Shares,
+    released: Shares,
+    schedule: VestingSchedule,
+    revocable: bool,
+    voting_power: bool,
+    created_at: u64,
+    revoked_at: Option<u64>,
+    release_task: Option<TaskId>,
+}
+
+impl VestingGrant {
+    pub fn new(
+        group_id: GroupId,
+        beneficiary: Principal,
+        total: Shares,
+        schedule: VestingSchedule,
+        revocable: bool,
+        voting_power: bool,
+        created_at: u64,
+    ) -> Result<Self, ValidationError> {
+        if total == Shares::default() {
+            return Err(ValidationError(String::from(
+                "Vesting grant should contain some shares",
+            )));
+        }
+
+        schedule.validate()?;
+
+        Ok(Self {
+            id: None,
+            group_id,
+            beneficiary,
+            total,
+            released: Shares::default(),
+            schedule,
+            revocable,
+            voting_power,
+            created_at,
+            revoked_at: None,
+            release_task: None,
+        })
+    }
+
+    // nothing vests after the grant is revoked
+    pub fn vested_at(&self, timestamp: u64) -> Shares {
+        let timestamp = match self.revoked_at {
+            Some(it) => it.min(timestamp),
+            None => timestamp,
+        };
+
+        self.schedule.vested_at(&self.total, timestamp)
+    }
+
+    pub fn releasable_at(&self, timestamp: u64) -> Shares {
+        self.vested_at(timestamp) - self.released.clone()
+    }
+
+    pub fn unvested_at(&self, timestamp: u64) -> Shares {
+        self.total.clone() - self.vested_at(timestamp)
+    }
+
+    pub fn release(&mut self, qty: Shares) {
+        self.released += qty;
+    }
+
+    pub fn revoke(&mut self, timestamp: u64) {
+        assert!(self.revocable);
+        assert!(self.revoked_at.is_none());
+
+        self.revoked_at = Some(timestamp);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.revoked_at.is_some() || self.released == self.total
+    }
+
+    pub fn set_release_task(&mut self, task_id: Option<TaskId>) {
+        self.release_task = task_id;
+    }
+
+    pub fn get_group_id(&self) -> &GroupId {
+        &self.group_id
+    }
+
+    pub fn get_beneficiary(&self) -> &Principal {
+        &self.beneficiary
+    }
+
+    pub fn get_total(&self) -> &Shares {
+        &self.total
+    }
+
+    pub fn get_released(&self) -> &Shares {
+        &self.released
+    }
+
+    pub fn get_schedule(&self) -> &VestingSchedule {
+        &self.schedule
+    }
+
+    pub fn is_revocable(&self) -> bool {
+        self.revocable
+    }
+
+    pub fn has_voting_power(&self) -> bool {
+        self.voting_power
+    }
+
+    pub fn get_created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    pub fn get_revoked_at(&self) -> Option<u64> {
+        self.revoked_at
+    }
+
+    pub fn get_release_task(&self) -> Option<TaskId> {
+        self.release_task
+    }
+}
+
+impl Model<VestingGrantId> for VestingGrant {
+    fn get_id(&self) -> Option<VestingGrantId> {
+        self.id
+    }
+
+    fn _init_id(&mut self, id: VestingGrantId) {
+        assert!(self.is_transient());
+        self.id = Some(id);
+    }
+
+    fn is_transient(&self) -> bool {
+        self.id.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::vesting_grant::model::VestingGrant;
+    use crate::repository::vesting_grant::types::VestingSchedule;
+    use candid::Principal;
+    use shared::types::wallet::Shares;
+
+    fn grant() -> VestingGrant {
+        VestingGrant::new(
+            1,
+            Principal::from_slice(&[1, 2, 3, 4, 5]),
+            Shares::from(1000),
+            VestingSchedule {
+                start: 100,
+                cliff: 20,
+                duration: 40,
+                period: 10,
+            },
+            true,
+            false,
+            100,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn revocation_stops_vesting() {
+        let mut grant = grant();
+        grant.revoke(135);
+
+        assert_eq!(grant.vested_at(135), Shares::from(750));
+        assert_eq!(grant.vested_at(200), Shares::from(750));
+        assert_eq!(grant.unvested_at(200), Shares::from(250));
+        assert!(grant.is_finished());
+    }
+
+    #[test]
+    fn revocation_before_the_cliff_vests_nothing() {
+        let mut grant = grant();
+        grant.revoke(110);
+
+        assert_eq!(grant.vested_at(200), Shares::default());
+        assert_eq!(grant.unvested_at(200), Shares::from(1000));
+    }
+
+    #[test]
+    fn released_shares_are_not_releasable_again() {
+        let mut grant = grant();
+
+        let qty = grant.releasable_at(125);
+        assert_eq!(qty, Shares::from(500));
+        grant.release(qty);
+
+        assert_eq!(grant.releasable_at(125), Shares::default());
+        assert_eq!(grant.releasable_at(140), Shares::from(500));
+        assert!(!grant.is_finished());
+
+        grant.release(Shares::from(500));
+        assert!(grant.is_finished());
+    }
+}
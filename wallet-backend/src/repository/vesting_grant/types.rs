@@ -0,0 +1,143 @@
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Id;
+use shared::types::wallet::{GroupId, Shares};
+use shared::validation::ValidationError;
+
+pub type VestingGrantId = Id;
+
+#[derive(CandidType, Deserialize)]
+pub struct VestingGrantFilter {
+    pub group_id: Option<GroupId>,
+    pub beneficiary: Option<Principal>,
+}
+
+// Shares unlock linearly, step by step, each period after the cliff.
+// All durations are relative to the start.
+#[derive(Debug, Clone, Copy, CandidType, Deserialize)]
+pub struct VestingSchedule {
+    pub start: u64,
+    pub cliff: u64,
+    pub duration: u64,
+    pub period: u64,
+}
+
+impl VestingSchedule {
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.period == 0 || self.duration == 0 {
+            return Err(ValidationError(String::from(
+                "Vesting period and duration should be positive",
+            )));
+        }
+
+        if self.cliff > self.duration {
+            return Err(ValidationError(String::from(
+                "Vesting cliff should not exceed the duration",
+            )));
+        }
+
+        // so each release task tick happens exactly at an unlock step
+        if self.cliff % self.period != 0 || self.duration % self.period != 0 {
+            return Err(ValidationError(String::from(
+                "Vesting cliff and duration should be multiples of the period",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn get_cliff_end(&self) -> u64 {
+        self.start.saturating_add(self.cliff)
+    }
+
+    pub fn vested_at(&self, total: &Shares, timestamp: u64) -> Shares {
+        if timestamp < self.get_cliff_end() {
+            return Shares::default();
+        }
+
+        let elapsed = timestamp - self.start;
+        if elapsed >= self.duration {
+            return total.clone();
+        }
+
+        let unlocked = elapsed - elapsed % self.period;
+
+        Shares::from(total.0.clone() * unlocked / self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::vesting_grant::types::VestingSchedule;
+    use shared::types::wallet::Shares;
+
+    // 4 periods of 10 with a cliff of 2 periods, starting at 100
+    fn schedule() -> VestingSchedule {
+        VestingSchedule {
+            start: 100,
+            cliff: 20,
+            duration: 40,
+            period: 10,
+        }
+    }
+
+    fn vested(timestamp: u64) -> Shares {
+        schedule().vested_at(&Shares::from(1000), timestamp)
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        assert_eq!(vested(0), Shares::default());
+        assert_eq!(vested(100), Shares::default());
+        assert_eq!(vested(119), Shares::default());
+    }
+
+    #[test]
+    fn cliff_unlocks_all_periods_it_covers() {
+        assert_eq!(vested(120), Shares::from(500));
+        assert_eq!(vested(129), Shares::from(500));
+    }
+
+    #[test]
+    fn shares_unlock_linearly_each_period() {
+        assert_eq!(vested(130), Shares::from(750));
+        assert_eq!(vested(139), Shares::from(750));
+    }
+
+    #[test]
+    fn everything_vests_after_the_duration() {
+        assert_eq!(vested(140), Shares::from(1000));
+        assert_eq!(vested(u64::MAX), Shares::from(1000));
+    }
+
+    #[test]
+    fn indivisible_totals_round_down_until_the_end() {
+        let total = Shares::from(10);
+        let schedule = VestingSchedule {
+            start: 0,
+            cliff: 0,
+            duration: 30,
+            period: 10,
+        };
+
+        assert_eq!(schedule.vested_at(&total, 10), Shares::from(3));
+        assert_eq!(schedule.vested_at(&total, 20), Shares::from(6));
+        assert_eq!(schedule.vested_at(&total, 30), Shares::from(10));
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        let mut it = schedule();
+        it.period = 0;
+        assert!(it.validate().is_err());
+
+        let mut it = schedule();
+        it.cliff = 50;
+        assert!(it.validate().is_err());
+
+        let mut it = schedule();
+        it.cliff = 15;
+        assert!(it.validate().is_err());
+
+        assert!(schedule().validate().is_ok());
+    }
+}
@@ -0,0 +1,124 @@
+use crate::repository::token::types::TokenId;
+use crate::repository::vote_escrow::model::VoteEscrowLock;
+use crate::repository::vote_escrow::types::{VoteEscrowLockFilter, VoteEscrowLockId};
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::{IdGenerator, Model, Repository};
+use shared::pageable::{Page, PageRequest, Pageable};
+use shared::types::wallet::Shares;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod model;
+pub mod types;
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct VoteEscrowLockRepository {
+    locks: HashMap<VoteEscrowLockId, VoteEscrowLock>,
+    id_gen: IdGenerator,
+
+    locks_by_token_index: BTreeMap<TokenId, BTreeSet<VoteEscrowLockId>>,
+    locks_by_owner_index: BTreeMap<Principal, BTreeSet<VoteEscrowLockId>>,
+}
+
+impl Repository<VoteEscrowLock, VoteEscrowLockId, VoteEscrowLockFilter, ()>
+    for VoteEscrowLockRepository
+{
+    fn save(&mut self, mut it: VoteEscrowLock) -> VoteEscrowLockId {
+        if it.is_transient() {
+            it._init_id(self.id_gen.generate());
+        }
+
+        let id = it.get_id().unwrap();
+        self.locks_by_token_index
+            .entry(*it.get_token_id())
+            .or_default()
+            .insert(id);
+        self.locks_by_owner_index
+            .entry(*it.get_owner())
+            .or_default()
+            .insert(id);
+        self.locks.insert(id, it);
+
+        id
+    }
+
+    fn delete(&mut self, id: &VoteEscrowLockId) -> Option<VoteEscrowLock> {
+        let it = self.locks.remove(id)?;
+
+        let index = self
+            .locks_by_token_index
+            .get_mut(it.get_token_id())
+            .unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.locks_by_token_index.remove(it.get_token_id());
+        }
+
+        let index = self.locks_by_owner_index.get_mut(it.get_owner()).unwrap();
+        index.remove(id);
+
+        if index.is_empty() {
+            self.locks_by_owner_index.remove(it.get_owner());
+        }
+
+        Some(it)
+    }
+
+    fn get(&self, id: &VoteEscrowLockId) -> Option<VoteEscrowLock> {
+        self.locks.get(id).cloned()
+    }
+
+    fn list(&self, page_req: &PageRequest<VoteEscrowLockFilter, ()>) -> Page<VoteEscrowLock> {
+        // the token filter takes precedence over the owner filter
+        let index = if let Some(token_id) = &page_req.filter.token_id {
+            self.locks_by_token_index.get(token_id)
+        } else if let Some(owner) = &page_req.filter.owner {
+            self.locks_by_owner_index.get(owner)
+        } else {
+            let (has_next, iter) = self.locks.iter().get_page(page_req);
+            let data = iter.map(|(_, it)| it.clone()).collect();
+
+            return Page::new(data, has_next);
+        };
+
+        if let Some(index) = index {
+            let (has_next, iter) = index.iter().get_page(page_req);
+            let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+            Page::new(data, has_next)
+        } else {
+            Page::empty()
+        }
+    }
+}
+
+impl VoteEscrowLockRepository {
+    pub fn get_locks_by_token(&self, token_id: &TokenId) -> BTreeSet<VoteEscrowLockId> {
+        self.locks_by_token_index
+            .get(token_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // sums boosts of all the locks of the token (or of a single owner), active at the moment
+    pub fn get_boost_at(
+        &self,
+        token_id: &TokenId,
+        owner: Option<&Principal>,
+        timestamp: u64,
+    ) -> Shares {
+        let mut boost = Shares::default();
+
+        if let Some(index) = self.locks_by_token_index.get(token_id) {
+            for id in index {
+                let lock = self.locks.get(id).unwrap();
+
+                if owner.map_or(true, |it| it == lock.get_owner()) && lock.is_active_at(timestamp) {
+                    boost += lock.get_boost().clone();
+                }
+            }
+        }
+
+        boost
+    }
+}
@@ -0,0 +1,106 @@
+use crate::repository::token::types::TokenId;
+use crate::repository::vote_escrow::types::VoteEscrowLockId;
+use candid::{CandidType, Deserialize, Principal};
+use ic_cron::types::TaskId;
+use shared::mvc::Model;
+use shared::types::wallet::Shares;
+
+// Group shares, locked by their owner in exchange for a voting power boost.
+// Expired locks are kept, so the boost can be resolved at any moment in the past.
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct VoteEscrowLock {
+    id: Option<VoteEscrowLockId>,
+    token_id: TokenId,
+    owner: Principal,
+    qty: Shares,
+    boost: Shares,
+    locked_at: u64,
+    unlocks_at: u64,
+    expired: bool,
+    expiration_task: Option<TaskId>,
+}
+
+impl VoteEscrowLock {
+    pub fn new(
+        token_id: TokenId,
+        owner: Principal,
+        qty: Shares,
+        boost: Shares,
+        locked_at: u64,
+        unlocks_at: u64,
+    ) -> Self {
+        Self {
+            id: None,
+            token_id,
+            owner,
+            qty,
+            boost,
+            locked_at,
+            unlocks_at,
+            expired: false,
+            expiration_task: None,
+        }
+    }
+
+    pub fn is_active_at(&self, timestamp: u64) -> bool {
+        self.locked_at <= timestamp && timestamp < self.unlocks_at
+    }
+
+    pub fn expire(&mut self) {
+        assert!(!self.expired);
+
+        self.expired = true;
+        self.expiration_task = None;
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    pub fn set_expiration_task(&mut self, task_id: Option<TaskId>) {
+        self.expiration_task = task_id;
+    }
+
+    pub fn get_token_id(&self) -> &TokenId {
+        &self.token_id
+    }
+
+    pub fn get_owner(&self) -> &Principal {
+        &self.owner
+    }
+
+    pub fn get_qty(&self) -> &Shares {
+        &self.qty
+    }
+
+    pub fn get_boost(&self) -> &Shares {
+        &self.boost
+    }
+
+    pub fn get_locked_at(&self) -> u64 {
+        self.locked_at
+    }
+
+    pub fn get_unlocks_at(&self) -> u64 {
+        self.unlocks_at
+    }
+
+    pub fn get_expiration_task(&self) -> Option<TaskId> {
+        self.expiration_task
+    }
+}
+
+impl Model<VoteEscrowLockId> for VoteEscrowLock {
+    fn get_id(&self) -> Option<VoteEscrowLockId> {
+        self.id
+    }
+
+    fn _init_id(&mut self, id: VoteEscrowLockId) {
+        assert!(self.is_transient());
+        self.id = Some(id);
+    }
+
+    fn is_transient(&self) -> bool {
+        self.id.is_none()
+    }
+}
@@ -0,0 +1,11 @@
+use crate::repository::token::types::TokenId;
+use candid::{CandidType, Deserialize, Principal};
+use shared::mvc::Id;
+
+pub type VoteEscrowLockId = Id;
+
+#[derive(CandidType, Deserialize)]
+pub struct VoteEscrowLockFilter {
+    pub token_id: Option<TokenId>,
+    pub owner: Option<Principal>,
+}
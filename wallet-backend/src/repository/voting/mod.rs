@@ -1,6 +1,6 @@
 use crate::repository::voting::model::Voting;
-use crate::repository::voting::types::VotingSort;
-use candid::{CandidType, Deserialize};
+use crate::repository::voting::types::{VotingFilter, VotingSort, VotingStatusFilter};
+use candid::{CandidType, Deserialize, Principal};
 use shared::mvc::{IdGenerator, Model, Repository};
 use shared::pageable::{Page, PageRequest, Pageable};
 use shared::types::wallet::{VotingConfigId, VotingId};
@@ -15,18 +15,33 @@ pub struct VotingRepository {
     id_gen: IdGenerator,
 
     votings_by_voting_config_index: BTreeMap<VotingConfigId, BTreeSet<VotingId>>,
+    // keyed by program initiators, since votings are only created through the union itself
+    votings_by_proposer_index: BTreeMap<Principal, BTreeSet<VotingId>>,
+    votings_by_status_index: BTreeMap<VotingStatusFilter, BTreeSet<VotingId>>,
+
+    // creation timestamps are kept after votings are deleted, so deletions don't reset rate limits
+    voting_creations_by_proposer: BTreeMap<(Principal, VotingConfigId), Vec<u64>>,
 
     votings_sorted_by_created_at_index: Vec<(u64, VotingId)>,
     votings_sorted_by_updated_at_index: Vec<(u64, VotingId)>,
 }
 
-impl Repository<Voting, VotingId, (), VotingSort> for VotingRepository {
+impl Repository<Voting, VotingId, VotingFilter, VotingSort> for VotingRepository {
     fn save(&mut self, mut it: Voting) -> VotingId {
         if it.is_transient() {
             it._init_id(self.id_gen.generate());
             self.add_to_index(&it);
         } else {
             self.remove_from_updated_at_index(&it);
+
+            let prev = self.votings.get(&it.get_id().unwrap()).unwrap();
+            let prev_status = VotingStatusFilter::from_status(prev.get_status());
+            let status = VotingStatusFilter::from_status(it.get_status());
+
+            if prev_status != status {
+                self.remove_from_status_index(prev_status, it.get_id().unwrap());
+                self.add_to_status_index(status, it.get_id().unwrap());
+            }
         }
 
         self.add_to_updated_at_index(&it);
@@ -49,7 +64,52 @@ impl Repository<Voting, VotingId, (), VotingSort> for VotingRepository {
         self.votings.get(id).cloned()
     }
 
-    fn list(&self, page_req: &PageRequest<(), VotingSort>) -> Page<Voting> {
+    fn list(&self, page_req: &PageRequest<VotingFilter, VotingSort>) -> Page<Voting> {
+        if page_req.filter.is_empty() {
+            return self.list_sorted(page_req);
+        }
+
+        let name_opt = page_req.filter.name.as_ref().map(|it| it.to_lowercase());
+
+        // only votings of the requested page are cloned
+        let mut data: Vec<_> = self
+            .find_ids_by_filter(&page_req.filter)
+            .iter()
+            .map(|id| self.votings.get(id).unwrap())
+            .filter(|it| match &name_opt {
+                Some(name) => it.get_name().to_lowercase().contains(name),
+                None => true,
+            })
+            .collect();
+
+        // same ordering as the unfiltered listing provides
+        match page_req.sort {
+            VotingSort::None => {}
+            VotingSort::CreatedAt(descending) => {
+                if !descending {
+                    data.sort_by(|a, b| b.get_created_at().cmp(&a.get_created_at()));
+                } else {
+                    data.sort_by_key(|it| it.get_created_at());
+                }
+            }
+            VotingSort::UpdatedAt(descending) => {
+                if !descending {
+                    data.sort_by(|a, b| b.get_updated_at().cmp(&a.get_updated_at()));
+                } else {
+                    data.sort_by_key(|it| it.get_updated_at());
+                }
+            }
+        }
+
+        let (has_next, iter) = data.iter().get_page(page_req);
+        let data = iter.map(|it| (*it).clone()).collect();
+
+        Page::new(data, has_next)
+    }
+}
+
+impl VotingRepository {
+    fn list_sorted(&self, page_req: &PageRequest<VotingFilter, VotingSort>) -> Page<Voting> {
         match page_req.sort {
             VotingSort::None => {
                 let (has_next, iter) = self.votings.iter().get_page(page_req);
@@ -103,9 +163,73 @@ impl Repository<Voting, VotingId, (), VotingSort> for VotingRepository {
             }
         }
     }
-}
 
-impl VotingRepository {
+    // intersects all the indexes the filter points to; the name filter is applied by the caller
+    fn find_ids_by_filter(&self, filter: &VotingFilter) -> BTreeSet<VotingId> {
+        let mut indexes = Vec::new();
+
+        if let Some(status) = &filter.status {
+            indexes.push(
+                self.votings_by_status_index
+                    .get(status)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+
+        if let Some(voting_config_id) = &filter.voting_config {
+            indexes.push(
+                self.votings_by_voting_config_index
+                    .get(voting_config_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+
+        if let Some(proposer) = &filter.proposer {
+            indexes.push(
+                self.votings_by_proposer_index
+                    .get(proposer)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
+
+        if let Some(range) = &filter.created_at {
+            let from = self
+                .votings_sorted_by_created_at_index
+                .partition_point(|(created_at, _)| *created_at < range.from);
+            let to = self
+                .votings_sorted_by_created_at_index
+                .partition_point(|(created_at, _)| *created_at <= range.to);
+
+            let index = if from < to {
+                self.votings_sorted_by_created_at_index[from..to]
+                    .iter()
+                    .map(|(_, id)| *id)
+                    .collect()
+            } else {
+                BTreeSet::new()
+            };
+
+            indexes.push(index);
+        }
+
+        indexes.sort_by_key(|it| it.len());
+
+        let mut iter = indexes.into_iter();
+        let mut result = match iter.next() {
+            Some(it) => it,
+            None => return self.votings.keys().cloned().collect(),
+        };
+
+        for index in iter {
+            result = result.intersection(&index).cloned().collect();
+        }
+
+        result
+    }
+
     pub fn voting_config_has_related_votings(&self, voting_config_id: &VotingConfigId) -> bool {
         if let Some(index) = self.votings_by_voting_config_index.get(voting_config_id) {
             !index.is_empty()
@@ -114,12 +238,61 @@ impl VotingRepository {
         }
     }
 
+    pub fn get_votings_by_proposer(&self, proposer: &Principal) -> Vec<Voting> {
+        self.votings_by_proposer_index
+            .get(proposer)
+            .map(|index| index.iter().map(|id| self.get(id).unwrap()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn count_votings_created_by_proposer_since(
+        &self,
+        proposer: Principal,
+        voting_config_id: VotingConfigId,
+        from: u64,
+    ) -> u32 {
+        self.voting_creations_by_proposer
+            .get(&(proposer, voting_config_id))
+            .map(|it| (it.len() - it.partition_point(|created_at| *created_at <= from)) as u32)
+            .unwrap_or_default()
+    }
+
+    pub fn list_by_proposer(
+        &self,
+        proposer: &Principal,
+        page_req: &PageRequest<(), ()>,
+    ) -> Page<Voting> {
+        if let Some(index) = self.votings_by_proposer_index.get(proposer) {
+            let (has_next, iter) = index.iter().get_page(page_req);
+            let data = iter.map(|id| self.get(id).unwrap()).collect();
+
+            Page::new(data, has_next)
+        } else {
+            Page::empty()
+        }
+    }
+
     fn add_to_index(&mut self, voting: &Voting) {
         self.votings_by_voting_config_index
             .entry(*voting.get_voting_config_id())
             .or_default()
             .insert(voting.get_id().unwrap());
 
+        self.votings_by_proposer_index
+            .entry(voting.get_proposer())
+            .or_default()
+            .insert(voting.get_id().unwrap());
+
+        self.voting_creations_by_proposer
+            .entry((voting.get_proposer(), *voting.get_voting_config_id()))
+            .or_default()
+            .push(voting.get_created_at());
+
+        self.add_to_status_index(
+            VotingStatusFilter::from_status(voting.get_status()),
+            voting.get_id().unwrap(),
+        );
+
         let idx = match self
             .votings_sorted_by_created_at_index
             .binary_search_by(|(created_at, _)| created_at.cmp(&voting.get_created_at()))
@@ -138,6 +311,22 @@ impl VotingRepository {
             .unwrap()
             .remove(&voting.get_id().unwrap());
 
+        let proposer_index = self
+            .votings_by_proposer_index
+            .get_mut(&voting.get_proposer())
+            .unwrap();
+        proposer_index.remove(&voting.get_id().unwrap());
+
+        if proposer_index.is_empty() {
+            self.votings_by_proposer_index
+                .remove(&voting.get_proposer());
+        }
+
+        self.remove_from_status_index(
+            VotingStatusFilter::from_status(voting.get_status()),
+            voting.get_id().unwrap(),
+        );
+
         let (idx, _) = self
             .votings_sorted_by_created_at_index
             .iter()
@@ -148,6 +337,19 @@ impl VotingRepository {
         self.votings_sorted_by_created_at_index.remove(idx);
     }
 
+    fn add_to_status_index(&mut self, status: VotingStatusFilter, id: VotingId) {
+        self.votings_by_status_index
+            .entry(status)
+            .or_default()
+            .insert(id);
+    }
+
+    fn remove_from_status_index(&mut self, status: VotingStatusFilter, id: VotingId) {
+        if let Some(index) = self.votings_by_status_index.get_mut(&status) {
+            index.remove(&id);
+        }
+    }
+
     fn add_to_updated_at_index(&mut self, voting: &Voting) {
         let idx = match self
             .votings_sorted_by_updated_at_index
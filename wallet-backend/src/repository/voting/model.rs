@@ -1,10 +1,13 @@
 use crate::repository::voting::types::{
-    RoundResult, VotingStatus, VOTING_DESCRIPTION_MAX_LEN, VOTING_DESCRIPTION_MIN_LEN,
+    LockedProposalBond, ProposalBondStatus, RoundResult, VotingStatus, VOTE_RATIONALE_MAX_LEN,
+    VOTE_RATIONALE_MIN_LEN, VOTING_DESCRIPTION_MAX_LEN, VOTING_DESCRIPTION_MIN_LEN,
     VOTING_NAME_MAX_LEN, VOTING_NAME_MIN_LEN,
 };
+use crate::repository::voting_config::types::ProposalBond;
 use candid::{CandidType, Deserialize, Principal};
 use ic_cron::types::TaskId;
 use shared::mvc::Model;
+use shared::types::history_ledger::SharesInfo;
 use shared::types::wallet::{ChoiceId, GroupId, Shares, VotingConfigId, VotingId};
 use shared::validation::{validate_and_trim_str, ValidationError};
 use std::collections::{BTreeMap, BTreeSet};
@@ -26,6 +29,8 @@ pub struct Voting {
     winners_need: u32,
 
     total_voting_power_by_group: BTreeMap<GroupId, Shares>,
+    // roots of group tree thresholds to all groups of the tree, as they were on creation
+    group_trees: BTreeMap<GroupId, BTreeSet<GroupId>>,
 
     winners: Vec<RoundResult>,
     losers: Vec<RoundResult>,
@@ -34,6 +39,13 @@ pub struct Voting {
 
     rejection_choice: Option<ChoiceId>,
     approval_choice: Option<ChoiceId>,
+    abstain_choice: Option<ChoiceId>,
+
+    proposal_bond: Option<LockedProposalBond>,
+
+    vote_rationales: BTreeMap<GroupId, BTreeMap<Principal, String>>,
+
+    shares_info_cache: BTreeMap<GroupId, BTreeMap<Principal, SharesInfo>>,
 }
 
 impl Voting {
@@ -66,6 +78,7 @@ impl Voting {
             winners_need,
 
             total_voting_power_by_group: BTreeMap::new(),
+            group_trees: BTreeMap::new(),
 
             winners: Vec::new(),
             losers: Vec::new(),
@@ -73,16 +86,33 @@ impl Voting {
 
             rejection_choice: None,
             approval_choice: None,
+            abstain_choice: None,
+
+            proposal_bond: None,
+
+            vote_rationales: BTreeMap::new(),
+
+            shares_info_cache: BTreeMap::new(),
         };
 
         Ok(voting)
     }
 
-    pub fn init_rejection_and_approval_choices(&mut self, rejection: ChoiceId, approval: ChoiceId) {
-        assert!(self.rejection_choice.is_none() && self.approval_choice.is_none());
+    pub fn init_rejection_and_approval_choices(
+        &mut self,
+        rejection: ChoiceId,
+        approval: ChoiceId,
+        abstain_opt: Option<ChoiceId>,
+    ) {
+        assert!(
+            self.rejection_choice.is_none()
+                && self.approval_choice.is_none()
+                && self.abstain_choice.is_none()
+        );
 
         self.rejection_choice = Some(rejection);
         self.approval_choice = Some(approval);
+        self.abstain_choice = abstain_opt;
     }
 
     pub fn update(
@@ -135,6 +165,20 @@ impl Voting {
         self.updated_at = timestamp;
     }
 
+    // the total voting power of every group of a tree is snapshotted at once, since
+    // otherwise it is only known for groups somebody has voted with
+    pub fn init_group_trees(
+        &mut self,
+        group_trees: BTreeMap<GroupId, BTreeSet<GroupId>>,
+        total_voting_power_by_group: BTreeMap<GroupId, Shares>,
+    ) {
+        assert!(self.group_trees.is_empty());
+
+        self.group_trees = group_trees;
+        self.total_voting_power_by_group
+            .extend(total_voting_power_by_group);
+    }
+
     pub fn reject(&mut self, timestamp: u64) {
         assert!(matches!(self.status, VotingStatus::Round(_)));
 
@@ -176,6 +220,68 @@ impl Voting {
         self.updated_at = timestamp;
     }
 
+    pub fn init_proposal_bond(&mut self, bond: ProposalBond) {
+        assert!(self.proposal_bond.is_none());
+
+        self.proposal_bond = Some(LockedProposalBond {
+            bond,
+            status: ProposalBondStatus::Locked,
+        });
+    }
+
+    pub fn set_proposal_bond_status(&mut self, status: ProposalBondStatus, timestamp: u64) {
+        let locked_bond = self.proposal_bond.as_mut().unwrap();
+        assert!(locked_bond.status == ProposalBondStatus::Locked);
+
+        locked_bond.status = status;
+        self.updated_at = timestamp;
+    }
+
+    // the rationale of a previous vote on behalf of the same group is removed, if none
+    pub fn set_vote_rationale(
+        &mut self,
+        group_id: GroupId,
+        voter: Principal,
+        rationale_opt: Option<String>,
+        timestamp: u64,
+    ) -> Result<(), ValidationError> {
+        match rationale_opt {
+            Some(rationale) => {
+                let rationale = validate_and_trim_str(
+                    rationale,
+                    VOTE_RATIONALE_MIN_LEN,
+                    VOTE_RATIONALE_MAX_LEN,
+                    "Vote rationale",
+                )?;
+
+                self.vote_rationales
+                    .entry(group_id)
+                    .or_default()
+                    .insert(voter, rationale);
+            }
+            None => {
+                if let Some(rationales) = self.vote_rationales.get_mut(&group_id) {
+                    rationales.remove(&voter);
+
+                    if rationales.is_empty() {
+                        self.vote_rationales.remove(&group_id);
+                    }
+                }
+            }
+        }
+
+        self.updated_at = timestamp;
+
+        Ok(())
+    }
+
+    pub fn cache_shares_info(&mut self, shares_info: SharesInfo) {
+        self.shares_info_cache
+            .entry(shares_info.group_id)
+            .or_default()
+            .insert(shares_info.principal_id, shares_info);
+    }
+
     pub fn set_cron_task(&mut self, task_id: TaskId, timestamp: u64) {
         self.task_id = Some(task_id);
         self.updated_at = timestamp;
@@ -201,6 +307,10 @@ impl Voting {
         self.updated_at = timestamp;
     }
 
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
     pub fn get_voting_config_id(&self) -> &VotingConfigId {
         &self.voting_config_id
     }
@@ -233,10 +343,34 @@ impl Voting {
         self.rejection_choice.unwrap()
     }
 
+    pub fn get_abstain_choice(&self) -> Option<ChoiceId> {
+        self.abstain_choice
+    }
+
+    pub fn get_proposal_bond(&self) -> &Option<LockedProposalBond> {
+        &self.proposal_bond
+    }
+
+    pub fn get_vote_rationales(&self) -> &BTreeMap<GroupId, BTreeMap<Principal, String>> {
+        &self.vote_rationales
+    }
+
+    pub fn get_cached_shares_info(
+        &self,
+        group_id: &GroupId,
+        of: &Principal,
+    ) -> Option<&SharesInfo> {
+        self.shares_info_cache.get(group_id)?.get(of)
+    }
+
     pub fn get_total_voting_power_by_group(&self) -> &BTreeMap<GroupId, Shares> {
         &self.total_voting_power_by_group
     }
 
+    pub fn get_group_trees(&self) -> &BTreeMap<GroupId, BTreeSet<GroupId>> {
+        &self.group_trees
+    }
+
     pub fn get_winners_need(&self) -> u32 {
         self.winners_need
     }
@@ -1,11 +1,14 @@
-use candid::{CandidType, Deserialize};
-use shared::types::wallet::ChoiceId;
+use crate::repository::voting_config::types::ProposalBond;
+use candid::{CandidType, Deserialize, Principal};
+use shared::types::wallet::{ChoiceId, VotingConfigId};
 use std::collections::BTreeSet;
 
 pub const VOTING_NAME_MIN_LEN: usize = 1;
 pub const VOTING_NAME_MAX_LEN: usize = 200;
 pub const VOTING_DESCRIPTION_MIN_LEN: usize = 0;
 pub const VOTING_DESCRIPTION_MAX_LEN: usize = 2000;
+pub const VOTE_RATIONALE_MIN_LEN: usize = 1;
+pub const VOTE_RATIONALE_MAX_LEN: usize = 2000;
 
 pub type RoundId = u16;
 
@@ -18,6 +21,20 @@ pub enum VotingStatus {
     Fail(String),
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, CandidType, Deserialize)]
+pub enum ProposalBondStatus {
+    Locked,
+    Refunded,
+    Burned,
+    SentToTreasury,
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct LockedProposalBond {
+    pub bond: ProposalBond,
+    pub status: ProposalBondStatus,
+}
+
 #[derive(Clone, CandidType, Deserialize)]
 pub struct RoundResult {
     round: RoundId,
@@ -56,4 +73,47 @@ pub enum VotingSort {
     None,
     CreatedAt(bool),
     UpdatedAt(bool),
-}
\ No newline at end of file
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, CandidType, Deserialize)]
+pub enum VotingStatusFilter {
+    Active,
+    PreRound,
+    Finished,
+    Rejected,
+}
+
+impl VotingStatusFilter {
+    pub fn from_status(status: &VotingStatus) -> Self {
+        match status {
+            VotingStatus::Round(_) => VotingStatusFilter::Active,
+            VotingStatus::PreRound(_) => VotingStatusFilter::PreRound,
+            VotingStatus::Success | VotingStatus::Fail(_) => VotingStatusFilter::Finished,
+            VotingStatus::Rejected => VotingStatusFilter::Rejected,
+        }
+    }
+}
+
+#[derive(Clone, Copy, CandidType, Deserialize)]
+pub struct CreatedAtRange {
+    pub from: u64,
+    pub to: u64,
+}
+
+#[derive(Default, CandidType, Deserialize)]
+pub struct VotingFilter {
+    pub status: Option<VotingStatusFilter>,
+    pub voting_config: Option<VotingConfigId>,
+    pub proposer: Option<Principal>,
+    pub created_at: Option<CreatedAtRange>,
+    pub name: Option<String>,
+}
+
+impl VotingFilter {
+    pub fn is_empty(&self) -> bool {
+        self.status.is_none()
+            && self.voting_config.is_none()
+            && self.proposer.is_none()
+            && self.created_at.is_none()
+            && self.name.is_none()
+    }
+}
@@ -82,6 +82,16 @@ impl Repository<VotingConfig, VotingConfigId, VotingConfigFilter, ()> for Voting
 }
 
 impl VotingConfigRepository {
+    pub fn get_voting_configs_by_permission(
+        &self,
+        permission_id: &PermissionId,
+    ) -> BTreeSet<VotingConfigId> {
+        self.voting_configs_by_permission_index
+            .get(permission_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     pub fn group_has_related_voting_configs(&self, group_id: &GroupId) -> bool {
         if let Some(index) = self.voting_configs_by_group_index.get(group_id) {
             !index.is_empty()
@@ -124,6 +134,10 @@ impl VotingConfigRepository {
         for gop in voting_config.get_next_round_threshold().list_groups() {
             self.add_to_group_index(id, gop);
         }
+
+        if let Some(pb) = voting_config.get_proposal_bond() {
+            self.add_to_group_index(id, pb.group_id);
+        }
     }
 
     fn remove_from_indexes(&mut self, voting_config: &VotingConfig) {
@@ -152,6 +166,10 @@ impl VotingConfigRepository {
         for gop in voting_config.get_next_round_threshold().list_groups() {
             self.remove_from_group_index(&id, &gop);
         }
+
+        if let Some(pb) = voting_config.get_proposal_bond() {
+            self.remove_from_group_index(&id, &pb.group_id);
+        }
     }
 
     fn add_to_permissions_index(
@@ -1,7 +1,8 @@
 use crate::repository::permission::types::PermissionId;
 use crate::repository::voting_config::types::{
-    LenInterval, RoundSettings, ThresholdValue, VOTING_CONFIG_DESCRIPTION_MAX_LEN,
-    VOTING_CONFIG_DESCRIPTION_MIN_LEN, VOTING_CONFIG_NAME_MAX_LEN, VOTING_CONFIG_NAME_MIN_LEN,
+    LenInterval, ProposalBond, ProposerLimits, RoundSettings, ThresholdValue,
+    VOTING_CONFIG_DESCRIPTION_MAX_LEN, VOTING_CONFIG_DESCRIPTION_MIN_LEN,
+    VOTING_CONFIG_NAME_MAX_LEN, VOTING_CONFIG_NAME_MIN_LEN,
 };
 use candid::{CandidType, Deserialize};
 use shared::mvc::Model;
@@ -26,6 +27,10 @@ pub struct VotingConfig {
     quorum: ThresholdValue,
     win: ThresholdValue,
     next_round: ThresholdValue,
+
+    allow_abstain: bool,
+    proposal_bond: Option<ProposalBond>,
+    proposer_limits: ProposerLimits,
 }
 
 impl VotingConfig {
@@ -41,6 +46,9 @@ impl VotingConfig {
         rejection: ThresholdValue,
         win: ThresholdValue,
         next_round: ThresholdValue,
+        allow_abstain: bool,
+        proposal_bond: Option<ProposalBond>,
+        proposer_limits: ProposerLimits,
     ) -> Result<VotingConfig, ValidationError> {
         if let Some(cc) = &choices_count {
             if !cc.is_valid() {
@@ -58,6 +66,16 @@ impl VotingConfig {
             }
         }
 
+        if let Some(pb) = &proposal_bond {
+            if !pb.is_valid() {
+                return Err(ValidationError("Invalid proposal bond".to_string()));
+            }
+        }
+
+        if !proposer_limits.is_valid() {
+            return Err(ValidationError("Invalid proposer limits".to_string()));
+        }
+
         let voting_config = VotingConfig {
             id: None,
             name: Self::process_name(name)?,
@@ -71,6 +89,9 @@ impl VotingConfig {
             rejection,
             win,
             next_round,
+            allow_abstain,
+            proposal_bond,
+            proposer_limits,
         };
 
         Ok(voting_config)
@@ -89,6 +110,9 @@ impl VotingConfig {
         rejection_opt: Option<ThresholdValue>,
         win_opt: Option<ThresholdValue>,
         next_round_opt: Option<ThresholdValue>,
+        allow_abstain_opt: Option<bool>,
+        proposal_bond_opt: Option<Option<ProposalBond>>,
+        proposer_limits_opt: Option<ProposerLimits>,
     ) -> Result<(), ValidationError> {
         if let Some(name) = name_opt {
             self.name = Self::process_name(name)?;
@@ -150,6 +174,28 @@ impl VotingConfig {
             self.next_round = next_round;
         }
 
+        if let Some(allow_abstain) = allow_abstain_opt {
+            self.allow_abstain = allow_abstain;
+        }
+
+        if let Some(proposal_bond) = proposal_bond_opt {
+            if let Some(pb) = &proposal_bond {
+                if !pb.is_valid() {
+                    return Err(ValidationError("Invalid proposal bond".to_string()));
+                }
+            }
+
+            self.proposal_bond = proposal_bond;
+        }
+
+        if let Some(proposer_limits) = proposer_limits_opt {
+            if !proposer_limits.is_valid() {
+                return Err(ValidationError("Invalid proposer limits".to_string()));
+            }
+
+            self.proposer_limits = proposer_limits;
+        }
+
         Ok(())
     }
 
@@ -189,6 +235,18 @@ impl VotingConfig {
         &self.next_round
     }
 
+    pub fn is_abstain_allowed(&self) -> bool {
+        self.allow_abstain
+    }
+
+    pub fn get_proposal_bond(&self) -> &Option<ProposalBond> {
+        &self.proposal_bond
+    }
+
+    pub fn get_proposer_limits(&self) -> &ProposerLimits {
+        &self.proposer_limits
+    }
+
     fn process_name(name: String) -> Result<String, ValidationError> {
         validate_and_trim_str(
             name,
@@ -108,10 +108,32 @@ impl ThresholdValue {
         result
     }
 
+    // unlike list_groups(), also lists groups of group tree targets, as they were snapshotted
+    pub fn list_voter_groups(
+        &self,
+        group_trees: &BTreeMap<GroupId, BTreeSet<GroupId>>,
+    ) -> BTreeSet<GroupId> {
+        let mut result = BTreeSet::new();
+
+        self._list_groups(&mut result);
+
+        for root in self.list_group_trees() {
+            if let Some(tree) = group_trees.get(&root) {
+                result.extend(tree.iter().copied());
+            }
+        }
+
+        result
+    }
+
+    // group trees are taken from the snapshot made on voting creation (the root and all of its
+    // descendants), so every group of a tree should also have its total snapshotted - a tree
+    // with a missing total can't be reached
     pub fn is_reached(
         &self,
         total: &BTreeMap<GroupId, Shares>,
         voted: &BTreeMap<GroupId, Shares>,
+        group_trees: &BTreeMap<GroupId, BTreeSet<GroupId>>,
     ) -> bool {
         let (voted_shares, total_shares) = match self.get_target() {
             Target::Group(group_id) => {
@@ -120,12 +142,33 @@ impl ThresholdValue {
 
                 (voted_shares, total_shares)
             }
+            Target::GroupTree(root) => {
+                let mut voted_shares = Shares::default();
+                let mut total_shares = Shares::default();
+
+                let tree = match group_trees.get(root) {
+                    Some(it) => it,
+                    None => return false,
+                };
+
+                for group_id in tree {
+                    let group_total = match total.get(group_id) {
+                        Some(it) => it.clone(),
+                        None => return false,
+                    };
+
+                    voted_shares += voted.get(group_id).cloned().unwrap_or_default();
+                    total_shares += group_total;
+                }
+
+                (voted_shares, total_shares)
+            }
             Target::Thresholds(thresholds) => {
                 let mut voted_shares_usize = 0usize;
                 let total_shares = Shares::from(thresholds.len());
 
                 for th in thresholds {
-                    if th.is_reached(total, voted) {
+                    if th.is_reached(total, voted, group_trees) {
                         voted_shares_usize += 1;
                     }
                 }
@@ -149,9 +192,17 @@ impl ThresholdValue {
         }
     }
 
+    pub fn list_group_trees(&self) -> BTreeSet<GroupId> {
+        match self.get_target() {
+            Target::Group(_) => BTreeSet::new(),
+            Target::GroupTree(root) => vec![*root].into_iter().collect(),
+            Target::Thresholds(t) => t.iter().flat_map(|it| it.list_group_trees()).collect(),
+        }
+    }
+
     fn _list_groups(&self, list: &mut BTreeSet<GroupId>) {
         match self.get_target() {
-            Target::Group(r) => {
+            Target::Group(r) | Target::GroupTree(r) => {
                 list.insert(*r);
             }
             Target::Thresholds(t) => {
@@ -186,6 +237,8 @@ pub struct FractionOf {
 pub enum Target {
     Thresholds(Vec<ThresholdValue>),
     Group(GroupId),
+    // the group and all of its descendants
+    GroupTree(GroupId),
 }
 
 #[derive(Clone, CandidType, Deserialize)]
@@ -209,8 +262,110 @@ impl LenInterval {
     }
 }
 
+#[derive(Clone, Copy, CandidType, Deserialize)]
+pub struct ProposerRateLimit {
+    pub max_votings: u32,
+    pub window: u64,
+}
+
+#[derive(Clone, Copy, Default, CandidType, Deserialize)]
+pub struct ProposerLimits {
+    pub max_active_votings: Option<u32>,
+    pub rate_limit: Option<ProposerRateLimit>,
+}
+
+impl ProposerLimits {
+    pub fn is_valid(&self) -> bool {
+        if let Some(max) = self.max_active_votings {
+            if max == 0 {
+                return false;
+            }
+        }
+
+        if let Some(rl) = &self.rate_limit {
+            if rl.max_votings == 0 || rl.window == 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Clone, Copy, CandidType, Deserialize)]
+pub enum RejectedBondAction {
+    Burn,
+    SendToTreasury,
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct ProposalBond {
+    pub group_id: GroupId,
+    pub quantity: Shares,
+    pub on_rejection: RejectedBondAction,
+}
+
+impl ProposalBond {
+    pub fn is_valid(&self) -> bool {
+        self.quantity > Shares::default()
+    }
+}
+
 #[derive(CandidType, Deserialize)]
 pub struct VotingConfigFilter {
     pub group: Option<GroupId>,
     pub permission: Option<PermissionId>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::repository::voting_config::types::{Fraction, FractionOf, Target, ThresholdValue};
+    use shared::types::wallet::{GroupId, Shares};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn two_thirds_of_tree(root: GroupId) -> ThresholdValue {
+        ThresholdValue::FractionOf(FractionOf {
+            fraction: Fraction::from(0.66),
+            target: Target::GroupTree(root),
+        })
+    }
+
+    fn group_trees() -> BTreeMap<GroupId, BTreeSet<GroupId>> {
+        vec![(1, vec![1, 2, 3].into_iter().collect())]
+            .into_iter()
+            .collect()
+    }
+
+    fn shares(it: &[(GroupId, u64)]) -> BTreeMap<GroupId, Shares> {
+        it.iter().map(|(g, s)| (*g, Shares::from(*s))).collect()
+    }
+
+    #[test]
+    fn group_tree_counts_groups_nobody_voted_with() {
+        let th = two_thirds_of_tree(1);
+        let total = shares(&[(1, 10), (2, 10), (3, 10)]);
+
+        assert!(!th.is_reached(&total, &shares(&[(2, 10)]), &group_trees()));
+        assert!(th.is_reached(&total, &shares(&[(2, 10), (3, 10)]), &group_trees()));
+    }
+
+    #[test]
+    fn group_tree_is_not_reached_without_a_snapshot() {
+        let th = two_thirds_of_tree(1);
+        let voted = shares(&[(1, 10), (2, 10), (3, 10)]);
+
+        assert!(!th.is_reached(&shares(&[(1, 10), (2, 10)]), &voted, &group_trees()));
+        assert!(!th.is_reached(&voted, &voted, &BTreeMap::new()));
+    }
+
+    #[test]
+    fn voter_groups_include_snapshotted_trees() {
+        let th = two_thirds_of_tree(1);
+
+        let expected: BTreeSet<GroupId> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(th.list_voter_groups(&group_trees()), expected);
+
+        let expected: BTreeSet<GroupId> = vec![1].into_iter().collect();
+        assert_eq!(th.list_voter_groups(&BTreeMap::new()), expected);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::repository::permission::model::Permission;
+use crate::service::access_config::types::{
+    AccessConfigError, AccessConfigService, CallCheck, ExecutionCheck, ExecutionCheckFailure,
+};
+use crate::service::permission::interface::get_wallet_candid_interface;
+use crate::service::voting::types::VotingService;
+use crate::settings::Settings;
+use candid::Principal;
+use shared::mvc::{HasRepository, Repository};
+use shared::remote_call::Program;
+use shared::types::wallet::AccessConfigId;
+
+impl AccessConfigService {
+    // evaluates the program the same way 'execute' does, but reports every step
+    pub async fn check_execution(
+        id: &AccessConfigId,
+        program: &Program,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<ExecutionCheck, AccessConfigError> {
+        let ac = AccessConfigService::get_access_config(id)?;
+
+        let permissions: Vec<_> = ac
+            .get_permissions()
+            .iter()
+            // unwrapping, because it should exist if it is listed
+            .map(|permission_id| Permission::repo().get(permission_id).unwrap())
+            .collect();
+
+        let calls = match program {
+            Program::Empty => Vec::new(),
+            Program::RemoteCallSequence(seq) => seq
+                .iter()
+                .map(|call| CallCheck {
+                    endpoint: call.endpoint.clone(),
+                    verdicts: ac
+                        .get_permissions()
+                        .iter()
+                        .zip(&permissions)
+                        .map(|(id, permission)| (*id, permission.match_call(call)))
+                        .collect(),
+                })
+                .collect(),
+        };
+
+        let allowing_permission = ac
+            .get_permissions()
+            .iter()
+            .zip(&permissions)
+            .find(|(_, permission)| permission.is_program_allowed(program))
+            .map(|(id, _)| *id);
+
+        let satisfied_allowee = AccessConfigService::find_satisfied_allowee(&ac, caller).cloned();
+
+        let remaining_quota = AccessConfigService::get_remaining_quota(&ac, caller, timestamp);
+
+        let failure = if let Err(e) = program.validate() {
+            Some(ExecutionCheckFailure::InvalidProgram(e))
+        } else if !ac.is_active_at(timestamp) {
+            Some(ExecutionCheckFailure::AccessConfigInactive)
+        } else if ac.get_multisig().is_some() {
+            Some(ExecutionCheckFailure::MultisigApprovalRequired)
+        } else if allowing_permission.is_none() {
+            Some(ExecutionCheckFailure::ProgramNotAllowedByAccessConfig)
+        } else if satisfied_allowee.is_none() {
+            Some(ExecutionCheckFailure::CallerNotAllowed)
+        } else if !ac.get_cycles_limits().is_program_within(program) {
+            Some(ExecutionCheckFailure::CyclesLimitExceeded)
+        } else if !remaining_quota.allows_execution(timestamp) {
+            Some(ExecutionCheckFailure::ExecutionQuotaExceeded(
+                remaining_quota,
+            ))
+        } else if !Settings::get().is_within_cycles_budget(program.get_total_cycles(), timestamp) {
+            Some(ExecutionCheckFailure::CyclesBudgetExceeded)
+        } else if let Err(e) = VotingService::assert_program_proposer_valid(program, caller) {
+            Some(ExecutionCheckFailure::InvalidProposer(e))
+        } else {
+            None
+        };
+
+        // fetching interfaces costs cycles, so only programs passing every other rule are typechecked
+        let failure = match failure {
+            None => program
+                .typecheck(&get_wallet_candid_interface())
+                .await
+                .err()
+                .map(ExecutionCheckFailure::TypecheckFailed),
+            f => f,
+        };
+
+        Ok(ExecutionCheck {
+            calls,
+            allowing_permission,
+            satisfied_allowee,
+            failure,
+        })
+    }
+}
@@ -1,7 +1,12 @@
 use crate::repository::access_config::model::AccessConfig;
-use crate::repository::access_config::types::{AccessConfigFilter, AlloweeConstraint};
-use crate::repository::permission::types::PermissionId;
+use crate::repository::access_config::types::{
+    AccessConfigFilter, AccessConfigQuota, AccessConfigValidity, AlloweeConstraint,
+    MultisigSettings,
+};
+use crate::repository::access_config_usage::model::AccessConfigUsage;
+use crate::repository::permission::types::{CyclesLimits, PermissionId};
 use crate::service::access_config::types::{AccessConfigError, AccessConfigService};
+use crate::{cron_dequeue, CronService};
 use shared::mvc::{HasRepository, Repository};
 use shared::pageable::{Page, PageRequest};
 use shared::types::wallet::AccessConfigId;
@@ -13,14 +18,37 @@ impl AccessConfigService {
         description: String,
         permissions: BTreeSet<PermissionId>,
         allowees: BTreeSet<AlloweeConstraint>,
+        query_delegation_expiry: Option<u64>,
+        cycles_limits: CyclesLimits,
+        quota: AccessConfigQuota,
+        multisig: Option<MultisigSettings>,
+        validity: AccessConfigValidity,
+        timestamp: u64,
     ) -> Result<AccessConfigId, AccessConfigError> {
         AccessConfigService::assert_permissions_exist(&permissions)?;
         AccessConfigService::assert_allowees_exist(&allowees)?;
 
-        let qc = AccessConfig::new(name, description, permissions, allowees)
-            .map_err(AccessConfigError::ValidationError)?;
+        let qc = AccessConfig::new(
+            name,
+            description,
+            permissions,
+            allowees,
+            query_delegation_expiry,
+            cycles_limits,
+            quota,
+            multisig,
+            validity,
+        )
+        .map_err(AccessConfigError::ValidationError)?;
 
-        Ok(AccessConfig::repo().save(qc))
+        let id = AccessConfig::repo().save(qc);
+
+        let mut qc = AccessConfig::repo().get(&id).unwrap();
+        if CronService::schedule_access_config_expiration(&mut qc, timestamp) {
+            AccessConfig::repo().save(qc);
+        }
+
+        Ok(id)
     }
 
     pub fn update_access_config(
@@ -29,6 +57,12 @@ impl AccessConfigService {
         new_description: Option<String>,
         new_permissions: Option<BTreeSet<PermissionId>>,
         new_allowees: Option<BTreeSet<AlloweeConstraint>>,
+        new_query_delegation_expiry: Option<Option<u64>>,
+        new_cycles_limits: Option<CyclesLimits>,
+        new_quota: Option<AccessConfigQuota>,
+        new_multisig: Option<Option<MultisigSettings>>,
+        new_validity: Option<AccessConfigValidity>,
+        timestamp: u64,
     ) -> Result<(), AccessConfigError> {
         AccessConfigService::assert_not_default(id)?;
 
@@ -42,8 +76,28 @@ impl AccessConfigService {
 
         let mut ac = AccessConfigService::get_access_config(id)?;
 
-        ac.update(new_name, new_description, new_permissions, new_allowees)
-            .map_err(AccessConfigError::ValidationError)?;
+        ac.update(
+            new_name,
+            new_description,
+            new_permissions,
+            new_allowees,
+            new_query_delegation_expiry,
+            new_cycles_limits,
+            new_quota,
+            new_multisig,
+            new_validity.clone(),
+        )
+        .map_err(AccessConfigError::ValidationError)?;
+
+        if new_validity.is_some() {
+            if let Some(task_id) = ac.get_expiration_task() {
+                cron_dequeue(task_id);
+                ac.set_expiration_task(None);
+            }
+
+            CronService::schedule_access_config_expiration(&mut ac, timestamp);
+        }
+
         AccessConfig::repo().save(ac);
 
         Ok(())
@@ -52,9 +106,18 @@ impl AccessConfigService {
     pub fn delete_access_config(id: &AccessConfigId) -> Result<AccessConfig, AccessConfigError> {
         AccessConfigService::assert_not_default(id)?;
 
-        AccessConfig::repo()
+        let ac = AccessConfig::repo()
             .delete(id)
-            .ok_or(AccessConfigError::AccessConfigNotFound(*id))
+            .ok_or(AccessConfigError::AccessConfigNotFound(*id))?;
+
+        if let Some(task_id) = ac.get_expiration_task() {
+            cron_dequeue(task_id);
+        }
+
+        AccessConfigUsage::repo().delete(id);
+        AccessConfigService::delete_multisig_proposals_of(id);
+
+        Ok(ac)
     }
 
     #[inline(always)]
@@ -0,0 +1,156 @@
+use crate::repository::access_config::model::AccessConfig;
+use crate::repository::permission::model::Permission;
+use crate::repository::permission::types::{PermissionId, PermissionTarget};
+use crate::repository::query_delegation::model::QueryDelegation;
+use crate::repository::voting_config::model::VotingConfig;
+use crate::service::access_config::types::{
+    AccessConfigExplanation, AccessConfigService, EndpointAccessExplanation, PermissionExplanation,
+    PrincipalAccessExplanation,
+};
+use candid::Principal;
+use shared::mvc::{HasRepository, Model, Repository};
+use shared::remote_call::RemoteCallEndpoint;
+use std::collections::BTreeSet;
+
+impl AccessConfigService {
+    pub fn explain_endpoint_access(
+        this_union_id: Principal,
+        endpoint: &RemoteCallEndpoint,
+        timestamp: u64,
+    ) -> EndpointAccessExplanation {
+        let permissions: Vec<_> = AccessConfigService::get_permissions_allowing(endpoint)
+            .into_iter()
+            .map(|permission_id| PermissionExplanation {
+                permission_id,
+                access_configs: AccessConfigService::explain_access_configs_of(
+                    &permission_id,
+                    timestamp,
+                ),
+                voting_configs: VotingConfig::repo()
+                    .get_voting_configs_by_permission(&permission_id)
+                    .into_iter()
+                    .collect(),
+            })
+            .collect();
+
+        let voting_proposers = if permissions.iter().any(|it| !it.voting_configs.is_empty()) {
+            let create_voting = RemoteCallEndpoint::new(this_union_id, "create_voting");
+
+            AccessConfigService::get_permissions_allowing(&create_voting)
+                .iter()
+                .flat_map(|it| AccessConfigService::explain_access_configs_of(it, timestamp))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        EndpointAccessExplanation {
+            permissions,
+            voting_proposers,
+        }
+    }
+
+    pub fn explain_principal_access(
+        this_union_id: Principal,
+        principal: Principal,
+        timestamp: u64,
+    ) -> PrincipalAccessExplanation {
+        let can_propose_votings = AccessConfigService::caller_has_access_to_method(
+            this_union_id,
+            "create_voting",
+            principal,
+            timestamp,
+        );
+
+        let mut direct = BTreeSet::new();
+        let mut by_voting = BTreeSet::new();
+
+        for permission in Permission::repo().get_all() {
+            let permission_id = permission.get_id().unwrap();
+            let allow_targets = permission
+                .get_targets()
+                .iter()
+                .filter(|it| !matches!(it, PermissionTarget::Deny(_)));
+
+            let is_allowed_directly = AccessConfig::repo()
+                .get_access_configs_by_permission(&permission_id)
+                .iter()
+                .any(|id| {
+                    // unwrapping, because it should exist if it is listed
+                    let ac = AccessConfig::repo().get(id).unwrap();
+
+                    ac.is_active_at(timestamp)
+                        && AccessConfigService::assert_caller_allowed(&ac, principal).is_ok()
+                });
+
+            let is_allowed_by_voting = can_propose_votings
+                && VotingConfig::repo().permission_has_related_voting_configs(&permission_id);
+
+            if is_allowed_directly {
+                direct.extend(allow_targets.clone().cloned());
+            }
+
+            if is_allowed_by_voting {
+                by_voting.extend(allow_targets.cloned());
+            }
+        }
+
+        let revoked_at = QueryDelegation::repo().get_revoked_at(&principal);
+        let mut delegated = BTreeSet::new();
+
+        for id in QueryDelegation::repo().get_delegations_by_delegate(&principal) {
+            // unwrapping, because it should exist if it is listed
+            let delegation = QueryDelegation::repo().get(&id).unwrap();
+
+            if delegation.is_expired(timestamp)
+                || matches!(revoked_at, Some(it) if it >= delegation.get_issued_at())
+            {
+                continue;
+            }
+
+            delegated.extend(delegation.get_allowed_query_targets().iter().cloned());
+        }
+
+        PrincipalAccessExplanation {
+            direct,
+            delegated,
+            by_voting,
+        }
+    }
+
+    // respects deny targets, unlike the index lookup alone
+    fn get_permissions_allowing(endpoint: &RemoteCallEndpoint) -> Vec<PermissionId> {
+        Permission::repo()
+            .get_permissions_by_endpoint(endpoint)
+            .into_iter()
+            .filter(|id| {
+                // unwrapping, because it should exist if it is listed
+                Permission::repo()
+                    .get(id)
+                    .unwrap()
+                    .is_endpoint_allowed(endpoint)
+            })
+            .collect()
+    }
+
+    fn explain_access_configs_of(
+        permission_id: &PermissionId,
+        timestamp: u64,
+    ) -> Vec<AccessConfigExplanation> {
+        AccessConfig::repo()
+            .get_access_configs_by_permission(permission_id)
+            .into_iter()
+            .map(|id| {
+                // unwrapping, because it should exist if it is listed
+                let ac = AccessConfig::repo().get(&id).unwrap();
+
+                AccessConfigExplanation {
+                    id,
+                    allowees: ac.get_allowees().clone(),
+                    is_active: ac.is_active_at(timestamp),
+                    is_multisig: ac.get_multisig().is_some(),
+                }
+            })
+            .collect()
+    }
+}
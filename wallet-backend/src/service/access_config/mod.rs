@@ -1,36 +1,52 @@
 use crate::repository::access_config::model::AccessConfig;
-use crate::repository::access_config::types::{AlloweeConstraint, GroupCondition};
+use crate::repository::access_config::types::{
+    AccessConfigQuota, AccessConfigValidity, AlloweeConstraint, GroupCondition,
+};
 use crate::repository::access_config::AccessConfigRepository;
+use crate::repository::access_config_usage::model::AccessConfigUsage;
 use crate::repository::group::model::Group;
 use crate::repository::permission::model::Permission;
-use crate::repository::permission::types::{PermissionId, PermissionTarget};
+use crate::repository::permission::types::{CyclesLimits, PermissionId, PermissionTarget};
 use crate::repository::profile::model::Profile;
 use crate::service::access_config::types::{
-    AccessConfigError, AccessConfigService, QueryDelegationProof, ALLOW_VOTE_ACCESS_CONFIG_ID,
+    AccessConfigError, AccessConfigService, RemainingQuota, ALLOW_VOTE_ACCESS_CONFIG_ID,
+    DEFAULT_QUERY_DELEGATION_EXPIRY,
 };
 use crate::service::group::types::{GroupService, HAS_PROFILE_GROUP_ID};
+use crate::service::permission::interface::get_wallet_candid_interface;
 use crate::service::permission::types::{
     ALLOW_READ_PERMISSION_ID, ALLOW_SEND_FEEDBACK_PERMISSION_ID, ALLOW_VOTE_PERMISSION_ID,
     ALLOW_WRITE_PERMISSION_ID,
 };
+use crate::service::voting::types::VotingService;
+use crate::settings::Settings;
 use crate::{EventsService, PermissionService};
 use candid::Principal;
 use shared::mvc::{HasRepository, Model, Repository};
 use shared::remote_call::{Program, ProgramExecutionResult, RemoteCallEndpoint};
-use shared::time::days;
 use shared::types::wallet::{AccessConfigId, ProgramExecutedWith, Shares};
 use std::collections::BTreeSet;
 
+pub mod check;
 pub mod crud;
+pub mod explain;
+pub mod multisig;
+pub mod query_delegation;
 pub mod types;
 
 impl AccessConfigService {
-    pub fn init_default_access_configs(wallet_creator_profile_id: Principal) {
+    pub fn init_default_access_configs(wallet_creator_profile_id: Principal, timestamp: u64) {
         let allow_vote_access_config_id = AccessConfigService::create_access_config(
             String::from("Voting access"),
             String::from("Non-deletable default access config. Allows 'Has profile' group members to call to any voting related method of this digital organization."),
             vec![ALLOW_VOTE_PERMISSION_ID].into_iter().collect(),
             vec![AlloweeConstraint::Group(GroupCondition { id: HAS_PROFILE_GROUP_ID, min_shares: Shares::from(1) })].into_iter().collect(),
+            None,
+            CyclesLimits::default(),
+            AccessConfigQuota::default(),
+            None,
+            AccessConfigValidity::default(),
+            timestamp,
         ).unwrap();
 
         assert_eq!(allow_vote_access_config_id, ALLOW_VOTE_ACCESS_CONFIG_ID);
@@ -40,6 +56,12 @@ impl AccessConfigService {
             String::from("Allows calling to any method (including query methods) of this digital organization. Be very careful editing or deleting this access config."),
             vec![ALLOW_WRITE_PERMISSION_ID, ALLOW_READ_PERMISSION_ID].into_iter().collect(),
             vec![AlloweeConstraint::Profile(wallet_creator_profile_id)].into_iter().collect(),
+            None,
+            CyclesLimits::default(),
+            AccessConfigQuota::default(),
+            None,
+            AccessConfigValidity::default(),
+            timestamp,
         ).unwrap();
 
         AccessConfigService::create_access_config(
@@ -47,6 +69,12 @@ impl AccessConfigService {
             String::from("Allows to call any query method of this digital organization. Be very careful editing or deleting this access config."),
             vec![ALLOW_READ_PERMISSION_ID].into_iter().collect(),
             vec![AlloweeConstraint::Group(GroupCondition { min_shares: Shares::from(1), id: HAS_PROFILE_GROUP_ID })].into_iter().collect(),
+            None,
+            CyclesLimits::default(),
+            AccessConfigQuota::default(),
+            None,
+            AccessConfigValidity::default(),
+            timestamp,
         ).unwrap();
     }
 
@@ -60,15 +88,63 @@ impl AccessConfigService {
             .validate()
             .map_err(AccessConfigError::ValidationError)?;
 
+        // fetching interfaces costs cycles, so only authorized programs are typechecked
+        AccessConfigService::assert_can_execute(id, &program, caller, timestamp)?;
+        program
+            .typecheck(&get_wallet_candid_interface())
+            .await
+            .map_err(AccessConfigError::ValidationError)?;
+
+        // the access config could have changed while interfaces were fetched
+        let ac = AccessConfigService::assert_can_execute(id, &program, caller, timestamp)?;
+
+        AccessConfigService::execute_with(&ac, program, caller, Vec::new(), timestamp).await
+    }
+
+    fn assert_can_execute(
+        id: &AccessConfigId,
+        program: &Program,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<AccessConfig, AccessConfigError> {
         let ac = AccessConfigService::get_access_config(id)?;
-        AccessConfigService::assert_program_fits(&ac, &program)?;
+        AccessConfigService::assert_active(&ac, timestamp)?;
+
+        if ac.get_multisig().is_some() {
+            return Err(AccessConfigError::MultisigApprovalRequired);
+        }
+
+        AccessConfigService::assert_program_fits(&ac, program)?;
         AccessConfigService::assert_caller_allowed(&ac, caller)?;
+        AccessConfigService::assert_within_limits(&ac, program, caller, timestamp)?;
+
+        VotingService::assert_program_proposer_valid(program, caller)
+            .map_err(AccessConfigError::ValidationError)?;
+
+        Ok(ac)
+    }
+
+    // the access config should already be checked to allow the program
+    async fn execute_with(
+        ac: &AccessConfig,
+        program: Program,
+        initiator: Principal,
+        approvers: Vec<Principal>,
+        timestamp: u64,
+    ) -> Result<ProgramExecutionResult, AccessConfigError> {
+        AccessConfigService::assert_within_limits(ac, &program, initiator, timestamp)?;
+
+        // charging upfront, since other programs may be executed while this one awaits
+        let cycles = program.get_total_cycles();
+        Settings::get().spend_cycles(cycles, timestamp);
+        AccessConfigService::record_execution(ac, initiator, timestamp);
 
         let result = program.execute().await;
 
         EventsService::emit_program_executed_event(
-            caller,
-            ProgramExecutedWith::WithAccessConfig(*id),
+            initiator,
+            ProgramExecutedWith::WithAccessConfig(ac.get_id().unwrap()),
+            approvers,
             program,
             result.clone(),
             timestamp,
@@ -81,22 +157,24 @@ impl AccessConfigService {
         canister_id: Principal,
         method_name: &str,
         caller: Principal,
+        timestamp: u64,
     ) -> bool {
-        let target_exact = PermissionTarget::Endpoint(RemoteCallEndpoint {
-            canister_id,
-            method_name: method_name.to_string(),
-        });
-        let target_wide = PermissionTarget::Endpoint(RemoteCallEndpoint::wildcard(canister_id));
+        let endpoint = RemoteCallEndpoint::new(canister_id, method_name);
 
-        let mut permission_ids = Permission::repo().get_permissions_by_target(&target_exact);
-        permission_ids.extend(Permission::repo().get_permissions_by_target(&target_wide));
+        for permission_id in Permission::repo().get_permissions_by_endpoint(&endpoint) {
+            // unwrapping, because it should exist if it is listed
+            let permission = Permission::repo().get(&permission_id).unwrap();
+            if !permission.is_endpoint_allowed(&endpoint) {
+                continue;
+            }
 
-        for permission_id in permission_ids {
             for config_id in AccessConfig::repo().get_access_configs_by_permission(&permission_id) {
                 // unwrapping, because it should exist if it is listed
                 let ac = AccessConfig::repo().get(&config_id).unwrap();
 
-                if AccessConfigService::assert_caller_allowed(&ac, caller).is_ok() {
+                if ac.is_active_at(timestamp)
+                    && AccessConfigService::assert_caller_allowed(&ac, caller).is_ok()
+                {
                     return true;
                 }
             }
@@ -105,46 +183,145 @@ impl AccessConfigService {
         false
     }
 
-    fn caller_has_access_to_target(target: &PermissionTarget, caller: Principal) -> bool {
-        let mut permission_ids = Permission::repo().get_permissions_by_target(target);
+    // the most permissive expiry among access configs allowing the caller to access the target,
+    // recurring windows of access configs are not carried over to the proof
+    fn query_delegation_expiry_for_target(
+        target: &PermissionTarget,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Option<u64> {
+        let permission_ids = Permission::repo().get_permissions_by_matching_target(target);
+        let mut expiry_opt = None;
 
         for permission_id in permission_ids {
+            // unwrapping, because it should exist if it is listed
+            let permission = Permission::repo().get(&permission_id).unwrap();
+            if !permission.is_target_granted(target) {
+                continue;
+            }
+
             for config_id in AccessConfig::repo().get_access_configs_by_permission(&permission_id) {
                 // unwrapping, because it should exist if it is listed
                 let ac = AccessConfig::repo().get(&config_id).unwrap();
 
-                if AccessConfigService::assert_caller_allowed(&ac, caller).is_ok() {
-                    return true;
+                if ac.is_active_at(timestamp)
+                    && AccessConfigService::assert_caller_allowed(&ac, caller).is_ok()
+                {
+                    let mut expiry = ac
+                        .get_query_delegation_expiry()
+                        .unwrap_or(DEFAULT_QUERY_DELEGATION_EXPIRY);
+
+                    // a proof should not outlive the access config
+                    if let Some(not_after) = ac.get_validity().not_after {
+                        expiry = expiry.min((not_after - timestamp).saturating_add(1));
+                    }
+
+                    expiry_opt = Some(expiry_opt.map_or(expiry, |it: u64| it.max(expiry)));
                 }
             }
         }
 
-        false
+        expiry_opt
     }
 
-    pub fn get_query_delegation_proof(
-        this_union_id: Principal,
-        delegate_id: Principal,
-        requested_targets: BTreeSet<PermissionTarget>,
+    pub fn get_remaining_quota(
+        ac: &AccessConfig,
+        caller: Principal,
         timestamp: u64,
-    ) -> QueryDelegationProof {
-        let groups_of_delegate = GroupService::get_groups_of(&delegate_id);
-        let mut allowed_query_targets = Vec::new();
-
-        for target in requested_targets {
-            if AccessConfigService::caller_has_access_to_target(&target, delegate_id) {
-                allowed_query_targets.push(target);
+    ) -> RemainingQuota {
+        let id = ac.get_id().unwrap();
+        let quota = ac.get_quota();
+        let usage = AccessConfigUsage::repo()
+            .get(&id)
+            .unwrap_or_else(|| AccessConfigUsage::new(id));
+
+        let (executions_in_period, period_ends_at) = match &quota.per_principal {
+            Some(limit) => {
+                let used = usage.get_executions_in_period(&caller, Some(limit.period), timestamp);
+                let period_start = timestamp - timestamp % limit.period;
+
+                (
+                    Some(limit.max_executions.saturating_sub(used)),
+                    Some(period_start.saturating_add(limit.period)),
+                )
             }
+            None => (None, None),
+        };
+
+        let total_executions = quota
+            .max_total_executions
+            .map(|max| max.saturating_sub(usage.get_total_executions()));
+
+        let next_execution_at = match (quota.min_interval, usage.get_last_executed_at(&caller)) {
+            (Some(interval), Some(last)) => Some(last.saturating_add(interval)),
+            _ => None,
+        };
+
+        RemainingQuota {
+            executions_in_period,
+            period_ends_at,
+            total_executions,
+            next_execution_at,
         }
+    }
 
-        // TODO: implement subnet signature
-        QueryDelegationProof {
-            union_id: this_union_id,
-            delegate_id,
-            allowed_query_targets,
-            // TODO: make expiration timeout configurable
-            expires_at: timestamp + days(1),
-            signature: (),
+    pub fn deactivate_expired_access_config(id: &AccessConfigId, timestamp: u64) {
+        let mut ac = match AccessConfig::repo().get(id) {
+            Some(it) => it,
+            None => return,
+        };
+
+        if !ac.is_active() || !ac.get_validity().is_expired(timestamp) {
+            return;
+        }
+
+        ac.deactivate();
+        AccessConfig::repo().save(ac);
+
+        EventsService::emit_access_config_expired_event(*id, timestamp);
+    }
+
+    fn record_execution(ac: &AccessConfig, caller: Principal, timestamp: u64) {
+        let id = ac.get_id().unwrap();
+        let mut usage = AccessConfigUsage::repo()
+            .get(&id)
+            .unwrap_or_else(|| AccessConfigUsage::new(id));
+
+        let period = ac.get_quota().per_principal.map(|it| it.period);
+        usage.record_execution(caller, period, timestamp);
+
+        AccessConfigUsage::repo().save(usage);
+    }
+
+    fn assert_within_limits(
+        ac: &AccessConfig,
+        program: &Program,
+        initiator: Principal,
+        timestamp: u64,
+    ) -> Result<(), AccessConfigError> {
+        if !ac.get_cycles_limits().is_program_within(program) {
+            return Err(AccessConfigError::CyclesLimitExceeded);
+        }
+
+        let remaining_quota = AccessConfigService::get_remaining_quota(ac, initiator, timestamp);
+        if !remaining_quota.allows_execution(timestamp) {
+            return Err(AccessConfigError::ExecutionQuotaExceeded(remaining_quota));
+        }
+
+        if !Settings::get().is_within_cycles_budget(program.get_total_cycles(), timestamp) {
+            return Err(AccessConfigError::CyclesBudgetExceeded);
+        }
+
+        Ok(())
+    }
+
+    fn assert_active(ac: &AccessConfig, timestamp: u64) -> Result<(), AccessConfigError> {
+        if ac.is_active_at(timestamp) {
+            Ok(())
+        } else {
+            Err(AccessConfigError::AccessConfigInactive(
+                ac.get_id().unwrap(),
+            ))
         }
     }
 
@@ -152,30 +329,61 @@ impl AccessConfigService {
         ac: &AccessConfig,
         caller: Principal,
     ) -> Result<(), AccessConfigError> {
-        for allowee in ac.get_allowees() {
-            match allowee {
-                AlloweeConstraint::Everyone => return Ok(()),
-                AlloweeConstraint::Profile(p) => {
-                    if *p == caller {
-                        // unwrapping, because it should exist if it is listed
-                        Profile::repo().get(&p).unwrap();
-
-                        return Ok(());
-                    }
-                }
-                AlloweeConstraint::Group(group_condition) => {
+        AccessConfigService::find_satisfied_allowee(ac, caller)
+            .map(|_| ())
+            .ok_or(AccessConfigError::CallerNotAllowed)
+    }
+
+    fn find_satisfied_allowee(ac: &AccessConfig, caller: Principal) -> Option<&AlloweeConstraint> {
+        ac.get_allowees()
+            .iter()
+            .find(|it| AccessConfigService::is_caller_allowed_by(it, caller))
+    }
+
+    fn is_caller_allowed_by(allowee: &AlloweeConstraint, caller: Principal) -> bool {
+        match allowee {
+            AlloweeConstraint::Everyone => true,
+            AlloweeConstraint::Profile(p) => {
+                if *p == caller {
                     // unwrapping, because it should exist if it is listed
-                    let group = Group::repo().get(&group_condition.id).unwrap();
-                    let token = GroupService::get_token(&group);
+                    Profile::repo().get(p).unwrap();
 
-                    if token.balance_of(&caller) >= group_condition.min_shares.clone() {
-                        return Ok(());
-                    }
+                    true
+                } else {
+                    false
                 }
             }
-        }
+            AlloweeConstraint::Group(group_condition) => {
+                // unwrapping, because it should exist if it is listed
+                let group = Group::repo().get(&group_condition.id).unwrap();
+                let token = GroupService::get_token(&group);
+
+                token.balance_of(&caller) >= group_condition.min_shares.clone()
+            }
+            AlloweeConstraint::GroupTree(group_condition) => {
+                let mut balance = Shares::default();
 
-        Err(AccessConfigError::CallerNotAllowed)
+                for group_id in GroupService::get_group_descendants(&group_condition.id)
+                    .iter()
+                    .chain(Some(&group_condition.id))
+                {
+                    // unwrapping, because it should exist if it is listed
+                    let group = Group::repo().get(group_id).unwrap();
+                    balance += GroupService::get_token(&group).balance_of(&caller);
+                }
+
+                balance >= group_condition.min_shares.clone()
+            }
+            AlloweeConstraint::And(constraints) => constraints
+                .iter()
+                .all(|it| AccessConfigService::is_caller_allowed_by(it, caller)),
+            AlloweeConstraint::Or(constraints) => constraints
+                .iter()
+                .any(|it| AccessConfigService::is_caller_allowed_by(it, caller)),
+            AlloweeConstraint::Not(constraint) => {
+                !AccessConfigService::is_caller_allowed_by(constraint, caller)
+            }
+        }
     }
 
     fn assert_program_fits(ac: &AccessConfig, program: &Program) -> Result<(), AccessConfigError> {
@@ -206,18 +414,16 @@ impl AccessConfigService {
         allowees: &BTreeSet<AlloweeConstraint>,
     ) -> Result<(), AccessConfigError> {
         for allowee in allowees {
-            match allowee {
-                AlloweeConstraint::Group(g) => {
-                    Group::repo()
-                        .get(&g.id)
-                        .ok_or(AccessConfigError::GroupNotFound(g.id))?;
-                }
-                AlloweeConstraint::Profile(p) => {
-                    Profile::repo()
-                        .get(p)
-                        .ok_or(AccessConfigError::ProfileNotFound(*p))?;
-                }
-                _ => {}
+            for group_id in allowee.get_mentioned_groups() {
+                Group::repo()
+                    .get(&group_id)
+                    .ok_or(AccessConfigError::GroupNotFound(group_id))?;
+            }
+
+            for profile_id in allowee.get_mentioned_profiles() {
+                Profile::repo()
+                    .get(&profile_id)
+                    .ok_or(AccessConfigError::ProfileNotFound(profile_id))?;
             }
         }
 
@@ -0,0 +1,234 @@
+use crate::repository::access_config::model::AccessConfig;
+use crate::repository::access_config::types::MultisigSettings;
+use crate::repository::multisig_proposal::model::MultisigProposal;
+use crate::repository::multisig_proposal::types::{
+    MultisigProposalFilter, MultisigProposalId, MultisigProposalStatus,
+};
+use crate::service::access_config::types::{AccessConfigError, AccessConfigService};
+use crate::service::permission::interface::get_wallet_candid_interface;
+use crate::service::voting::types::VotingService;
+use candid::Principal;
+use shared::mvc::{HasRepository, Repository};
+use shared::pageable::{Page, PageRequest};
+use shared::remote_call::{Program, ProgramExecutionResult};
+use shared::types::wallet::AccessConfigId;
+
+impl AccessConfigService {
+    // the proposal is executed right away, if the proposer alone is enough to reach the threshold
+    pub async fn propose_multisig_program(
+        id: &AccessConfigId,
+        program: Program,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<(MultisigProposalId, Option<ProgramExecutionResult>), AccessConfigError> {
+        program
+            .validate()
+            .map_err(AccessConfigError::ValidationError)?;
+
+        // fetching interfaces costs cycles, so only authorized programs are typechecked
+        AccessConfigService::assert_can_propose(id, &program, caller, timestamp)?;
+        program
+            .typecheck(&get_wallet_candid_interface())
+            .await
+            .map_err(AccessConfigError::ValidationError)?;
+
+        // the access config could have changed while interfaces were fetched
+        let ac = AccessConfigService::assert_can_propose(id, &program, caller, timestamp)?;
+        let multisig = AccessConfigService::get_multisig_settings(&ac)?;
+
+        let proposal = MultisigProposal::new(
+            *id,
+            caller,
+            program,
+            timestamp,
+            timestamp.saturating_add(multisig.timeout),
+        );
+        let proposal_id = MultisigProposal::repo().save(proposal);
+
+        let result =
+            AccessConfigService::try_execute_multisig_proposal(&proposal_id, timestamp).await?;
+
+        Ok((proposal_id, result))
+    }
+
+    fn assert_can_propose(
+        id: &AccessConfigId,
+        program: &Program,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<AccessConfig, AccessConfigError> {
+        let ac = AccessConfigService::get_access_config(id)?;
+        AccessConfigService::get_multisig_settings(&ac)?;
+
+        AccessConfigService::assert_active(&ac, timestamp)?;
+        AccessConfigService::assert_program_fits(&ac, program)?;
+        AccessConfigService::assert_caller_allowed(&ac, caller)?;
+
+        if !ac.get_cycles_limits().is_program_within(program) {
+            return Err(AccessConfigError::CyclesLimitExceeded);
+        }
+
+        VotingService::assert_program_proposer_valid(program, caller)
+            .map_err(AccessConfigError::ValidationError)?;
+
+        Ok(ac)
+    }
+
+    pub async fn approve_multisig_proposal(
+        id: &MultisigProposalId,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<Option<ProgramExecutionResult>, AccessConfigError> {
+        let (mut proposal, ac) = AccessConfigService::get_pending_multisig_proposal(id, timestamp)?;
+        AccessConfigService::assert_caller_allowed(&ac, caller)?;
+
+        proposal.approve(caller);
+        MultisigProposal::repo().save(proposal);
+
+        AccessConfigService::try_execute_multisig_proposal(id, timestamp).await
+    }
+
+    // the proposal is rejected, once the veto threshold is reached
+    pub fn reject_multisig_proposal(
+        id: &MultisigProposalId,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<(), AccessConfigError> {
+        let (mut proposal, ac) = AccessConfigService::get_pending_multisig_proposal(id, timestamp)?;
+        AccessConfigService::assert_caller_allowed(&ac, caller)?;
+
+        let multisig = AccessConfigService::get_multisig_settings(&ac)?;
+
+        proposal.reject(caller);
+        let rejecters = proposal
+            .get_rejecters()
+            .iter()
+            .filter(|it| AccessConfigService::assert_caller_allowed(&ac, **it).is_ok())
+            .count();
+
+        if rejecters >= multisig.get_veto_threshold() as usize {
+            proposal.set_status(MultisigProposalStatus::Rejected);
+        }
+
+        MultisigProposal::repo().save(proposal);
+
+        Ok(())
+    }
+
+    pub fn cancel_multisig_proposal(
+        id: &MultisigProposalId,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<(), AccessConfigError> {
+        let (mut proposal, _) = AccessConfigService::get_pending_multisig_proposal(id, timestamp)?;
+
+        if *proposal.get_proposer() != caller {
+            return Err(AccessConfigError::NotMultisigProposer);
+        }
+
+        proposal.set_status(MultisigProposalStatus::Cancelled);
+        MultisigProposal::repo().save(proposal);
+
+        Ok(())
+    }
+
+    pub fn get_multisig_proposal(
+        id: &MultisigProposalId,
+        timestamp: u64,
+    ) -> Result<MultisigProposal, AccessConfigError> {
+        let mut proposal = MultisigProposal::repo()
+            .get(id)
+            .ok_or(AccessConfigError::MultisigProposalNotFound(*id))?;
+        proposal.refresh_status(timestamp);
+
+        Ok(proposal)
+    }
+
+    pub fn list_multisig_proposals(
+        page_req: &PageRequest<MultisigProposalFilter, ()>,
+        timestamp: u64,
+    ) -> Page<MultisigProposal> {
+        let mut page = MultisigProposal::repo().list(page_req);
+        for proposal in &mut page.data {
+            proposal.refresh_status(timestamp);
+        }
+
+        page
+    }
+
+    pub fn delete_multisig_proposals_of(access_config_id: &AccessConfigId) {
+        for id in MultisigProposal::repo().get_proposals_by_access_config(access_config_id) {
+            MultisigProposal::repo().delete(&id);
+        }
+    }
+
+    // executes the proposal if it gathered enough approvals, returns None otherwise
+    async fn try_execute_multisig_proposal(
+        id: &MultisigProposalId,
+        timestamp: u64,
+    ) -> Result<Option<ProgramExecutionResult>, AccessConfigError> {
+        let (mut proposal, ac) = AccessConfigService::get_pending_multisig_proposal(id, timestamp)?;
+        let multisig = AccessConfigService::get_multisig_settings(&ac)?;
+
+        // allowees could have changed since approvals were given - only current ones count
+        let approvers: Vec<_> = proposal
+            .get_approvers()
+            .iter()
+            .filter(|it| AccessConfigService::assert_caller_allowed(&ac, **it).is_ok())
+            .cloned()
+            .collect();
+
+        if approvers.len() < multisig.threshold as usize {
+            return Ok(None);
+        }
+
+        // the access config could have changed since the program was proposed
+        AccessConfigService::assert_active(&ac, timestamp)?;
+        AccessConfigService::assert_program_fits(&ac, proposal.get_program())?;
+
+        // marking upfront, so the proposal can't be executed twice while this one awaits
+        proposal.set_status(MultisigProposalStatus::Executed);
+        MultisigProposal::repo().save(proposal.clone());
+
+        let res = AccessConfigService::execute_with(
+            &ac,
+            proposal.get_program().clone(),
+            *proposal.get_proposer(),
+            approvers,
+            timestamp,
+        )
+        .await;
+
+        match res {
+            Ok(result) => Ok(Some(result)),
+            Err(e) => {
+                // the program was not executed - let allowees retry later
+                proposal.set_status(MultisigProposalStatus::Pending);
+                MultisigProposal::repo().save(proposal);
+
+                Err(e)
+            }
+        }
+    }
+
+    fn get_pending_multisig_proposal(
+        id: &MultisigProposalId,
+        timestamp: u64,
+    ) -> Result<(MultisigProposal, AccessConfig), AccessConfigError> {
+        let proposal = AccessConfigService::get_multisig_proposal(id, timestamp)?;
+
+        if !proposal.is_pending() {
+            return Err(AccessConfigError::MultisigProposalNotPending(*id));
+        }
+
+        let ac = AccessConfigService::get_access_config(proposal.get_access_config_id())?;
+
+        Ok((proposal, ac))
+    }
+
+    fn get_multisig_settings(ac: &AccessConfig) -> Result<MultisigSettings, AccessConfigError> {
+        ac.get_multisig()
+            .cloned()
+            .ok_or_else(|| AccessConfigError::NotMultisigAccessConfig(ac.get_id().unwrap()))
+    }
+}
@@ -0,0 +1,165 @@
+use crate::repository::permission::types::PermissionTarget;
+use crate::repository::query_delegation::model::QueryDelegation;
+use crate::repository::query_delegation::types::QueryDelegationId;
+use crate::service::access_config::types::{
+    AccessConfigError, AccessConfigService, QueryDelegationProof, QueryDelegationProofSignature,
+};
+use candid::Principal;
+use ic_cdk::api::{data_certificate, set_certified_data};
+use ic_certified_map::{AsHashTree, RbTree};
+use shared::mvc::{HasRepository, Repository};
+use shared::types::Blob;
+use shared::validation::ValidationError;
+use std::collections::BTreeSet;
+
+// not persisted - rebuilt from the repository after each upgrade
+static mut CERTIFIED_QUERY_DELEGATIONS: Option<RbTree<Blob, Blob>> = None;
+
+fn get_certified_query_delegations() -> &'static mut RbTree<Blob, Blob> {
+    unsafe {
+        match CERTIFIED_QUERY_DELEGATIONS.as_mut() {
+            Some(t) => t,
+            None => {
+                CERTIFIED_QUERY_DELEGATIONS = Some(RbTree::new());
+                get_certified_query_delegations()
+            }
+        }
+    }
+}
+
+impl AccessConfigService {
+    pub fn issue_query_delegation_proof(
+        this_union_id: Principal,
+        delegate_id: Principal,
+        requested_targets: BTreeSet<PermissionTarget>,
+        expires_in_opt: Option<u64>,
+        timestamp: u64,
+    ) -> Result<QueryDelegationProof, AccessConfigError> {
+        if expires_in_opt == Some(0) {
+            return Err(AccessConfigError::ValidationError(ValidationError(
+                String::from("Query delegation expiry should be positive"),
+            )));
+        }
+
+        let mut allowed_query_targets = Vec::new();
+        let mut expires_in_opt = expires_in_opt;
+
+        for target in requested_targets {
+            if let Some(expiry) = AccessConfigService::query_delegation_expiry_for_target(
+                &target,
+                delegate_id,
+                timestamp,
+            ) {
+                allowed_query_targets.push(target);
+                expires_in_opt = Some(expires_in_opt.map_or(expiry, |it| it.min(expiry)));
+            }
+        }
+
+        if allowed_query_targets.is_empty() {
+            return Err(AccessConfigError::QueryTargetsNotAllowed);
+        }
+
+        AccessConfigService::delete_expired_query_delegations_of(&delegate_id, timestamp);
+
+        let delegation = QueryDelegation::new(
+            delegate_id,
+            allowed_query_targets,
+            timestamp,
+            timestamp.saturating_add(expires_in_opt.unwrap()),
+        );
+        let id = QueryDelegation::repo().save(delegation);
+        let delegation = QueryDelegation::repo().get(&id).unwrap();
+
+        let proof = QueryDelegationProof::from_delegation(this_union_id, &delegation);
+        AccessConfigService::certify_query_delegation_proof(&proof);
+        AccessConfigService::update_certified_data();
+
+        Ok(proof)
+    }
+
+    // the certificate is only available for non-replicated query calls
+    pub fn get_query_delegation_proof(
+        this_union_id: Principal,
+        id: &QueryDelegationId,
+        delegate_id: Principal,
+    ) -> Result<QueryDelegationProof, AccessConfigError> {
+        let delegation = QueryDelegation::repo()
+            .get(id)
+            .filter(|it| *it.get_delegate_id() == delegate_id)
+            .ok_or(AccessConfigError::QueryDelegationNotFound(*id))?;
+
+        let mut proof = QueryDelegationProof::from_delegation(this_union_id, &delegation);
+        proof.signature = data_certificate().map(|certificate| {
+            let witness =
+                get_certified_query_delegations().witness(&QueryDelegationProof::key(*id));
+
+            QueryDelegationProofSignature {
+                certificate,
+                witness: serde_cbor::to_vec(&witness).unwrap(),
+            }
+        });
+
+        Ok(proof)
+    }
+
+    // cuts off all proofs issued to the delegate until now - both by this union and by other ones
+    pub fn revoke_query_delegate(delegate_id: Principal, timestamp: u64) {
+        QueryDelegation::repo().revoke_delegate(delegate_id, timestamp);
+
+        for id in QueryDelegation::repo().get_delegations_by_delegate(&delegate_id) {
+            QueryDelegation::repo().delete(&id);
+            get_certified_query_delegations().delete(&QueryDelegationProof::key(id));
+        }
+
+        AccessConfigService::update_certified_data();
+    }
+
+    #[inline(always)]
+    pub fn list_revoked_query_delegates() -> Vec<(Principal, u64)> {
+        QueryDelegation::repo().list_revoked_delegates()
+    }
+
+    pub fn assert_query_delegate_not_revoked(
+        proof: &QueryDelegationProof,
+    ) -> Result<(), AccessConfigError> {
+        match QueryDelegation::repo().get_revoked_at(&proof.delegate_id) {
+            Some(revoked_at) if revoked_at >= proof.issued_at => {
+                Err(AccessConfigError::QueryDelegateRevoked(proof.delegate_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn rebuild_query_delegation_certification(this_union_id: Principal) {
+        unsafe { CERTIFIED_QUERY_DELEGATIONS = Some(RbTree::new()) };
+
+        for delegation in QueryDelegation::repo().get_all() {
+            let proof = QueryDelegationProof::from_delegation(this_union_id, &delegation);
+            AccessConfigService::certify_query_delegation_proof(&proof);
+        }
+
+        AccessConfigService::update_certified_data();
+    }
+
+    fn delete_expired_query_delegations_of(delegate_id: &Principal, timestamp: u64) {
+        for id in QueryDelegation::repo().get_delegations_by_delegate(delegate_id) {
+            let delegation = QueryDelegation::repo().get(&id).unwrap();
+
+            if delegation.is_expired(timestamp) {
+                QueryDelegation::repo().delete(&id);
+                get_certified_query_delegations().delete(&QueryDelegationProof::key(id));
+            }
+        }
+    }
+
+    fn certify_query_delegation_proof(proof: &QueryDelegationProof) {
+        get_certified_query_delegations().insert(
+            QueryDelegationProof::key(proof.id),
+            proof.content_hash().to_vec(),
+        );
+    }
+
+    fn update_certified_data() {
+        set_certified_data(&get_certified_query_delegations().root_hash());
+    }
+}
@@ -1,13 +1,28 @@
-use crate::repository::permission::types::{PermissionId, PermissionTarget};
-use candid::{CandidType, Deserialize, Principal};
-use ic_cdk::api::time;
-use ic_cdk::caller;
-use shared::types::wallet::{AccessConfigId, GroupId, ProfileId};
+use crate::repository::access_config::types::AlloweeConstraint;
+use crate::repository::multisig_proposal::types::MultisigProposalId;
+use crate::repository::permission::types::{PermissionId, PermissionTarget, TargetMatch};
+use crate::repository::query_delegation::model::QueryDelegation;
+use crate::repository::query_delegation::types::QueryDelegationId;
+use crate::settings::Settings;
+use candid::{CandidType, Deserialize, Encode, Principal};
+use shared::certification::{sha256, Certificate, Hash, HashTree};
+use shared::mvc::Model;
+use shared::remote_call::RemoteCallEndpoint;
+use shared::time::{days, mins};
+use shared::types::wallet::{AccessConfigId, GroupId, ProfileId, VotingConfigId};
+use shared::types::Blob;
 use shared::validation::ValidationError;
 use std::collections::BTreeSet;
 
 pub const ALLOW_VOTE_ACCESS_CONFIG_ID: AccessConfigId = 0;
 
+pub const DEFAULT_QUERY_DELEGATION_EXPIRY: u64 = days(1);
+
+// bounds the time it takes for a revocation made by the issuing union to take effect
+pub const QUERY_DELEGATION_CERTIFICATE_MAX_AGE: u64 = mins(5);
+
+pub const QUERY_DELEGATION_LABEL: &[u8] = b"query_delegation";
+
 pub struct AccessConfigService;
 
 #[derive(Debug)]
@@ -20,26 +35,164 @@ pub enum AccessConfigError {
     UnableToEditDefaultAccessConfig,
     CallerNotAllowed,
     ProgramNotAllowedByAccessConfig,
+    QueryDelegationNotFound(QueryDelegationId),
+    QueryTargetsNotAllowed,
+    QueryDelegateRevoked(Principal),
+    CyclesLimitExceeded,
+    CyclesBudgetExceeded,
+    AccessConfigInactive(AccessConfigId),
+    ExecutionQuotaExceeded(RemainingQuota),
+    MultisigApprovalRequired,
+    NotMultisigAccessConfig(AccessConfigId),
+    MultisigProposalNotFound(MultisigProposalId),
+    MultisigProposalNotPending(MultisigProposalId),
+    NotMultisigProposer,
+}
+
+// What is left of an access config quota for a particular principal, no limit if none
+#[derive(Debug, Clone, CandidType, Deserialize)]
+pub struct RemainingQuota {
+    pub executions_in_period: Option<u64>,
+    pub period_ends_at: Option<u64>,
+    pub total_executions: Option<u64>,
+    pub next_execution_at: Option<u64>,
+}
+
+impl RemainingQuota {
+    pub fn allows_execution(&self, timestamp: u64) -> bool {
+        self.executions_in_period != Some(0)
+            && self.total_executions != Some(0)
+            && !matches!(self.next_execution_at, Some(it) if timestamp < it)
+    }
+}
+
+// The first rule of the access config, which doesn't let the program execute
+#[derive(Clone, CandidType, Deserialize)]
+pub enum ExecutionCheckFailure {
+    InvalidProgram(ValidationError),
+    AccessConfigInactive,
+    MultisigApprovalRequired,
+    ProgramNotAllowedByAccessConfig,
+    CallerNotAllowed,
+    CyclesLimitExceeded,
+    ExecutionQuotaExceeded(RemainingQuota),
+    CyclesBudgetExceeded,
+    InvalidProposer(ValidationError),
+    TypecheckFailed(ValidationError),
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct CallCheck {
+    pub endpoint: RemoteCallEndpoint,
+    // how each permission of the access config decides on the call
+    pub verdicts: Vec<(PermissionId, TargetMatch)>,
+}
+
+// A dry-run of an execution, nothing is charged or recorded
+#[derive(Clone, CandidType, Deserialize)]
+pub struct ExecutionCheck {
+    pub calls: Vec<CallCheck>,
+    // a single permission should allow all calls of the program
+    pub allowing_permission: Option<PermissionId>,
+    pub satisfied_allowee: Option<AlloweeConstraint>,
+    pub failure: Option<ExecutionCheckFailure>,
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct AccessConfigExplanation {
+    pub id: AccessConfigId,
+    pub allowees: BTreeSet<AlloweeConstraint>,
+    pub is_active: bool,
+    pub is_multisig: bool,
+}
+
+// A permission allowing calls to an endpoint and everything able to use this permission
+#[derive(Clone, CandidType, Deserialize)]
+pub struct PermissionExplanation {
+    pub permission_id: PermissionId,
+    pub access_configs: Vec<AccessConfigExplanation>,
+    pub voting_configs: Vec<VotingConfigId>,
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct EndpointAccessExplanation {
+    pub permissions: Vec<PermissionExplanation>,
+    // access configs allowing to propose votings, if any of voting configs can reach the endpoint
+    pub voting_proposers: Vec<AccessConfigExplanation>,
+}
+
+// Deny targets of the same permissions still apply to endpoints matched by these targets
+#[derive(Clone, CandidType, Deserialize)]
+pub struct PrincipalAccessExplanation {
+    // by executing programs or calling to queries with access configs
+    pub direct: BTreeSet<PermissionTarget>,
+    // by presenting query delegation proofs issued by this union to other unions
+    pub delegated: BTreeSet<PermissionTarget>,
+    // by proposing votings with voting configs
+    pub by_voting: BTreeSet<PermissionTarget>,
+}
+
+#[derive(Clone, CandidType, Deserialize)]
+pub struct QueryDelegationProofSignature {
+    // CBOR-encoded IC state certificate of the issuing union
+    pub certificate: Blob,
+    // CBOR-encoded hash tree witness of the proof
+    pub witness: Blob,
 }
 
-#[derive(CandidType, Deserialize)]
+#[derive(Clone, CandidType, Deserialize)]
 pub struct QueryDelegationProof {
+    pub id: QueryDelegationId,
     pub union_id: Principal,
     pub delegate_id: Principal,
     pub allowed_query_targets: Vec<PermissionTarget>,
+    pub issued_at: u64,
     pub expires_at: u64,
-    // TODO: implement subnet signature
-    pub signature: (),
+    // only present when the proof is fetched with a non-replicated query call
+    pub signature: Option<QueryDelegationProofSignature>,
 }
 
 impl QueryDelegationProof {
+    pub fn from_delegation(union_id: Principal, it: &QueryDelegation) -> Self {
+        Self {
+            id: it.get_id().unwrap(),
+            union_id,
+            delegate_id: *it.get_delegate_id(),
+            allowed_query_targets: it.get_allowed_query_targets().clone(),
+            issued_at: it.get_issued_at(),
+            expires_at: it.get_expires_at(),
+            signature: None,
+        }
+    }
+
+    pub fn key(id: QueryDelegationId) -> Blob {
+        let mut key = QUERY_DELEGATION_LABEL.to_vec();
+        key.extend_from_slice(&id.to_be_bytes());
+
+        key
+    }
+
+    // the certified leaf of the proof - everything except the signature
+    pub fn content_hash(&self) -> Hash {
+        let content = Encode!(
+            &self.id,
+            &self.union_id,
+            &self.delegate_id,
+            &self.allowed_query_targets,
+            &self.issued_at,
+            &self.expires_at
+        )
+        .unwrap();
+
+        sha256(&content)
+    }
+
     pub fn validate(
         &self,
         caller: Principal,
         timestamp: u64,
-        targets: &Vec<PermissionTarget>,
+        endpoint: &RemoteCallEndpoint,
     ) -> Result<(), ValidationError> {
-        // TODO: implement signature validation
         if self.expires_at < timestamp {
             return Err(ValidationError(format!(
                 "Query delegation proof is expired"
@@ -52,14 +205,63 @@ impl QueryDelegationProof {
             )));
         }
 
-        for target in targets {
-            if self.allowed_query_targets.contains(target) {
-                return Ok(());
-            }
+        if !self
+            .allowed_query_targets
+            .iter()
+            .any(|target| match target {
+                PermissionTarget::Endpoint(e) => e.matches(endpoint),
+                _ => false,
+            })
+        {
+            return Err(ValidationError(format!(
+                "Query delegation proof does not contain required targets"
+            )));
         }
 
-        Err(ValidationError(format!(
-            "Query delegation proof does not contain required targets"
-        )))
+        let root_key = Settings::get().get_ic_root_key();
+        self.validate_signature(timestamp, |certificate| {
+            certificate.verify(&self.union_id, &root_key)
+        })
+    }
+
+    pub fn validate_signature<F: Fn(&Certificate) -> bool>(
+        &self,
+        timestamp: u64,
+        verify_certificate: F,
+    ) -> Result<(), ValidationError> {
+        let err = |msg: &str| Err(ValidationError(format!("Query delegation proof {}", msg)));
+
+        let signature = match &self.signature {
+            Some(s) => s,
+            None => return err("is not signed"),
+        };
+
+        let certificate = match Certificate::from_cbor(&signature.certificate) {
+            Some(c) if verify_certificate(&c) => c,
+            _ => return err("certificate is invalid"),
+        };
+
+        match certificate.get_time() {
+            Some(certified_at)
+                if certified_at.saturating_add(QUERY_DELEGATION_CERTIFICATE_MAX_AGE)
+                    >= timestamp => {}
+            _ => return err("certificate is too old"),
+        }
+
+        let certified_data = match certificate.get_certified_data(&self.union_id) {
+            Some(d) => d,
+            None => return err("certificate does not belong to the issuing union"),
+        };
+
+        let witness = match HashTree::from_cbor(&signature.witness) {
+            Some(w) if w.reconstruct().as_slice() == certified_data => w,
+            _ => return err("witness is invalid"),
+        };
+
+        // revoked proofs are removed from the tree of the issuing union
+        match witness.lookup_path(&[&Self::key(self.id)]) {
+            Some(leaf) if leaf == self.content_hash().as_slice() => Ok(()),
+            _ => err("is not certified by the issuing union"),
+        }
     }
 }
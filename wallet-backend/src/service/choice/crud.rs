@@ -4,15 +4,18 @@ use crate::repository::nested_voting::types::RemoteVotingId;
 use crate::repository::token::model::Token;
 use crate::repository::voting::model::Voting;
 use crate::service::choice::types::{ChoiceError, ChoiceService};
+use crate::service::permission::interface::get_wallet_candid_interface;
 use crate::service::voting::types::VotingService;
 use crate::service::voting_config::types::VotingConfigService;
+use crate::settings::Settings;
+use ic_cdk::api::id;
 use shared::mvc::{HasRepository, Repository};
 use shared::pageable::{Page, PageRequest};
 use shared::remote_call::Program;
 use shared::types::wallet::{ChoiceId, VotingId};
 
 impl ChoiceService {
-    pub fn create_choice(
+    pub async fn create_choice(
         name: String,
         description: String,
         program: Program,
@@ -20,6 +23,13 @@ impl ChoiceService {
         timestamp: u64,
     ) -> Result<ChoiceId, ChoiceError> {
         program.validate().map_err(ChoiceError::ValidationError)?;
+        program
+            .typecheck(&get_wallet_candid_interface())
+            .await
+            .map_err(ChoiceError::ValidationError)?;
+        VotingService::assert_program_proposer_valid(&program, id())
+            .map_err(ChoiceError::ValidationError)?;
+
         let voting_id = match voting_id {
             RemoteVotingId::Common(id) => id,
             _ => unreachable!(),
@@ -36,6 +46,8 @@ impl ChoiceService {
             return Err(ChoiceError::ProgramNotAllowedByVotingConfig);
         }
 
+        ChoiceService::assert_program_fits_cycles_budget(&program)?;
+
         VotingService::reset_approval_choice(&voting);
 
         let choice = Choice::new(
@@ -54,22 +66,41 @@ impl ChoiceService {
         Ok(id)
     }
 
-    pub fn create_rejection_and_approval_choices(voting_id: VotingId) -> (ChoiceId, ChoiceId) {
+    pub fn create_rejection_and_approval_choices(
+        voting_id: VotingId,
+        allow_abstain: bool,
+    ) -> (ChoiceId, ChoiceId, Option<ChoiceId>) {
         let rejection_choice = Choice::new_rejection(voting_id);
         let approval_choice = Choice::new_approval(voting_id);
 
         let rejection_choice_id = Choice::repo().save(rejection_choice);
         let approval_choice_id = Choice::repo().save(approval_choice);
 
-        (rejection_choice_id, approval_choice_id)
+        let abstain_choice_id_opt = if allow_abstain {
+            Some(Choice::repo().save(Choice::new_abstain(voting_id)))
+        } else {
+            None
+        };
+
+        (rejection_choice_id, approval_choice_id, abstain_choice_id_opt)
     }
 
-    pub fn update_choice(
+    pub async fn update_choice(
         choice_id: &ChoiceId,
         new_name: Option<String>,
         new_description: Option<String>,
         new_program: Option<Program>,
     ) -> Result<(), ChoiceError> {
+        if let Some(program) = &new_program {
+            program.validate().map_err(ChoiceError::ValidationError)?;
+            program
+                .typecheck(&get_wallet_candid_interface())
+                .await
+                .map_err(ChoiceError::ValidationError)?;
+            VotingService::assert_program_proposer_valid(program, id())
+                .map_err(ChoiceError::ValidationError)?;
+        }
+
         let mut choice = ChoiceService::get_choice(choice_id)?;
         let voting_id = match choice.get_voting_id() {
             RemoteVotingId::Common(id) => id,
@@ -84,11 +115,11 @@ impl ChoiceService {
         }
 
         if let Some(program) = &new_program {
-            program.validate().map_err(ChoiceError::ValidationError)?;
-
             if !VotingConfigService::does_program_fit(&vc, program) {
                 return Err(ChoiceError::ProgramNotAllowedByVotingConfig);
             }
+
+            ChoiceService::assert_program_fits_cycles_budget(program)?;
         }
 
         VotingService::reset_approval_choice(&voting);
@@ -149,4 +180,14 @@ impl ChoiceService {
     pub fn list_choices(page_req: &PageRequest<ChoiceFilter, ()>) -> Page<Choice> {
         Choice::repo().list(page_req)
     }
+
+    // a program which can never fit the union's cycles budget would fail after the voting anyway
+    fn assert_program_fits_cycles_budget(program: &Program) -> Result<(), ChoiceError> {
+        match Settings::get().get_cycles_budget() {
+            Some(budget) if program.get_total_cycles() > budget.amount => {
+                Err(ChoiceError::ProgramExceedsCyclesBudget)
+            }
+            _ => Ok(()),
+        }
+    }
 }
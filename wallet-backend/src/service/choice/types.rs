@@ -12,6 +12,7 @@ pub enum ChoiceError {
     VotingError(VotingError),
     UnableToEditVoting(VotingId),
     ProgramNotAllowedByVotingConfig,
+    ProgramExceedsCyclesBudget,
 }
 
 pub struct ChoiceService;
@@ -0,0 +1,94 @@
+use crate::repository::comment::model::Comment;
+use crate::repository::comment::types::{CommentFilter, CommentId};
+use crate::repository::voting::model::Voting;
+use crate::service::comment::types::{CommentError, CommentService};
+use candid::Principal;
+use shared::mvc::{HasRepository, Repository};
+use shared::pageable::{Page, PageRequest};
+use shared::types::wallet::VotingId;
+
+impl CommentService {
+    pub fn create_comment(
+        voting_id: VotingId,
+        parent_id: Option<CommentId>,
+        author: Principal,
+        text: String,
+        timestamp: u64,
+    ) -> Result<CommentId, CommentError> {
+        Voting::repo()
+            .get(&voting_id)
+            .ok_or(CommentError::VotingNotFound(voting_id))?;
+
+        CommentService::assert_profile_exists(&author)?;
+
+        if let Some(parent_id) = parent_id {
+            let parent = CommentService::get_comment(&parent_id)?;
+
+            if *parent.get_voting_id() != voting_id || parent.is_removed() {
+                return Err(CommentError::InvalidParentComment(parent_id));
+            }
+        }
+
+        let comment = Comment::new(voting_id, parent_id, author, text, timestamp)
+            .map_err(CommentError::ValidationError)?;
+
+        Ok(Comment::repo().save(comment))
+    }
+
+    pub fn update_comment(
+        id: &CommentId,
+        author: Principal,
+        new_text: String,
+        timestamp: u64,
+    ) -> Result<(), CommentError> {
+        let mut comment = CommentService::get_comment(id)?;
+        CommentService::assert_editable_by(&comment, &author)?;
+
+        comment
+            .update(new_text, timestamp)
+            .map_err(CommentError::ValidationError)?;
+        Comment::repo().save(comment);
+
+        Ok(())
+    }
+
+    pub fn delete_comment(
+        id: &CommentId,
+        author: Principal,
+        timestamp: u64,
+    ) -> Result<(), CommentError> {
+        let comment = CommentService::get_comment(id)?;
+        CommentService::assert_editable_by(&comment, &author)?;
+
+        CommentService::remove_comment(comment, timestamp);
+
+        Ok(())
+    }
+
+    pub fn moderate_comment(id: &CommentId, timestamp: u64) -> Result<(), CommentError> {
+        let comment = CommentService::get_comment(id)?;
+        if comment.is_removed() {
+            return Err(CommentError::CommentIsRemoved(*id));
+        }
+
+        CommentService::remove_comment(comment, timestamp);
+
+        Ok(())
+    }
+
+    pub fn delete_comments_of_voting(voting_id: &VotingId) {
+        Comment::repo().delete_all_by_voting(voting_id);
+    }
+
+    #[inline(always)]
+    pub fn get_comment(id: &CommentId) -> Result<Comment, CommentError> {
+        Comment::repo()
+            .get(id)
+            .ok_or(CommentError::CommentNotFound(*id))
+    }
+
+    #[inline(always)]
+    pub fn list_comments(page_req: &PageRequest<CommentFilter, ()>) -> Page<Comment> {
+        Comment::repo().list(page_req)
+    }
+}
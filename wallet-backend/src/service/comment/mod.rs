@@ -0,0 +1,41 @@
+use crate::repository::comment::model::Comment;
+use crate::repository::profile::model::Profile;
+use crate::service::comment::types::{CommentError, CommentService};
+use candid::Principal;
+use shared::mvc::{HasRepository, Model, Repository};
+
+pub mod crud;
+pub mod types;
+
+impl CommentService {
+    // comments with replies are only marked as removed, so the thread stays readable
+    fn remove_comment(mut comment: Comment, timestamp: u64) {
+        let id = comment.get_id().unwrap();
+
+        if Comment::repo().has_replies(*comment.get_voting_id(), id) {
+            comment.remove(timestamp);
+            Comment::repo().save(comment);
+        } else {
+            Comment::repo().delete(&id);
+        }
+    }
+
+    fn assert_profile_exists(author: &Principal) -> Result<(), CommentError> {
+        Profile::repo()
+            .get(author)
+            .map(|_| ())
+            .ok_or(CommentError::ProfileNotExists(*author))
+    }
+
+    fn assert_editable_by(comment: &Comment, author: &Principal) -> Result<(), CommentError> {
+        if comment.get_author() != *author {
+            return Err(CommentError::CallerIsNotAnAuthor);
+        }
+
+        if comment.is_removed() {
+            return Err(CommentError::CommentIsRemoved(comment.get_id().unwrap()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::repository::comment::types::CommentId;
+use candid::Principal;
+use shared::types::wallet::VotingId;
+use shared::validation::ValidationError;
+
+pub struct CommentService;
+
+#[derive(Debug)]
+pub enum CommentError {
+    ValidationError(ValidationError),
+    VotingNotFound(VotingId),
+    CommentNotFound(CommentId),
+    InvalidParentComment(CommentId),
+    ProfileNotExists(Principal),
+    CallerIsNotAnAuthor,
+    CommentIsRemoved(CommentId),
+}
@@ -1,21 +1,32 @@
+use crate::repository::access_config::model::AccessConfig;
 use crate::repository::choice::model::Choice;
+use crate::repository::vesting_grant::model::VestingGrant;
+use crate::repository::vesting_grant::types::VestingGrantId;
+use crate::repository::vote_escrow::model::VoteEscrowLock;
+use crate::repository::vote_escrow::types::VoteEscrowLockId;
 use crate::repository::voting::model::Voting;
 use crate::repository::voting_config::model::VotingConfig;
+use crate::service::access_config::types::AccessConfigService;
 use crate::service::events::EventsService;
+use crate::service::group::types::GroupService;
 use crate::service::voting::types::VotingService;
+use crate::settings::Settings;
 use crate::{cron_enqueue, cron_ready_tasks};
 use candid::{CandidType, Deserialize};
 use ic_cdk::api::time;
 use ic_cdk::spawn;
 use ic_cron::types::{Iterations, SchedulingOptions};
 use shared::mvc::{HasRepository, Model, Repository};
-use shared::types::wallet::{ProgramExecutedWith, VotingId};
+use shared::types::wallet::{AccessConfigId, ProgramExecutedWith, VotingId};
 
 #[derive(CandidType, Deserialize)]
 pub enum CronTaskKind {
     RoundStart(VotingId),
     RoundEnd(VotingId),
     VotingExecution(VotingId),
+    AccessConfigExpiration(AccessConfigId),
+    VestingRelease(VestingGrantId),
+    VoteEscrowExpiration(VoteEscrowLockId),
 }
 
 pub struct CronService;
@@ -63,6 +74,60 @@ impl CronService {
         voting.set_cron_task(task_id, timestamp);
     }
 
+    // returns false, if the access config never expires
+    pub fn schedule_access_config_expiration(ac: &mut AccessConfig, timestamp: u64) -> bool {
+        let not_after = match ac.get_validity().not_after {
+            Some(it) => it,
+            None => return false,
+        };
+
+        let task_id = cron_enqueue(
+            CronTaskKind::AccessConfigExpiration(ac.get_id().unwrap()),
+            SchedulingOptions {
+                // the config is still valid at the 'not_after' timestamp itself
+                delay_nano: not_after.saturating_sub(timestamp).saturating_add(1),
+                interval_nano: 0,
+                iterations: Iterations::Exact(1),
+            },
+        )
+        .expect("Unable to schedule a task");
+
+        ac.set_expiration_task(Some(task_id));
+
+        true
+    }
+
+    // ticks at the end of the cliff and then at each unlock step, until the grant is finished
+    pub fn schedule_vesting_release(grant: &mut VestingGrant, timestamp: u64) {
+        let schedule = grant.get_schedule();
+
+        let task_id = cron_enqueue(
+            CronTaskKind::VestingRelease(grant.get_id().unwrap()),
+            SchedulingOptions {
+                delay_nano: schedule.get_cliff_end().saturating_sub(timestamp),
+                interval_nano: schedule.period,
+                iterations: Iterations::Infinite,
+            },
+        )
+        .expect("Unable to schedule a task");
+
+        grant.set_release_task(Some(task_id));
+    }
+
+    pub fn schedule_vote_escrow_expiration(lock: &mut VoteEscrowLock, timestamp: u64) {
+        let task_id = cron_enqueue(
+            CronTaskKind::VoteEscrowExpiration(lock.get_id().unwrap()),
+            SchedulingOptions {
+                delay_nano: lock.get_unlocks_at().saturating_sub(timestamp),
+                interval_nano: 0,
+                iterations: Iterations::Exact(1),
+            },
+        )
+        .expect("Unable to schedule a task");
+
+        lock.set_expiration_task(Some(task_id));
+    }
+
     pub fn process_tasks() {
         let timestamp = time();
 
@@ -88,9 +153,22 @@ impl CronService {
                         .unwrap();
 
                     VotingService::try_finish_voting(&mut voting, &vc, timestamp);
+                    VotingService::settle_proposal_bond(&mut voting, timestamp);
 
                     Voting::repo().save(voting);
                 }
+                CronTaskKind::AccessConfigExpiration(access_config_id) => {
+                    AccessConfigService::deactivate_expired_access_config(
+                        &access_config_id,
+                        timestamp,
+                    );
+                }
+                CronTaskKind::VestingRelease(grant_id) => {
+                    GroupService::release_vested_shares(&grant_id, timestamp);
+                }
+                CronTaskKind::VoteEscrowExpiration(lock_id) => {
+                    GroupService::expire_vote_escrow_lock(&lock_id);
+                }
                 CronTaskKind::VotingExecution(voting_id) => spawn(async move {
                     let voting = Voting::repo().get(&voting_id).unwrap();
 
@@ -102,6 +180,10 @@ impl CronService {
                         {
                             let timestamp = time();
                             let program = choice.get_program().clone();
+
+                            // an accepted decision is not blocked by the budget - choice programs
+                            // are checked against it on creation, here the spending is only recorded
+                            Settings::get().spend_cycles(program.get_total_cycles(), timestamp);
                             let result = program.execute().await;
 
                             EventsService::emit_program_executed_event(
@@ -109,6 +191,7 @@ impl CronService {
                                 ProgramExecutedWith::WithVotingConfig(
                                     *voting.get_voting_config_id(),
                                 ),
+                                Vec::new(),
                                 program,
                                 result,
                                 timestamp,
@@ -1,6 +1,14 @@
 use crate::repository::nested_voting::types::RemoteVotingId;
 use crate::repository::voting::types::RoundResult;
 use ic_event_hub_macros::Event;
+use shared::types::wallet::AccessConfigId;
+
+#[derive(Event)]
+pub struct AccessConfigExpiredEvent {
+    #[topic]
+    pub access_config_id: AccessConfigId,
+    pub timestamp: u64,
+}
 
 #[derive(Event)]
 pub struct VotingRoundStartEvent {
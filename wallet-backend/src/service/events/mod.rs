@@ -3,8 +3,8 @@ use crate::repository::nested_voting::model::NestedVoting;
 use crate::repository::nested_voting::types::RemoteVotingId;
 use crate::repository::voting::types::RoundResult;
 use crate::service::events::events::{
-    VotingRoundEndEvent, VotingRoundEndEventFilter, VotingRoundStartEvent,
-    VotingRoundStartEventFilter,
+    AccessConfigExpiredEvent, VotingRoundEndEvent, VotingRoundEndEventFilter,
+    VotingRoundStartEvent, VotingRoundStartEventFilter,
 };
 use candid::Principal;
 use ic_cdk::print;
@@ -15,9 +15,9 @@ use shared::candid::{CandidCallResult, CandidRejectionCode};
 use shared::mvc::{HasRepository, Model, Repository};
 use shared::remote_call::{Program, ProgramExecutionResult};
 use shared::types::wallet::{
-    GroupId, PrincipalShareholder, ProfileActivatedEvent, ProfileCreatedEvent,
-    ProgramExecutedEvent_0, ProgramExecutedEvent_1, ProgramExecutedEvent_2, ProgramExecutedWith,
-    Shareholder, Shares, SharesMoveEvent, TotalSupplyUpdatedEvent,
+    AccessConfigId, GroupId, PrincipalShareholder, ProfileActivatedEvent, ProfileCreatedEvent,
+    ProgramExecutedEvent_0, ProgramExecutedEvent_1, ProgramExecutedEvent_2, ProgramExecutedEvent_3,
+    ProgramExecutedWith, Shareholder, Shares, SharesMoveEvent, TotalSupplyUpdatedEvent,
 };
 
 pub mod events;
@@ -28,6 +28,7 @@ impl EventsService {
     pub fn emit_program_executed_event(
         initiator: Principal,
         with: ProgramExecutedWith,
+        approvers: Vec<Principal>,
         program: Program,
         result: ProgramExecutionResult,
         timestamp: u64,
@@ -44,6 +45,24 @@ impl EventsService {
             .expect("Unable to emit program executed event part 2: ");
         emit(ProgramExecutedEvent_2 { timestamp, result })
             .expect("Unable to emit program executed event part 3: ");
+
+        if !approvers.is_empty() {
+            emit(ProgramExecutedEvent_3 {
+                timestamp,
+                approvers,
+            })
+            .expect("Unable to emit program executed event part 4: ");
+        }
+    }
+
+    pub fn emit_access_config_expired_event(access_config_id: AccessConfigId, timestamp: u64) {
+        print("emit_access_config_expired_event()");
+
+        emit(AccessConfigExpiredEvent {
+            access_config_id,
+            timestamp,
+        })
+        .expect("Unable to emit access config expired event: ");
     }
 
     pub fn emit_shares_mint_event(
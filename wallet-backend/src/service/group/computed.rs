@@ -0,0 +1,152 @@
+use crate::repository::group::model::Group;
+use crate::repository::group::types::GroupRule;
+use crate::repository::token::model::Token;
+use crate::repository::token::types::ChoiceOrGroup;
+use crate::service::events::EventsService;
+use crate::service::group::types::{GroupError, GroupService, DEFAULT_GROUP_SHARES};
+use crate::service::token::types::TokenService;
+use candid::Principal;
+use shared::mvc::{HasRepository, Model, Repository};
+use shared::types::wallet::{GroupId, Shares};
+use std::collections::BTreeSet;
+
+impl GroupService {
+    pub fn create_computed_group(
+        name: String,
+        description: String,
+        rule: GroupRule,
+        timestamp: u64,
+    ) -> Result<GroupId, GroupError> {
+        GroupService::assert_rule_sources_valid(&rule, None)?;
+
+        let group =
+            Group::new_computed(name, description, rule).map_err(GroupError::ValidationError)?;
+        let id = Group::repo().save(group);
+
+        let token_id = TokenService::create_token(ChoiceOrGroup::Group(id), false, false);
+        let mut group = Group::repo().get(&id).unwrap();
+        group.init_token(token_id);
+        Group::repo().save(group.clone());
+
+        let principals: Vec<_> = GroupService::list_rule_source_holders(group.get_rule().unwrap())
+            .into_iter()
+            .collect();
+        GroupService::recompute_group(&group, &principals, timestamp);
+
+        Ok(id)
+    }
+
+    pub fn update_computed_group_rule(
+        group_id: GroupId,
+        new_rule: GroupRule,
+        timestamp: u64,
+    ) -> Result<(), GroupError> {
+        let mut group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_computed(&group)?;
+        GroupService::assert_rule_sources_valid(&new_rule, Some(group_id))?;
+
+        // current members could drop out, new ones could join
+        let mut principals = GroupService::get_token(&group).list_holders();
+        principals.extend(GroupService::list_rule_source_holders(&new_rule));
+
+        group
+            .set_rule(new_rule)
+            .map_err(GroupError::ValidationError)?;
+        Group::repo().save(group.clone());
+
+        let principals: Vec<_> = principals.into_iter().collect();
+        GroupService::recompute_group(&group, &principals, timestamp);
+
+        Ok(())
+    }
+
+    // should be invoked each time balances of a regular group change
+    pub fn recompute_dependent_groups(group_id: GroupId, principals: &[Principal], timestamp: u64) {
+        for id in Group::repo().get_computed_groups_depending_on(&group_id) {
+            let group = Group::repo().get(&id).unwrap();
+            GroupService::recompute_group(&group, principals, timestamp);
+        }
+    }
+
+    fn recompute_group(group: &Group, principals: &[Principal], timestamp: u64) {
+        let group_id = group.get_id().unwrap();
+        let rule = group.get_rule().unwrap();
+        let member_balance = Shares::from(DEFAULT_GROUP_SHARES);
+        let zero = Shares::default();
+
+        let mut token = GroupService::get_token(group);
+
+        for principal in principals {
+            let new_balance = rule.compute(&member_balance, |source_id| {
+                // unwrapping, because sources can't be deleted while referenced
+                let source = Group::repo().get(source_id).unwrap();
+                GroupService::get_token(&source).balance_of(principal)
+            });
+
+            let balance = token.balance_of(principal);
+            if new_balance == balance {
+                continue;
+            }
+
+            token.rebalance(*principal, new_balance.clone());
+            let total_supply = token.total_supply();
+
+            if balance == zero {
+                Token::repo().add_to_principal_index(*principal, token.get_id().unwrap());
+            } else if new_balance == zero {
+                Token::repo().remove_from_principal_index(principal, &token.get_id().unwrap());
+            }
+
+            if new_balance > balance {
+                EventsService::emit_shares_mint_event(
+                    group_id,
+                    *principal,
+                    new_balance.clone() - balance,
+                    new_balance,
+                    total_supply,
+                    timestamp,
+                );
+            } else {
+                EventsService::emit_shares_burn_event(
+                    group_id,
+                    *principal,
+                    balance - new_balance.clone(),
+                    new_balance,
+                    total_supply,
+                    timestamp,
+                );
+            }
+        }
+
+        Token::repo().save(token);
+    }
+
+    fn list_rule_source_holders(rule: &GroupRule) -> BTreeSet<Principal> {
+        rule.list_groups()
+            .iter()
+            .flat_map(|id| {
+                let source = Group::repo().get(id).unwrap();
+                GroupService::get_token(&source).list_holders()
+            })
+            .collect()
+    }
+
+    // computed groups can't depend on each other, so there are no cycles and recomputation order
+    fn assert_rule_sources_valid(
+        rule: &GroupRule,
+        this_group_id: Option<GroupId>,
+    ) -> Result<(), GroupError> {
+        for id in rule.list_groups() {
+            if Some(id) == this_group_id {
+                return Err(GroupError::InvalidGroupRuleSource(id));
+            }
+
+            let source = GroupService::get_group(id)?.it;
+            if source.is_computed() {
+                return Err(GroupError::InvalidGroupRuleSource(id));
+            }
+        }
+
+        Ok(())
+    }
+}
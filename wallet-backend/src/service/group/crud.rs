@@ -1,6 +1,7 @@
 use crate::controller::group::api::GroupExt;
 use crate::repository::access_config::model::AccessConfig;
 use crate::repository::group::model::Group;
+use crate::repository::group::types::VoteEscrowSettings;
 use crate::repository::token::model::Token;
 use crate::repository::token::types::ChoiceOrGroup;
 use crate::repository::voting_config::model::VotingConfig;
@@ -16,8 +17,16 @@ impl GroupService {
         description: String,
         private: bool,
         transferable: bool,
+        parent: Option<GroupId>,
     ) -> Result<GroupId, GroupError> {
-        let group = Group::new(name, description, private).map_err(GroupError::ValidationError)?;
+        if let Some(parent) = parent {
+            GroupService::get_group(parent)?;
+        }
+
+        let mut group =
+            Group::new(name, description, private).map_err(GroupError::ValidationError)?;
+        group.set_parent(parent);
+
         let id = Group::repo().save(group);
 
         let token_id = TokenService::create_token(ChoiceOrGroup::Group(id), private, transferable);
@@ -36,6 +45,15 @@ impl GroupService {
         if VotingConfig::repo().group_has_related_voting_configs(&group_id) {
             return Err(GroupError::RelatedVotingConfigsExist);
         }
+        if !Group::repo()
+            .get_computed_groups_depending_on(&group_id)
+            .is_empty()
+        {
+            return Err(GroupError::RelatedComputedGroupsExist);
+        }
+        if !Group::repo().get_children(&group_id).is_empty() {
+            return Err(GroupError::GroupHasChildren(group_id));
+        }
 
         // TODO: check for existing nested voting configs
 
@@ -44,6 +62,8 @@ impl GroupService {
             .ok_or(GroupError::GroupNotFound(group_id))?;
 
         let token = Token::repo().delete(&group.get_token()).unwrap();
+        GroupService::delete_vesting_grants_of(&group_id);
+        GroupService::delete_vote_escrow_locks_of(&group.get_token());
 
         Ok((group, token))
     }
@@ -52,12 +72,18 @@ impl GroupService {
         group_id: GroupId,
         new_name: Option<String>,
         new_description: Option<String>,
+        new_vote_escrow: Option<Option<VoteEscrowSettings>>,
+        new_parent: Option<Option<GroupId>>,
     ) -> Result<(), GroupError> {
         let mut group = GroupService::get_group(group_id)?;
 
+        if let Some(parent) = new_parent {
+            GroupService::set_group_parent(&mut group.it, parent)?;
+        }
+
         group
             .it
-            .update(new_name, new_description)
+            .update(new_name, new_description, new_vote_escrow)
             .map_err(GroupError::ValidationError)?;
 
         Group::repo().save(group.it);
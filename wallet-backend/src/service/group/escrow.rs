@@ -0,0 +1,142 @@
+use crate::cron_dequeue;
+use crate::repository::group::model::Group;
+use crate::repository::token::model::Token;
+use crate::repository::token::types::TokenId;
+use crate::repository::vote_escrow::model::VoteEscrowLock;
+use crate::repository::vote_escrow::types::{VoteEscrowLockFilter, VoteEscrowLockId};
+use crate::service::cron::CronService;
+use crate::service::group::types::{GroupError, GroupService};
+use candid::Principal;
+use ic_cdk::api::print;
+use shared::mvc::{HasRepository, Repository};
+use shared::pageable::{Page, PageRequest};
+use shared::types::wallet::{GroupId, Shares};
+use shared::validation::ValidationError;
+
+impl GroupService {
+    // the locked shares can't be transferred or burned, until the lock expires
+    pub fn lock_shares_for_voting_power(
+        group_id: GroupId,
+        owner: Principal,
+        qty: Shares,
+        duration: u64,
+        timestamp: u64,
+    ) -> Result<VoteEscrowLockId, GroupError> {
+        GroupService::assert_not_has_profile_group(group_id)?;
+
+        let group = GroupService::get_group(group_id)?.it;
+        let settings = group
+            .get_vote_escrow()
+            .ok_or(GroupError::VoteEscrowDisabled(group_id))?;
+
+        if qty == Shares::default() {
+            return Err(GroupError::ValidationError(ValidationError(String::from(
+                "Vote escrow lock should contain some shares",
+            ))));
+        }
+
+        let boost = settings
+            .calc_boost(&qty, duration)
+            .map_err(GroupError::ValidationError)?;
+
+        GroupService::lock_shares(group_id, owner, qty.clone())?;
+
+        let lock = VoteEscrowLock::new(
+            group.get_token(),
+            owner,
+            qty,
+            boost,
+            timestamp,
+            timestamp.saturating_add(duration),
+        );
+        let id = VoteEscrowLock::repo().save(lock);
+
+        let mut lock = VoteEscrowLock::repo().get(&id).unwrap();
+        CronService::schedule_vote_escrow_expiration(&mut lock, timestamp);
+        VoteEscrowLock::repo().save(lock);
+
+        Ok(id)
+    }
+
+    // invoked by the expiration task, does nothing if the lock is already gone; the lock is
+    // expired anyway, if shares can't be unlocked (e.g. the group was deleted)
+    pub fn expire_vote_escrow_lock(id: &VoteEscrowLockId) {
+        let mut lock = match VoteEscrowLock::repo().get(id) {
+            Some(it) if !it.is_expired() => it,
+            _ => return,
+        };
+
+        match Token::repo().get(lock.get_token_id()) {
+            Some(mut token) => match token.unlock(*lock.get_owner(), lock.get_qty().clone()) {
+                Ok(_) => {
+                    Token::repo().save(token);
+                }
+                Err(e) => print(format!("Unable to unlock vote escrow shares: {:?}", e)),
+            },
+            None => print(format!(
+                "Unable to unlock vote escrow shares: token {} not found",
+                lock.get_token_id()
+            )),
+        }
+
+        lock.expire();
+        VoteEscrowLock::repo().save(lock);
+    }
+
+    // voting power = shares + boosts of the owner's locks, active at the moment
+    pub fn get_group_voting_power_of(
+        group_id: GroupId,
+        owner: &Principal,
+        timestamp: u64,
+    ) -> Result<Shares, GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let token = GroupService::get_token(&group);
+
+        let boost = VoteEscrowLock::repo().get_boost_at(&group.get_token(), Some(owner), timestamp);
+
+        Ok(token.balance_of(owner) + boost)
+    }
+
+    pub fn get_total_group_voting_power(
+        group_id: GroupId,
+        timestamp: u64,
+    ) -> Result<Shares, GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let token = GroupService::get_token(&group);
+
+        let boost = VoteEscrowLock::repo().get_boost_at(&group.get_token(), None, timestamp);
+
+        Ok(token.total_supply() + boost)
+    }
+
+    // an extra to the balance (or the total supply, if the owner is not set) from a shares info,
+    // zero for deleted groups
+    pub fn get_voting_power_boost_at(
+        group_id: GroupId,
+        owner: Option<&Principal>,
+        timestamp: u64,
+    ) -> Shares {
+        match Group::repo().get(&group_id) {
+            Some(group) => {
+                VoteEscrowLock::repo().get_boost_at(&group.get_token(), owner, timestamp)
+            }
+            None => Shares::default(),
+        }
+    }
+
+    pub fn list_vote_escrow_locks(
+        page_req: &PageRequest<VoteEscrowLockFilter, ()>,
+    ) -> Page<VoteEscrowLock> {
+        VoteEscrowLock::repo().list(page_req)
+    }
+
+    pub fn delete_vote_escrow_locks_of(token_id: &TokenId) {
+        for id in VoteEscrowLock::repo().get_locks_by_token(token_id) {
+            let lock = VoteEscrowLock::repo().delete(&id).unwrap();
+
+            if let Some(task_id) = lock.get_expiration_task() {
+                cron_dequeue(task_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::repository::group::model::Group;
+use crate::service::group::types::{GroupError, GroupService};
+use shared::mvc::{HasRepository, Repository};
+use shared::types::wallet::GroupId;
+use std::collections::BTreeSet;
+
+impl GroupService {
+    pub fn get_group_descendants(group_id: &GroupId) -> BTreeSet<GroupId> {
+        Group::repo().get_descendants(group_id)
+    }
+
+    pub fn list_group_descendants(group_id: GroupId) -> Result<Vec<GroupId>, GroupError> {
+        GroupService::get_group(group_id)?;
+
+        Ok(GroupService::get_group_descendants(&group_id)
+            .into_iter()
+            .collect())
+    }
+
+    pub fn list_group_ancestors(group_id: GroupId) -> Result<Vec<GroupId>, GroupError> {
+        GroupService::get_group(group_id)?;
+
+        Ok(Group::repo().get_ancestors(&group_id))
+    }
+
+    fn assert_parent_valid(group_id: GroupId, parent: GroupId) -> Result<(), GroupError> {
+        GroupService::get_group(parent)?;
+
+        if parent == group_id || GroupService::get_group_descendants(&group_id).contains(&parent) {
+            Err(GroupError::InvalidGroupParent(parent))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn set_group_parent(group: &mut Group, parent: Option<GroupId>) -> Result<(), GroupError> {
+        if let Some(parent) = parent {
+            GroupService::assert_parent_valid(group.get_id().unwrap(), parent)?;
+        }
+
+        group.set_parent(parent);
+
+        Ok(())
+    }
+}
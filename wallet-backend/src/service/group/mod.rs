@@ -10,8 +10,12 @@ use shared::mvc::{HasRepository, Model, Repository};
 use shared::pageable::{Page, PageRequest};
 use shared::types::wallet::{GroupId, Shares};
 
+pub mod computed;
 pub mod crud;
+pub mod escrow;
+pub mod hierarchy;
 pub mod types;
+pub mod vesting;
 
 impl GroupService {
     pub fn init_has_profile_group() {
@@ -22,6 +26,7 @@ impl GroupService {
             ),
             true,
             false,
+            None,
         )
         .unwrap();
 
@@ -41,6 +46,8 @@ impl GroupService {
         GroupService::assert_not_has_profile_group(group_id)?;
 
         let group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_not_computed(&group)?;
+
         let mut token = GroupService::get_token(&group);
 
         let zero = Shares::default();
@@ -70,6 +77,10 @@ impl GroupService {
 
         Token::repo().save(token);
 
+        if !group.is_private() {
+            GroupService::recompute_dependent_groups(group_id, &[owner], timestamp);
+        }
+
         Ok(())
     }
 
@@ -82,6 +93,8 @@ impl GroupService {
         GroupService::assert_not_has_profile_group(group_id)?;
 
         let group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_not_computed(&group)?;
+
         let mut token = GroupService::get_token(&group);
 
         if group.is_private() {
@@ -110,6 +123,8 @@ impl GroupService {
             timestamp,
         );
 
+        GroupService::recompute_dependent_groups(group_id, &[owner], timestamp);
+
         Ok(())
     }
 
@@ -125,6 +140,7 @@ impl GroupService {
         let group = GroupService::get_group(group_id)?.it;
         let mut token = GroupService::get_token(&group);
 
+        GroupService::assert_not_computed(&group)?;
         GroupService::assert_transferable(&group, &token)?;
 
         if group.is_private() {
@@ -163,6 +179,8 @@ impl GroupService {
             timestamp,
         );
 
+        GroupService::recompute_dependent_groups(group_id, &[from, to], timestamp);
+
         Ok(())
     }
 
@@ -201,6 +219,8 @@ impl GroupService {
             EventsService::emit_profile_activated_event(owner);
         }
 
+        GroupService::recompute_dependent_groups(group_id, &[owner], timestamp);
+
         Ok(())
     }
 
@@ -227,6 +247,121 @@ impl GroupService {
         Ok(())
     }
 
+    pub fn lock_shares(group_id: GroupId, owner: Principal, qty: Shares) -> Result<(), GroupError> {
+        GroupService::assert_not_has_profile_group(group_id)?;
+
+        let group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_not_computed(&group)?;
+
+        let mut token = GroupService::get_token(&group);
+
+        token
+            .lock(owner, qty)
+            .map_err(GroupError::ValidationError)?;
+
+        Token::repo().save(token);
+
+        Ok(())
+    }
+
+    pub fn unlock_shares(
+        group_id: GroupId,
+        owner: Principal,
+        qty: Shares,
+    ) -> Result<(), GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let mut token = GroupService::get_token(&group);
+
+        token
+            .unlock(owner, qty)
+            .map_err(GroupError::ValidationError)?;
+
+        Token::repo().save(token);
+
+        Ok(())
+    }
+
+    pub fn burn_locked_shares(
+        group_id: GroupId,
+        owner: Principal,
+        qty: Shares,
+        timestamp: u64,
+    ) -> Result<(), GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let mut token = GroupService::get_token(&group);
+
+        token
+            .burn_locked(owner, qty.clone())
+            .map_err(GroupError::ValidationError)?;
+
+        let new_balance = token.balance_of(&owner);
+        let total_supply = token.total_supply();
+
+        if new_balance.clone() + token.unaccepted_balance_of(&owner) == Shares::default() {
+            Token::repo().remove_from_principal_index(&owner, &token.get_id().unwrap());
+        }
+
+        Token::repo().save(token);
+
+        EventsService::emit_shares_burn_event(
+            group_id,
+            owner,
+            qty,
+            new_balance,
+            total_supply,
+            timestamp,
+        );
+
+        GroupService::recompute_dependent_groups(group_id, &[owner], timestamp);
+
+        Ok(())
+    }
+
+    pub fn transfer_locked_shares(
+        group_id: GroupId,
+        from: Principal,
+        to: Principal,
+        qty: Shares,
+        timestamp: u64,
+    ) -> Result<(), GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let mut token = GroupService::get_token(&group);
+
+        let zero = Shares::default();
+        let prev_to_balance = token.balance_of(&to) + token.unaccepted_balance_of(&to);
+
+        token
+            .burn_locked(from, qty.clone())
+            .map_err(GroupError::ValidationError)?;
+        token.mint(to, qty.clone());
+
+        let from_balance = token.balance_of(&from);
+        let to_balance = token.balance_of(&to);
+
+        if from_balance.clone() + token.unaccepted_balance_of(&from) == zero {
+            Token::repo().remove_from_principal_index(&from, &token.get_id().unwrap());
+        }
+        if prev_to_balance == zero && to_balance > zero {
+            Token::repo().add_to_principal_index(to, token.get_id().unwrap());
+        }
+
+        Token::repo().save(token);
+
+        EventsService::emit_shares_transfer_event(
+            group_id,
+            from,
+            to,
+            qty,
+            from_balance,
+            to_balance,
+            timestamp,
+        );
+
+        GroupService::recompute_dependent_groups(group_id, &[from, to], timestamp);
+
+        Ok(())
+    }
+
     pub fn get_groups_of(caller: &Principal) -> Vec<GroupExt> {
         Token::repo()
             .get_tokens_by_principal(caller)
@@ -260,6 +395,16 @@ impl GroupService {
         Ok(token.balance_of(owner))
     }
 
+    pub fn get_locked_group_shares_balance_of(
+        group_id: GroupId,
+        owner: &Principal,
+    ) -> Result<Shares, GroupError> {
+        let group = GroupService::get_group(group_id)?.it;
+        let token = GroupService::get_token(&group);
+
+        Ok(token.locked_balance_of(owner))
+    }
+
     pub fn get_unaccepted_group_shares_balance_of(
         group_id: GroupId,
         owner: &Principal,
@@ -310,26 +455,68 @@ impl GroupService {
         Ok(token.unaccepted_balances(page_req))
     }
 
-    // unused, but maybe one day...
-    fn convert_to_private(group: &mut Group, token: &mut Token) -> Result<(), GroupError> {
-        GroupService::assert_not_has_profile_group(group.get_id().unwrap())?;
+    // unlocked shares of every member become unaccepted, so members have to accept them again
+    pub fn convert_to_private(group_id: GroupId, timestamp: u64) -> Result<(), GroupError> {
+        GroupService::assert_not_has_profile_group(group_id)?;
+
+        let mut group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_not_computed(&group)?;
         GroupService::assert_public(&group)?;
+
+        let mut token = GroupService::get_token(&group);
+
         group.set_private(true);
-        token.make_acceptable();
+        let changes = token.make_acceptable();
+
+        for change in &changes {
+            EventsService::emit_shares_burn_event(
+                group_id,
+                change.owner,
+                change.qty.clone(),
+                change.new_balance.clone(),
+                change.total_supply.clone(),
+                timestamp,
+            );
+        }
+
+        Group::repo().save(group);
+        Token::repo().save(token);
 
-        // TODO: sends correct events
+        let owners: Vec<_> = changes.into_iter().map(|it| it.owner).collect();
+        GroupService::recompute_dependent_groups(group_id, &owners, timestamp);
 
         Ok(())
     }
 
-    // unused, but maybe one day...
-    fn convert_to_public(group: &mut Group, token: &mut Token) -> Result<(), GroupError> {
-        GroupService::assert_not_has_profile_group(group.get_id().unwrap())?;
+    // all unaccepted shares are accepted on behalf of their owners
+    pub fn convert_to_public(group_id: GroupId, timestamp: u64) -> Result<(), GroupError> {
+        GroupService::assert_not_has_profile_group(group_id)?;
+
+        let mut group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_not_computed(&group)?;
         GroupService::assert_private(&group)?;
+
+        let mut token = GroupService::get_token(&group);
+
         group.set_private(false);
-        token.make_not_acceptable();
+        let changes = token.make_not_acceptable();
 
-        // TODO: send correct events
+        for change in &changes {
+            EventsService::emit_shares_mint_event(
+                group_id,
+                change.owner,
+                change.qty.clone(),
+                change.new_balance.clone(),
+                change.total_supply.clone(),
+                timestamp,
+            );
+        }
+
+        Group::repo().save(group);
+        Token::repo().save(token);
+
+        let owners: Vec<_> = changes.into_iter().map(|it| it.owner).collect();
+        GroupService::recompute_dependent_groups(group_id, &owners, timestamp);
 
         Ok(())
     }
@@ -354,6 +541,22 @@ impl GroupService {
         }
     }
 
+    fn assert_computed(group: &Group) -> Result<(), GroupError> {
+        if !group.is_computed() {
+            Err(GroupError::GroupIsNotComputed(group.get_id().unwrap()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn assert_not_computed(group: &Group) -> Result<(), GroupError> {
+        if group.is_computed() {
+            Err(GroupError::GroupIsComputed(group.get_id().unwrap()))
+        } else {
+            Ok(())
+        }
+    }
+
     fn assert_transferable(group: &Group, token: &Token) -> Result<(), GroupError> {
         if !token.is_transferable() {
             Err(GroupError::GroupSharesAreNotTransferable(
@@ -1,3 +1,4 @@
+use crate::repository::vesting_grant::types::VestingGrantId;
 use candid::Principal;
 use shared::mvc::ZERO_ID;
 use shared::types::wallet::GroupId;
@@ -20,4 +21,14 @@ pub enum GroupError {
     UnableToEditHasProfileGroup,
     RelatedAccessConfigsExist,
     RelatedVotingConfigsExist,
+    VestingGrantNotFound(VestingGrantId),
+    VestingGrantIsNotRevocable(VestingGrantId),
+    VestingGrantIsFinished(VestingGrantId),
+    VoteEscrowDisabled(GroupId),
+    GroupIsComputed(GroupId),
+    GroupIsNotComputed(GroupId),
+    InvalidGroupRuleSource(GroupId),
+    RelatedComputedGroupsExist,
+    InvalidGroupParent(GroupId),
+    GroupHasChildren(GroupId),
 }
@@ -0,0 +1,181 @@
+use crate::controller::group::api::VestingGrantExt;
+use crate::cron_dequeue;
+use crate::repository::vesting_grant::model::VestingGrant;
+use crate::repository::vesting_grant::types::{
+    VestingGrantFilter, VestingGrantId, VestingSchedule,
+};
+use crate::service::cron::CronService;
+use crate::service::group::types::{GroupError, GroupService};
+use candid::Principal;
+use ic_cdk::api::print;
+use shared::mvc::{HasRepository, Repository};
+use shared::pageable::{Page, PageRequest};
+use shared::types::wallet::{GroupId, Shares};
+
+impl GroupService {
+    // private groups are not supported - their shares have to be accepted by the beneficiary first
+    pub fn create_vesting_grant(
+        group_id: GroupId,
+        beneficiary: Principal,
+        qty: Shares,
+        schedule: VestingSchedule,
+        revocable: bool,
+        voting_power: bool,
+        timestamp: u64,
+    ) -> Result<VestingGrantId, GroupError> {
+        GroupService::assert_not_has_profile_group(group_id)?;
+
+        let group = GroupService::get_group(group_id)?.it;
+        GroupService::assert_public(&group)?;
+        GroupService::assert_not_computed(&group)?;
+
+        let grant = VestingGrant::new(
+            group_id,
+            beneficiary,
+            qty.clone(),
+            schedule,
+            revocable,
+            voting_power,
+            timestamp,
+        )
+        .map_err(GroupError::ValidationError)?;
+
+        // shares with voting power are minted right away, so the history ledger counts them
+        if voting_power {
+            GroupService::mint_shares(group_id, beneficiary, qty.clone(), timestamp)?;
+            GroupService::lock_shares(group_id, beneficiary, qty)?;
+        }
+
+        let id = VestingGrant::repo().save(grant);
+
+        let mut grant = VestingGrant::repo().get(&id).unwrap();
+        CronService::schedule_vesting_release(&mut grant, timestamp);
+        VestingGrant::repo().save(grant);
+
+        Ok(id)
+    }
+
+    pub fn revoke_vesting_grant(id: &VestingGrantId, timestamp: u64) -> Result<(), GroupError> {
+        let mut grant = GroupService::get_vesting_grant(id)?;
+
+        if !grant.is_revocable() {
+            return Err(GroupError::VestingGrantIsNotRevocable(*id));
+        }
+        if grant.is_finished() {
+            return Err(GroupError::VestingGrantIsFinished(*id));
+        }
+
+        // the beneficiary keeps everything vested before the revocation
+        GroupService::release_vesting_grant(&mut grant, timestamp)?;
+        grant.revoke(timestamp);
+
+        let unvested = grant.unvested_at(timestamp);
+        if grant.has_voting_power() && unvested > Shares::default() {
+            GroupService::burn_locked_shares(
+                *grant.get_group_id(),
+                *grant.get_beneficiary(),
+                unvested,
+                timestamp,
+            )?;
+        }
+
+        GroupService::finish_vesting_grant(grant);
+
+        Ok(())
+    }
+
+    // invoked by the release task, does nothing if the grant is already gone; the task is
+    // dropped, if shares can't be released (e.g. locked shares were burned as a proposal bond)
+    pub fn release_vested_shares(id: &VestingGrantId, timestamp: u64) {
+        let mut grant = match VestingGrant::repo().get(id) {
+            Some(it) => it,
+            None => return,
+        };
+
+        if let Err(e) = GroupService::release_vesting_grant(&mut grant, timestamp) {
+            print(format!("Unable to release vested shares: {:?}", e));
+            GroupService::finish_vesting_grant(grant);
+
+            return;
+        }
+
+        if grant.is_finished() {
+            GroupService::finish_vesting_grant(grant);
+        } else {
+            VestingGrant::repo().save(grant);
+        }
+    }
+
+    pub fn get_vesting_grant(id: &VestingGrantId) -> Result<VestingGrant, GroupError> {
+        VestingGrant::repo()
+            .get(id)
+            .ok_or(GroupError::VestingGrantNotFound(*id))
+    }
+
+    pub fn get_vesting_grant_ext(
+        id: &VestingGrantId,
+        timestamp: u64,
+    ) -> Result<VestingGrantExt, GroupError> {
+        let it = GroupService::get_vesting_grant(id)?;
+        let vested = it.vested_at(timestamp);
+
+        Ok(VestingGrantExt { it, vested })
+    }
+
+    pub fn list_vesting_grants(
+        page_req: &PageRequest<VestingGrantFilter, ()>,
+        timestamp: u64,
+    ) -> Page<VestingGrantExt> {
+        let page = VestingGrant::repo().list(page_req);
+        let data = page
+            .data
+            .into_iter()
+            .map(|it| {
+                let vested = it.vested_at(timestamp);
+                VestingGrantExt { it, vested }
+            })
+            .collect();
+
+        Page::new(data, page.has_next)
+    }
+
+    pub fn delete_vesting_grants_of(group_id: &GroupId) {
+        for id in VestingGrant::repo().get_grants_by_group(group_id) {
+            let grant = VestingGrant::repo().delete(&id).unwrap();
+
+            if let Some(task_id) = grant.get_release_task() {
+                cron_dequeue(task_id);
+            }
+        }
+    }
+
+    // vested shares are unlocked, if they were minted upfront, or minted otherwise
+    fn release_vesting_grant(grant: &mut VestingGrant, timestamp: u64) -> Result<(), GroupError> {
+        let qty = grant.releasable_at(timestamp);
+        if qty == Shares::default() {
+            return Ok(());
+        }
+
+        let group_id = *grant.get_group_id();
+        let beneficiary = *grant.get_beneficiary();
+
+        if grant.has_voting_power() {
+            GroupService::unlock_shares(group_id, beneficiary, qty.clone())?;
+        } else {
+            GroupService::mint_shares(group_id, beneficiary, qty.clone(), timestamp)?;
+        }
+
+        grant.release(qty);
+
+        Ok(())
+    }
+
+    fn finish_vesting_grant(mut grant: VestingGrant) {
+        if let Some(task_id) = grant.get_release_task() {
+            cron_dequeue(task_id);
+            grant.set_release_task(None);
+        }
+
+        VestingGrant::repo().save(grant);
+    }
+}
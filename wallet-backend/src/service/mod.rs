@@ -1,5 +1,6 @@
 pub mod access_config;
 pub mod choice;
+pub mod comment;
 pub mod cron;
 pub mod events;
 pub mod group;
@@ -10,7 +10,8 @@ use crate::repository::voting_config::types::Fraction;
 use crate::service::choice::types::ChoiceService;
 use crate::service::nested_voting::types::{NestedVotingError, NestedVotingService};
 use crate::service::nested_voting_config::types::NestedVotingConfigService;
-use crate::service::voting::types::{MultiChoiceVote, Vote};
+use crate::service::voting::types::{CommonVote, MultiChoiceVote, Vote};
+use crate::settings::Settings;
 use bigdecimal::{BigDecimal, One};
 use candid::{Nat, Principal};
 use shared::mvc::{HasRepository, Repository};
@@ -27,8 +28,15 @@ impl NestedVotingService {
         caller: Principal,
         vote: MultiChoiceVote,
     ) -> Result<(), NestedVotingError> {
-        // checking inputs
-        if !vote.shares_info.is_signature_valid() {
+        // checking inputs - the shares info should be taken from the history ledger with a query
+        // call, otherwise it has no certificate
+        let settings = Settings::get();
+        let history_ledger =
+            settings.get_most_actual_by_history_ledger(&vote.shares_info.timestamp);
+        if !vote
+            .shares_info
+            .is_signature_valid(history_ledger, &settings.get_ic_root_key())
+        {
             return Err(NestedVotingError::SharesInfoInvalidSignature);
         }
 
@@ -159,10 +167,11 @@ impl NestedVotingService {
         let resp = match voting.get_remote_voting_id() {
             RemoteVotingId::Common(voting_id) => union_id.cast_my_vote(CastMyVoteRequest {
                 id: voting_id,
-                vote: Vote::Common(MultiChoiceVote {
-                    shares_info: voting.get_shares_info().clone(),
+                vote: Vote::Common(CommonVote {
+                    group_id: voting.get_shares_info().group_id,
                     vote: result_vote,
                 }),
+                rationale: None,
             }),
             RemoteVotingId::Nested(voting_id) => {
                 union_id.cast_my_nested_vote(CastMyNestedVoteRequest {
@@ -1,6 +1,8 @@
 use crate::repository::access_config::model::AccessConfig;
 use crate::repository::permission::model::Permission;
-use crate::repository::permission::types::{PermissionFilter, PermissionId, PermissionTarget};
+use crate::repository::permission::types::{
+    CyclesLimits, PermissionFilter, PermissionId, PermissionTarget,
+};
 use crate::repository::voting_config::model::VotingConfig;
 use crate::service::permission::types::{PermissionError, PermissionService};
 use shared::mvc::{HasRepository, Repository};
@@ -13,8 +15,9 @@ impl PermissionService {
         name: String,
         description: String,
         targets: Vec<PermissionTarget>,
+        cycles_limits: CyclesLimits,
     ) -> Result<PermissionId, PermissionError> {
-        let permission = Permission::new(name, description, targets)
+        let permission = Permission::new(name, description, targets, cycles_limits)
             .map_err(PermissionError::ValidationError)?;
 
         Ok(Permission::repo().save(permission))
@@ -25,6 +28,7 @@ impl PermissionService {
         new_name: Option<String>,
         new_description: Option<String>,
         new_targets: Option<BTreeSet<PermissionTarget>>,
+        new_cycles_limits: Option<CyclesLimits>,
     ) -> Result<(), PermissionError> {
         PermissionService::assert_not_default(*id)?;
         let mut permission = PermissionService::get_permission(id)?;
@@ -32,7 +36,7 @@ impl PermissionService {
         // WARNING! it is possible to change the permission while related votings are in-progress
 
         permission
-            .update(new_name, new_description, new_targets)
+            .update(new_name, new_description, new_targets, new_cycles_limits)
             .map_err(PermissionError::ValidationError)?;
 
         Permission::repo().save(permission);
@@ -0,0 +1,85 @@
+use candid::parser::types::{Binding, FuncMode, IDLProg, IDLType};
+use candid::Principal;
+use ic_cdk::trap;
+use shared::remote_call::{CandidInterface, RemoteCallEndpoint};
+
+// the interface this canister was built with, so endpoint sets can't drift from it
+const WALLET_INTERFACE: &str = include_str!("../../../can.did");
+
+#[derive(Debug, Clone)]
+pub struct WalletMethod {
+    pub name: String,
+    pub is_query: bool,
+}
+
+impl WalletMethod {
+    // acts on behalf of its caller
+    pub fn is_personal(&self) -> bool {
+        self.name.contains("_my_")
+    }
+
+    // some read methods are updates, because they call to other canisters
+    pub fn is_read_only(&self) -> bool {
+        self.is_query || self.name.starts_with("get_") || self.name.starts_with("list_")
+    }
+
+    pub fn is_voting_related(&self) -> bool {
+        self.name.contains("voting") || self.name.contains("vote")
+    }
+
+    // changes how votings work, not just takes part in them
+    pub fn is_voting_admin(&self) -> bool {
+        self.is_voting_related()
+            && !self.is_read_only()
+            && (self.name.contains("config") || self.name.starts_with("moderate_"))
+    }
+
+    pub fn to_endpoint(&self, this_canister_id: Principal) -> RemoteCallEndpoint {
+        RemoteCallEndpoint::new(this_canister_id, &self.name)
+    }
+}
+
+// programs calling to this canister are checked against it without any remote calls
+pub fn get_wallet_candid_interface() -> CandidInterface {
+    CandidInterface::parse(WALLET_INTERFACE)
+        .unwrap_or_else(|e| trap(format!("Unable to parse the wallet interface: {}", e.0).as_str()))
+}
+
+pub fn get_wallet_methods() -> Vec<WalletMethod> {
+    let prog: IDLProg = WALLET_INTERFACE
+        .parse()
+        .unwrap_or_else(|e| trap(format!("Unable to parse the wallet interface: {}", e).as_str()));
+
+    let service = match prog.actor {
+        Some(IDLType::ClassT(_, service)) => *service,
+        Some(service) => service,
+        None => trap("The wallet interface has no service"),
+    };
+
+    let bindings = match service {
+        IDLType::ServT(bindings) => bindings,
+        _ => trap("The wallet interface service should be defined inline"),
+    };
+
+    bindings
+        .into_iter()
+        .filter_map(|Binding { id, typ }| match typ {
+            IDLType::FuncT(func) => Some(WalletMethod {
+                name: id,
+                is_query: func.modes.contains(&FuncMode::Query),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn get_wallet_endpoints<F: Fn(&WalletMethod) -> bool>(
+    this_canister_id: Principal,
+    filter: F,
+) -> Vec<RemoteCallEndpoint> {
+    get_wallet_methods()
+        .iter()
+        .filter(|it| filter(it))
+        .map(|it| it.to_endpoint(this_canister_id))
+        .collect()
+}
@@ -1,12 +1,17 @@
-use crate::repository::permission::types::{PermissionId, PermissionTarget};
+use crate::repository::permission::model::Permission;
+use crate::repository::permission::types::{
+    CyclesLimits, PermissionId, PermissionTarget, PermissionTemplate,
+};
+use crate::service::permission::interface::{get_wallet_endpoints, WalletMethod};
 use crate::service::permission::types::{
-    PermissionError, PermissionService, _get_all_read_endpoints, _get_all_write_endpoints,
-    _get_voting_endpoints, ALLOW_READ_PERMISSION_ID, ALLOW_SEND_FEEDBACK_PERMISSION_ID,
-    ALLOW_VOTE_PERMISSION_ID, ALLOW_WRITE_PERMISSION_ID,
+    PermissionError, PermissionService, PermissionTemplateInfo, ALLOW_READ_PERMISSION_ID,
+    ALLOW_SEND_FEEDBACK_PERMISSION_ID, ALLOW_VOTE_PERMISSION_ID, ALLOW_WRITE_PERMISSION_ID,
 };
 use candid::Principal;
+use shared::mvc::{HasRepository, Repository};
 
 pub mod crud;
+pub mod interface;
 pub mod types;
 
 impl PermissionService {
@@ -14,9 +19,10 @@ impl PermissionService {
         let allow_write_permission_id = PermissionService::create_permission(
             String::from("Allow write all"),
             String::from(
-                "Non-deletable default permission. Allows update calls to ANY non-personal method of this union.",
+                "Non-deletable default permission. Allows update calls to ANY method of this union.",
             ),
-            _get_all_write_endpoints(this_canister_id).into_iter().map(PermissionTarget::Endpoint).collect(),
+            PermissionService::get_default_targets(&ALLOW_WRITE_PERMISSION_ID, this_canister_id),
+            CyclesLimits::default(),
         )
         .unwrap();
 
@@ -25,11 +31,12 @@ impl PermissionService {
         let allow_read_permission_id = PermissionService::create_permission(
             String::from("Allow read all"),
             String::from(
-                "Non-deletable default permission. Allows query calls to ANY non-personal method of this union.",
+                "Non-deletable default permission. Allows query calls to ANY method of this union.",
             ),
-            _get_all_read_endpoints(this_canister_id).into_iter().map(PermissionTarget::Endpoint).collect(),
+            PermissionService::get_default_targets(&ALLOW_READ_PERMISSION_ID, this_canister_id),
+            CyclesLimits::default(),
         )
-            .unwrap();
+        .unwrap();
 
         assert_eq!(allow_read_permission_id, ALLOW_READ_PERMISSION_ID);
 
@@ -37,6 +44,7 @@ impl PermissionService {
             String::from("Feedback"),
             String::from("Non-deletable default permission. Allows execution of empty programs."),
             vec![PermissionTarget::SelfEmptyProgram],
+            CyclesLimits::default(),
         )
         .unwrap();
 
@@ -47,16 +55,66 @@ impl PermissionService {
             String::from(
                 "Non-deletable default permission. Allows to call voting-related methods.",
             ),
-            _get_voting_endpoints(this_canister_id)
-                .into_iter()
-                .map(PermissionTarget::Endpoint)
-                .collect(),
+            PermissionService::get_default_targets(&ALLOW_VOTE_PERMISSION_ID, this_canister_id),
+            CyclesLimits::default(),
         )
         .unwrap();
 
         assert_eq!(voting_participation_permission_id, ALLOW_VOTE_PERMISSION_ID);
     }
 
+    pub fn create_permission_from_template(
+        template: PermissionTemplate,
+        this_canister_id: Principal,
+    ) -> Result<PermissionId, PermissionError> {
+        if let Some(id) = Permission::repo().get_template_permission(&template) {
+            return Err(PermissionError::TemplatePermissionAlreadyExists(id));
+        }
+
+        let id = PermissionService::create_permission(
+            String::from(template.get_name()),
+            String::from(template.get_description()),
+            PermissionService::get_template_targets(&template, this_canister_id),
+            CyclesLimits::default(),
+        )?;
+
+        Permission::repo().set_template_permission(template, id);
+
+        Ok(id)
+    }
+
+    pub fn list_permission_templates(this_canister_id: Principal) -> Vec<PermissionTemplateInfo> {
+        PermissionTemplate::ALL
+            .iter()
+            .map(|template| PermissionTemplateInfo {
+                template: *template,
+                name: String::from(template.get_name()),
+                description: String::from(template.get_description()),
+                targets: PermissionService::get_template_targets(template, this_canister_id),
+                permission_id: Permission::repo().get_template_permission(template),
+            })
+            .collect()
+    }
+
+    // the wallet interface may change with an upgrade, so endpoints are generated anew
+    pub fn refresh_generated_permissions(this_canister_id: Principal) {
+        for id in [
+            ALLOW_WRITE_PERMISSION_ID,
+            ALLOW_READ_PERMISSION_ID,
+            ALLOW_VOTE_PERMISSION_ID,
+        ] {
+            let targets = PermissionService::get_default_targets(&id, this_canister_id);
+            PermissionService::refresh_permission_targets(&id, targets);
+        }
+
+        for template in PermissionTemplate::ALL {
+            if let Some(id) = Permission::repo().get_template_permission(&template) {
+                let targets = PermissionService::get_template_targets(&template, this_canister_id);
+                PermissionService::refresh_permission_targets(&id, targets);
+            }
+        }
+    }
+
     pub fn assert_not_default(id: PermissionId) -> Result<(), PermissionError> {
         if id == ALLOW_WRITE_PERMISSION_ID
             || id == ALLOW_READ_PERMISSION_ID
@@ -68,4 +126,50 @@ impl PermissionService {
             Ok(())
         }
     }
+
+    fn refresh_permission_targets(id: &PermissionId, targets: Vec<PermissionTarget>) {
+        if let Some(mut permission) = Permission::repo().get(id) {
+            permission
+                .update(None, None, Some(targets.into_iter().collect()), None)
+                .expect("Unable to refresh permission targets");
+
+            Permission::repo().save(permission);
+        }
+    }
+
+    fn get_default_targets(
+        id: &PermissionId,
+        this_canister_id: Principal,
+    ) -> Vec<PermissionTarget> {
+        let filter: fn(&WalletMethod) -> bool = match *id {
+            ALLOW_WRITE_PERMISSION_ID => |it| !it.is_read_only(),
+            ALLOW_READ_PERMISSION_ID => |it| it.is_read_only(),
+            ALLOW_VOTE_PERMISSION_ID => |it| it.is_voting_related() && !it.is_voting_admin(),
+            _ => unreachable!("Permission {} has no generated targets", id),
+        };
+
+        get_wallet_endpoints(this_canister_id, filter)
+            .into_iter()
+            .map(PermissionTarget::Endpoint)
+            .collect()
+    }
+
+    fn get_template_targets(
+        template: &PermissionTemplate,
+        this_canister_id: Principal,
+    ) -> Vec<PermissionTarget> {
+        let filter: fn(&WalletMethod) -> bool = match template {
+            PermissionTemplate::ReadOnly => |it| it.is_read_only(),
+            PermissionTemplate::GroupAdmin => |it| it.name.contains("group"),
+            PermissionTemplate::VotingAdmin => |it| it.is_voting_related(),
+            PermissionTemplate::StreamingAdmin => {
+                |it| it.name.contains("batch") || it.name.contains("chunk")
+            }
+        };
+
+        get_wallet_endpoints(this_canister_id, |it| !it.is_personal() && filter(it))
+            .into_iter()
+            .map(PermissionTarget::Endpoint)
+            .collect()
+    }
 }
@@ -1,7 +1,7 @@
 use crate::repository::access_config::model::AccessConfig;
 use crate::repository::profile::model::Profile;
 use crate::repository::token::model::Token;
-use crate::service::group::types::{GroupService, DEFAULT_GROUP_SHARES};
+use crate::service::group::types::{GroupService, DEFAULT_GROUP_SHARES, HAS_PROFILE_GROUP_ID};
 use crate::service::profile::types::{ProfileError, ProfileService};
 use crate::EventsService;
 use shared::mvc::{HasRepository, Model, Repository};
@@ -50,7 +50,7 @@ impl ProfileService {
         Ok(())
     }
 
-    pub fn delete_profile(id: ProfileId) -> Result<(), ProfileError> {
+    pub fn delete_profile(id: ProfileId, timestamp: u64) -> Result<(), ProfileError> {
         if AccessConfig::repo().profile_has_related_access_configs(&id) {
             return Err(ProfileError::RelatedAccessConfigsExist);
         }
@@ -66,6 +66,8 @@ impl ProfileService {
         Token::repo().remove_from_principal_index(&id, &token.get_id().unwrap());
         Token::repo().save(token);
 
+        GroupService::recompute_dependent_groups(HAS_PROFILE_GROUP_ID, &[id], timestamp);
+
         Ok(())
     }
 
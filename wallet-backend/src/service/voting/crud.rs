@@ -1,14 +1,21 @@
+use crate::controller::voting::api::CreateVotingRequest;
 use crate::repository::choice::model::Choice;
 use crate::repository::voting::model::Voting;
-use crate::repository::voting::types::VotingSort;
+use crate::repository::voting::types::{VotingFilter, VotingSort};
 use crate::repository::voting_config::model::VotingConfig;
 use crate::service::choice::types::ChoiceService;
+use crate::service::comment::types::CommentService;
+use crate::service::group::types::GroupService;
 use crate::service::voting::types::{VotingError, VotingService};
 use crate::{cron_dequeue, CronService};
-use candid::Principal;
+use candid::{decode_args, Principal};
+use ic_cdk::api::{id, time};
 use shared::mvc::{HasRepository, Model, Repository};
 use shared::pageable::{Page, PageRequest};
-use shared::types::wallet::{VotingConfigId, VotingId};
+use shared::remote_call::Program;
+use shared::types::wallet::{GroupId, Shares, VotingConfigId, VotingId};
+use shared::validation::ValidationError;
+use std::collections::{BTreeMap, BTreeSet};
 
 impl VotingService {
     pub fn create_voting(
@@ -24,8 +31,9 @@ impl VotingService {
             .ok_or(VotingError::VotingConfigNotFound(voting_config_id))?;
 
         VotingService::assert_winners_need_is_fine(&vc, winners_need)?;
+        VotingService::assert_proposer_limits_not_exceeded(&vc, &proposer, timestamp)?;
 
-        let voting = Voting::new(
+        let mut voting = Voting::new(
             voting_config_id,
             name,
             description,
@@ -35,18 +43,107 @@ impl VotingService {
         )
         .map_err(VotingError::ValidationError)?;
 
+        let (group_trees, total_voting_power_by_group) =
+            VotingService::snapshot_group_trees(&vc, timestamp)?;
+        voting.init_group_trees(group_trees, total_voting_power_by_group);
+
+        if let Some(bond) = vc.get_proposal_bond() {
+            GroupService::lock_shares(bond.group_id, proposer, bond.quantity.clone())
+                .map_err(VotingError::UnableToLockProposalBond)?;
+
+            voting.init_proposal_bond(bond.clone());
+        }
+
         let id = Voting::repo().save(voting);
         let mut voting = Voting::repo().get(&id).unwrap();
 
-        let (rejection_choice, approval_choice) =
-            ChoiceService::create_rejection_and_approval_choices(id);
-        voting.init_rejection_and_approval_choices(rejection_choice, approval_choice);
+        let (rejection_choice, approval_choice, abstain_choice_opt) =
+            ChoiceService::create_rejection_and_approval_choices(id, vc.is_abstain_allowed());
+        voting.init_rejection_and_approval_choices(
+            rejection_choice,
+            approval_choice,
+            abstain_choice_opt,
+        );
 
         CronService::schedule_round_end(&mut voting, &vc, timestamp);
 
         Ok(Voting::repo().save(voting))
     }
 
+    // group trees are resolved once, so moving groups around mid-voting doesn't change the
+    // electorate; the voting is created right now, so the current total voting power of each
+    // group is the same the history ledger would return for the creation timestamp
+    fn snapshot_group_trees(
+        vc: &VotingConfig,
+        timestamp: u64,
+    ) -> Result<
+        (
+            BTreeMap<GroupId, BTreeSet<GroupId>>,
+            BTreeMap<GroupId, Shares>,
+        ),
+        VotingError,
+    > {
+        let roots = [
+            vc.get_approval_threshold(),
+            vc.get_rejection_threshold(),
+            vc.get_quorum_threshold(),
+            vc.get_win_threshold(),
+            vc.get_next_round_threshold(),
+        ]
+        .iter()
+        .flat_map(|it| it.list_group_trees())
+        .collect::<BTreeSet<_>>();
+
+        let mut group_trees = BTreeMap::new();
+        let mut total_voting_power_by_group = BTreeMap::new();
+
+        for root in roots {
+            let mut tree = GroupService::get_group_descendants(&root);
+            tree.insert(root);
+
+            for group_id in &tree {
+                let total = GroupService::get_total_group_voting_power(*group_id, timestamp)
+                    .map_err(VotingError::UnableToSnapshotGroupTree)?;
+
+                total_voting_power_by_group.insert(*group_id, total);
+            }
+
+            group_trees.insert(root, tree);
+        }
+
+        Ok((group_trees, total_voting_power_by_group))
+    }
+
+    // a program can only create votings on behalf of whoever executes it, votings created
+    // without an explicit proposer are proposed by the union itself
+    pub fn assert_program_proposer_valid(
+        program: &Program,
+        proposer: Principal,
+    ) -> Result<(), ValidationError> {
+        let seq = match program {
+            Program::Empty => return Ok(()),
+            Program::RemoteCallSequence(seq) => seq,
+        };
+
+        for call in seq {
+            if call.endpoint.canister_id != id() || call.endpoint.method_name != "create_voting" {
+                continue;
+            }
+
+            let (req,): (CreateVotingRequest,) = decode_args(&call.args.serialize_args()?)
+                .map_err(|e| ValidationError(format!("Invalid create_voting arguments: {}", e)))?;
+
+            if req.proposer.unwrap_or_else(id) != proposer {
+                return Err(ValidationError(format!(
+                    "Votings can only be proposed by {}",
+                    proposer
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn update_voting(
         id: &VotingId,
         new_name: Option<String>,
@@ -74,7 +171,10 @@ impl VotingService {
     }
 
     pub fn delete_voting(id: &VotingId) -> Result<(), VotingError> {
-        let voting = Voting::repo().delete(id).unwrap();
+        let mut voting = Voting::repo().delete(id).unwrap();
+
+        // a deleted voting is never resolved, so the proposer gets the bond back
+        VotingService::refund_proposal_bond(&mut voting, time());
 
         if let Some(task_id) = voting.get_cron_task() {
             cron_dequeue(task_id);
@@ -88,6 +188,10 @@ impl VotingService {
             .delete(&voting.get_approval_choice())
             .unwrap();
 
+        if let Some(abstain_choice) = voting.get_abstain_choice() {
+            Choice::repo().delete(&abstain_choice).unwrap();
+        }
+
         for result in voting.get_losers() {
             for choice in result.get_choices() {
                 Choice::repo().delete(choice).unwrap();
@@ -104,6 +208,8 @@ impl VotingService {
             Choice::repo().delete(choice).unwrap();
         }
 
+        CommentService::delete_comments_of_voting(id);
+
         Ok(())
     }
 
@@ -115,7 +221,15 @@ impl VotingService {
     }
 
     #[inline(always)]
-    pub fn list_votings(page_req: &PageRequest<(), VotingSort>) -> Page<Voting> {
+    pub fn list_votings_by_proposer(
+        proposer: &Principal,
+        page_req: &PageRequest<(), ()>,
+    ) -> Page<Voting> {
+        Voting::repo().list_by_proposer(proposer, page_req)
+    }
+
+    #[inline(always)]
+    pub fn list_votings(page_req: &PageRequest<VotingFilter, VotingSort>) -> Page<Voting> {
         Voting::repo().list(page_req)
     }
 }
@@ -1,15 +1,18 @@
 use crate::repository::choice::model::Choice;
 use crate::repository::token::model::Token;
 use crate::repository::voting::model::Voting;
-use crate::repository::voting::types::{RoundResult, VotingStatus};
+use crate::repository::voting::types::{ProposalBondStatus, RoundResult, VotingStatus};
 use crate::repository::voting_config::model::VotingConfig;
-use crate::repository::voting_config::types::Fraction;
+use crate::repository::voting_config::types::{Fraction, RejectedBondAction};
 use crate::service::choice::types::ChoiceService;
 use crate::service::cron::CronService;
+use crate::service::group::types::GroupService;
+use crate::service::history_ledger::HistoryLedgerService;
 use crate::service::voting::types::{Vote, VotingError, VotingService};
 use crate::service::voting_config::types::VotingConfigService;
 use bigdecimal::{BigDecimal, One};
 use candid::{Nat, Principal};
+use ic_cdk::api::{id, print};
 use shared::mvc::{HasRepository, Model, Repository};
 use shared::types::history_ledger::SharesInfo;
 use shared::types::wallet::{ChoiceId, GroupId, Shares, VotingId};
@@ -19,9 +22,38 @@ pub mod crud;
 pub mod types;
 
 impl VotingService {
-    pub fn cast_vote(
+    pub async fn cast_vote(
         id: &VotingId,
         vote: Vote,
+        rationale_opt: Option<String>,
+        caller: Principal,
+        timestamp: u64,
+    ) -> Result<(), VotingError> {
+        let voting = VotingService::get_voting(id)?;
+
+        if !matches!(voting.get_status(), VotingStatus::Round(_)) {
+            return Err(VotingError::VotingOnlyAllowedDuringRounds);
+        }
+
+        let shares_info =
+            VotingService::resolve_shares_info(&voting, vote.get_group_id(), caller).await?;
+
+        VotingService::cast_vote_with_shares_info(
+            id,
+            vote,
+            shares_info,
+            rationale_opt,
+            caller,
+            timestamp,
+        )
+    }
+
+    // the voting is re-fetched, since it could change while the shares info was resolving
+    fn cast_vote_with_shares_info(
+        id: &VotingId,
+        vote: Vote,
+        shares_info: SharesInfo,
+        rationale_opt: Option<String>,
         caller: Principal,
         timestamp: u64,
     ) -> Result<(), VotingError> {
@@ -32,25 +64,54 @@ impl VotingService {
             return Err(VotingError::VotingOnlyAllowedDuringRounds);
         }
 
-        let (choices, shares_info) = match vote {
+        VotingService::assert_shares_info_valid(&shares_info, &voting, caller)?;
+
+        // vote-escrowed shares are boosted the same way the ledger snapshot is taken - at the
+        // moment the voting was created
+        let balance = shares_info.balance.clone()
+            + GroupService::get_voting_power_boost_at(
+                shares_info.group_id,
+                Some(&shares_info.principal_id),
+                voting.get_created_at(),
+            );
+        let total_voting_power = shares_info.total_supply.clone()
+            + GroupService::get_voting_power_boost_at(
+                shares_info.group_id,
+                None,
+                voting.get_created_at(),
+            );
+
+        let choices = match vote {
             Vote::Rejection(s) => {
-                VotingService::assert_can_reject(&vc, &s.shares_info.group_id)?;
+                VotingService::assert_can_reject(&vc, &voting, &s.group_id)?;
 
-                let balance = s.shares_info.balance.clone();
                 let rejection_choice = Choice::repo().get(&voting.get_rejection_choice()).unwrap();
 
-                (vec![(rejection_choice, balance)], s.shares_info)
+                vec![(rejection_choice, balance)]
             }
             Vote::Approval(s) => {
-                VotingService::assert_can_approve(&vc, &s.shares_info.group_id)?;
+                VotingService::assert_can_approve(&vc, &voting, &s.group_id)?;
 
-                let balance = s.shares_info.balance.clone();
                 let approval_choice = Choice::repo().get(&voting.get_approval_choice()).unwrap();
 
-                (vec![(approval_choice, balance)], s.shares_info)
+                vec![(approval_choice, balance)]
+            }
+            Vote::Abstain(s) => {
+                VotingService::assert_can_vote(&vc, &voting, &s.group_id)?;
+
+                // abstaining only makes sense when the quorum is checked, which is not the case for round 0
+                let abstain_choice_id = match voting.get_status() {
+                    VotingStatus::Round(r) if *r > 0 => voting.get_abstain_choice(),
+                    _ => None,
+                }
+                .ok_or(VotingError::AbstainNotAllowed)?;
+
+                let abstain_choice = Choice::repo().get(&abstain_choice_id).unwrap();
+
+                vec![(abstain_choice, balance)]
             }
             Vote::Common(m) => {
-                VotingService::assert_can_vote(&vc, &m.shares_info.group_id)?;
+                VotingService::assert_can_vote(&vc, &voting, &m.group_id)?;
 
                 let total_fraction: BigDecimal = m.vote.iter().map(|(_, f)| f.0.abs()).sum();
 
@@ -62,14 +123,13 @@ impl VotingService {
                     .vote
                     .into_iter()
                     .map(|(id, f)| {
-                        let shares: Nat = (Fraction(f.0.abs())
-                            * Fraction::from(m.shares_info.balance.clone()))
-                        .into();
+                        let shares: Nat =
+                            (Fraction(f.0.abs()) * Fraction::from(balance.clone())).into();
                         (id, shares)
                     })
                     .collect::<Vec<_>>();
 
-                let choices = votes
+                votes
                     .into_iter()
                     .map(|(id, shares)| {
                         assert!(voting.get_choices().contains(&id));
@@ -77,19 +137,20 @@ impl VotingService {
 
                         (choice, shares)
                     })
-                    .collect();
-
-                (choices, m.shares_info)
+                    .collect()
             }
         };
 
-        VotingService::assert_shares_info_valid(&shares_info, &voting, caller)?;
+        // a recast vote replaces the rationale of the previous one
+        voting
+            .set_vote_rationale(shares_info.group_id, caller, rationale_opt, timestamp)
+            .map_err(VotingError::ValidationError)?;
 
         VotingService::remove_prev_vote(&voting, shares_info.group_id, shares_info.principal_id);
         VotingService::put_vote(
             &mut voting,
             choices,
-            shares_info.total_supply,
+            total_voting_power,
             shares_info.group_id,
             shares_info.principal_id,
             timestamp,
@@ -100,6 +161,30 @@ impl VotingService {
         Ok(())
     }
 
+    // the voter's shares are resolved through the history ledger at the moment the voting was created
+    async fn resolve_shares_info(
+        voting: &Voting,
+        group_id: GroupId,
+        voter: Principal,
+    ) -> Result<SharesInfo, VotingError> {
+        if let Some(shares_info) = voting.get_cached_shares_info(&group_id, &voter) {
+            return Ok(shares_info.clone());
+        }
+
+        let voting_id = voting.get_id().unwrap();
+        let shares_info =
+            HistoryLedgerService::get_shares_info_of_at(group_id, voter, voting.get_created_at())
+                .await
+                .map_err(VotingError::HistoryLedgerError)?
+                .ok_or(VotingError::InsufficientSharesBalance)?;
+
+        let mut voting = VotingService::get_voting(&voting_id)?;
+        voting.cache_shares_info(shares_info.clone());
+        Voting::repo().save(voting);
+
+        Ok(shares_info)
+    }
+
     pub fn try_finish_voting(voting: &mut Voting, vc: &VotingConfig, timestamp: u64) {
         match voting.get_status() {
             VotingStatus::Round(r) => {
@@ -110,6 +195,7 @@ impl VotingService {
                 if vc.get_rejection_threshold().is_reached(
                     voting.get_total_voting_power_by_group(),
                     &rejection_votes_per_group,
+                    voting.get_group_trees(),
                 ) {
                     voting.reject(timestamp);
                     return;
@@ -132,6 +218,7 @@ impl VotingService {
                     if vc.get_approval_threshold().is_reached(
                         voting.get_total_voting_power_by_group(),
                         &approval_votes_per_group,
+                        voting.get_group_trees(),
                     ) {
                         voting.next_round(timestamp);
                         CronService::schedule_round_start(voting, vc, timestamp);
@@ -150,7 +237,12 @@ impl VotingService {
                     .map(|id| Choice::repo().get(id).unwrap())
                     .collect();
 
-                for choice in &choices {
+                // abstain votes count toward the quorum, but the abstain choice can never win
+                let abstain_choice_opt = voting
+                    .get_abstain_choice()
+                    .map(|id| Choice::repo().get(&id).unwrap());
+
+                for choice in choices.iter().chain(abstain_choice_opt.iter()) {
                     let common_votes_per_group =
                         ChoiceService::list_total_voted_shares_by_group(choice);
 
@@ -167,6 +259,7 @@ impl VotingService {
                 if vc.get_quorum_threshold().is_reached(
                     voting.get_total_voting_power_by_group(),
                     &total_used_votes_by_group,
+                    voting.get_group_trees(),
                 ) {
                     let mut win = vec![];
                     let mut next_round = vec![];
@@ -176,10 +269,11 @@ impl VotingService {
                         let votes_per_group =
                             ChoiceService::list_total_voted_shares_by_group(&choice);
 
-                        if vc
-                            .get_win_threshold()
-                            .is_reached(voting.get_total_voting_power_by_group(), &votes_per_group)
-                        {
+                        if vc.get_win_threshold().is_reached(
+                            voting.get_total_voting_power_by_group(),
+                            &votes_per_group,
+                            voting.get_group_trees(),
+                        ) {
                             win.push(choice.get_id().unwrap());
                             won = true;
                         }
@@ -188,6 +282,7 @@ impl VotingService {
                             && vc.get_next_round_threshold().is_reached(
                                 voting.get_total_voting_power_by_group(),
                                 &votes_per_group,
+                                voting.get_group_trees(),
                             )
                         {
                             next_round.push(choice.get_id().unwrap());
@@ -254,6 +349,63 @@ impl VotingService {
         }
     }
 
+    pub fn settle_proposal_bond(voting: &mut Voting, timestamp: u64) {
+        let locked_bond = match voting.get_proposal_bond() {
+            Some(it) if it.status == ProposalBondStatus::Locked => it.clone(),
+            _ => return,
+        };
+
+        let bond = locked_bond.bond;
+        let proposer = voting.get_proposer();
+
+        match voting.get_status() {
+            VotingStatus::Rejected => {
+                let (res, status) = match bond.on_rejection {
+                    RejectedBondAction::Burn => (
+                        GroupService::burn_locked_shares(
+                            bond.group_id,
+                            proposer,
+                            bond.quantity,
+                            timestamp,
+                        ),
+                        ProposalBondStatus::Burned,
+                    ),
+                    RejectedBondAction::SendToTreasury => (
+                        GroupService::transfer_locked_shares(
+                            bond.group_id,
+                            proposer,
+                            id(),
+                            bond.quantity,
+                            timestamp,
+                        ),
+                        ProposalBondStatus::SentToTreasury,
+                    ),
+                };
+
+                match res {
+                    Ok(_) => voting.set_proposal_bond_status(status, timestamp),
+                    Err(e) => print(format!("Unable to forfeit proposal bond: {:?}", e)),
+                }
+            }
+            VotingStatus::Success | VotingStatus::Fail(_) => {
+                VotingService::refund_proposal_bond(voting, timestamp)
+            }
+            _ => {}
+        }
+    }
+
+    pub fn refund_proposal_bond(voting: &mut Voting, timestamp: u64) {
+        let bond = match voting.get_proposal_bond() {
+            Some(it) if it.status == ProposalBondStatus::Locked => it.bond.clone(),
+            _ => return,
+        };
+
+        match GroupService::unlock_shares(bond.group_id, voting.get_proposer(), bond.quantity) {
+            Ok(_) => voting.set_proposal_bond_status(ProposalBondStatus::Refunded, timestamp),
+            Err(e) => print(format!("Unable to refund proposal bond: {:?}", e)),
+        }
+    }
+
     pub fn reset_approval_choice(voting: &Voting) {
         let approval_choice = Choice::repo().get(&voting.get_approval_choice()).unwrap();
         ChoiceService::reset(&approval_choice);
@@ -304,11 +456,23 @@ impl VotingService {
         Ok(result)
     }
 
+    pub fn get_vote_rationales(
+        voting_id: &VotingId,
+    ) -> Result<BTreeMap<GroupId, BTreeMap<Principal, String>>, VotingError> {
+        let voting = VotingService::get_voting(voting_id)?;
+
+        Ok(voting.get_vote_rationales().clone())
+    }
+
     fn get_voting_choices(voting: &Voting) -> Vec<Choice> {
         let mut choices = Vec::new();
         choices.push(Choice::repo().get(&voting.get_approval_choice()).unwrap());
         choices.push(Choice::repo().get(&voting.get_rejection_choice()).unwrap());
 
+        if let Some(id) = voting.get_abstain_choice() {
+            choices.push(Choice::repo().get(&id).unwrap());
+        }
+
         for id in voting.get_choices() {
             choices.push(Choice::repo().get(id).unwrap());
         }
@@ -345,6 +509,10 @@ impl VotingService {
 
                     list.push(ChoiceService::get_choice(&voting.get_rejection_choice()).unwrap());
 
+                    if let Some(id) = voting.get_abstain_choice() {
+                        list.push(ChoiceService::get_choice(&id).unwrap());
+                    }
+
                     list
                 }
             }
@@ -363,7 +531,7 @@ impl VotingService {
     fn put_vote(
         voting: &mut Voting,
         choices: Vec<(Choice, Shares)>,
-        total_supply: Shares,
+        total_voting_power: Shares,
         group_id: GroupId,
         principal: Principal,
         timestamp: u64,
@@ -376,7 +544,7 @@ impl VotingService {
             Choice::repo().save(choice);
         }
 
-        voting.update_total_voting_power_by_group(group_id, total_supply, timestamp);
+        voting.update_total_voting_power_by_group(group_id, total_voting_power, timestamp);
     }
 
     fn assert_winners_need_is_fine(
@@ -392,6 +560,56 @@ impl VotingService {
         Ok(())
     }
 
+    // the proposer is the program initiator, votings proposed by the union itself share its limits
+    fn assert_proposer_limits_not_exceeded(
+        vc: &VotingConfig,
+        proposer: &Principal,
+        timestamp: u64,
+    ) -> Result<(), VotingError> {
+        let limits = vc.get_proposer_limits();
+        if limits.max_active_votings.is_none() && limits.rate_limit.is_none() {
+            return Ok(());
+        }
+
+        let vc_id = vc.get_id().unwrap();
+
+        if let Some(max) = limits.max_active_votings {
+            let active = Voting::repo()
+                .get_votings_by_proposer(proposer)
+                .iter()
+                .filter(|it| it.get_voting_config_id() == &vc_id)
+                .filter(|it| {
+                    matches!(
+                        it.get_status(),
+                        VotingStatus::PreRound(_) | VotingStatus::Round(_)
+                    )
+                })
+                .count() as u32;
+
+            if active >= max {
+                return Err(VotingError::TooManyActiveVotings(max));
+            }
+        }
+
+        if let Some(rl) = &limits.rate_limit {
+            let window_start = timestamp.saturating_sub(rl.window);
+            let created = Voting::repo().count_votings_created_by_proposer_since(
+                *proposer,
+                vc_id,
+                window_start,
+            );
+
+            if created >= rl.max_votings {
+                return Err(VotingError::VotingCreationRateLimitExceeded(
+                    rl.max_votings,
+                    rl.window,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn is_editable(voting: &Voting) -> bool {
         match voting.get_status() {
             VotingStatus::Round(r) => *r == 0,
@@ -399,18 +617,30 @@ impl VotingService {
         }
     }
 
-    fn assert_can_approve(vc: &VotingConfig, group_id: &GroupId) -> Result<(), VotingError> {
-        if vc.get_approval_threshold().list_groups().contains(group_id) {
+    fn assert_can_approve(
+        vc: &VotingConfig,
+        voting: &Voting,
+        group_id: &GroupId,
+    ) -> Result<(), VotingError> {
+        if vc
+            .get_approval_threshold()
+            .list_voter_groups(voting.get_group_trees())
+            .contains(group_id)
+        {
             Ok(())
         } else {
             Err(VotingError::VoterCantApprove)
         }
     }
 
-    fn assert_can_reject(vc: &VotingConfig, group_id: &GroupId) -> Result<(), VotingError> {
+    fn assert_can_reject(
+        vc: &VotingConfig,
+        voting: &Voting,
+        group_id: &GroupId,
+    ) -> Result<(), VotingError> {
         if vc
             .get_rejection_threshold()
-            .list_groups()
+            .list_voter_groups(voting.get_group_trees())
             .contains(group_id)
         {
             Ok(())
@@ -419,10 +649,16 @@ impl VotingService {
         }
     }
 
-    fn assert_can_vote(vc: &VotingConfig, group_id: &GroupId) -> Result<(), VotingError> {
-        let mut list = vc.get_quorum_threshold().list_groups();
-        list.extend(vc.get_win_threshold().list_groups());
-        list.extend(vc.get_next_round_threshold().list_groups());
+    fn assert_can_vote(
+        vc: &VotingConfig,
+        voting: &Voting,
+        group_id: &GroupId,
+    ) -> Result<(), VotingError> {
+        let group_trees = voting.get_group_trees();
+
+        let mut list = vc.get_quorum_threshold().list_voter_groups(group_trees);
+        list.extend(vc.get_win_threshold().list_voter_groups(group_trees));
+        list.extend(vc.get_next_round_threshold().list_voter_groups(group_trees));
 
         if list.contains(group_id) {
             Ok(())
@@ -436,10 +672,8 @@ impl VotingService {
         voting: &Voting,
         caller: Principal,
     ) -> Result<(), VotingError> {
-        if !shares_info.is_signature_valid() {
-            return Err(VotingError::SharesInfoSignatureInvalid);
-        }
-
+        // the shares info is fetched directly from this union's history ledger, so there
+        // is no certificate to check - inter-canister calls don't receive one
         if voting.get_created_at() != shares_info.timestamp {
             return Err(VotingError::SharesInfoTimestampInvalid);
         }
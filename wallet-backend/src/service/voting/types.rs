@@ -1,7 +1,9 @@
 use crate::repository::voting_config::types::{Fraction, LenInterval};
+use crate::service::group::types::GroupError;
+use crate::service::history_ledger::HistoryLedgerError;
 use candid::{CandidType, Deserialize};
 use shared::types::history_ledger::SharesInfo;
-use shared::types::wallet::{ChoiceId, ProfileId, VotingConfigId, VotingId};
+use shared::types::wallet::{ChoiceId, GroupId, ProfileId, VotingConfigId, VotingId};
 use shared::validation::ValidationError;
 use std::collections::BTreeMap;
 
@@ -23,17 +25,29 @@ pub enum VotingError {
     VoterCantApprove,
     VoterCantReject,
     VoterCantVote,
-    SharesInfoSignatureInvalid,
+    AbstainNotAllowed,
     SharesInfoTimestampInvalid,
     InsufficientSharesBalance,
     SharesInfoDoesntBelongToVoter,
+    HistoryLedgerError(HistoryLedgerError),
+    UnableToLockProposalBond(GroupError),
+    UnableToSnapshotGroupTree(GroupError),
+    TooManyActiveVotings(u32),
+    VotingCreationRateLimitExceeded(u32, u64),
 }
 
 #[derive(CandidType, Deserialize)]
 pub struct SingleChoiceVote {
-    pub shares_info: SharesInfo,
+    pub group_id: GroupId,
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct CommonVote {
+    pub group_id: GroupId,
+    pub vote: BTreeMap<ChoiceId, Fraction>,
+}
+
+// used by nested votings, where the shares info is supplied by the voter
 #[derive(CandidType, Deserialize)]
 pub struct MultiChoiceVote {
     pub shares_info: SharesInfo,
@@ -44,5 +58,15 @@ pub struct MultiChoiceVote {
 pub enum Vote {
     Rejection(SingleChoiceVote),
     Approval(SingleChoiceVote),
-    Common(MultiChoiceVote),
+    Abstain(SingleChoiceVote),
+    Common(CommonVote),
+}
+
+impl Vote {
+    pub fn get_group_id(&self) -> GroupId {
+        match self {
+            Vote::Rejection(s) | Vote::Approval(s) | Vote::Abstain(s) => s.group_id,
+            Vote::Common(m) => m.group_id,
+        }
+    }
 }
@@ -2,7 +2,7 @@ use crate::repository::permission::types::PermissionId;
 use crate::repository::voting::model::Voting;
 use crate::repository::voting_config::model::VotingConfig;
 use crate::repository::voting_config::types::{
-    LenInterval, RoundSettings, ThresholdValue, VotingConfigFilter,
+    LenInterval, ProposalBond, ProposerLimits, RoundSettings, ThresholdValue, VotingConfigFilter,
 };
 use crate::service::voting_config::types::{VotingConfigError, VotingConfigService};
 use shared::mvc::{HasRepository, Repository};
@@ -23,6 +23,9 @@ impl VotingConfigService {
         rejection: ThresholdValue,
         win: ThresholdValue,
         next_round: ThresholdValue,
+        allow_abstain: bool,
+        proposal_bond: Option<ProposalBond>,
+        proposer_limits: ProposerLimits,
     ) -> Result<VotingConfigId, VotingConfigError> {
         VotingConfigService::assert_permissions_exist(&permissions)?;
 
@@ -46,6 +49,10 @@ impl VotingConfigService {
             VotingConfigService::assert_group_exists(&group)?;
         }
 
+        if let Some(bond) = &proposal_bond {
+            VotingConfigService::assert_proposal_bond_valid(bond)?;
+        }
+
         let vc = VotingConfig::new(
             name,
             description,
@@ -58,6 +65,9 @@ impl VotingConfigService {
             rejection,
             win,
             next_round,
+            allow_abstain,
+            proposal_bond,
+            proposer_limits,
         )
         .map_err(VotingConfigError::ValidationError)?;
 
@@ -77,6 +87,9 @@ impl VotingConfigService {
         rejection_opt: Option<ThresholdValue>,
         win_opt: Option<ThresholdValue>,
         next_round_opt: Option<ThresholdValue>,
+        allow_abstain_opt: Option<bool>,
+        proposal_bond_opt: Option<Option<ProposalBond>>,
+        proposer_limits_opt: Option<ProposerLimits>,
     ) -> Result<(), VotingConfigError> {
         VotingConfigService::assert_not_default(id)?;
 
@@ -114,6 +127,10 @@ impl VotingConfigService {
             }
         }
 
+        if let Some(Some(bond)) = &proposal_bond_opt {
+            VotingConfigService::assert_proposal_bond_valid(bond)?;
+        }
+
         let mut vc = VotingConfigService::get_voting_config(&id)?;
 
         vc.update(
@@ -128,6 +145,9 @@ impl VotingConfigService {
             rejection_opt,
             win_opt,
             next_round_opt,
+            allow_abstain_opt,
+            proposal_bond_opt,
+            proposer_limits_opt,
         )
         .map_err(VotingConfigError::ValidationError)?;
 
@@ -3,7 +3,8 @@ use crate::repository::permission::model::Permission;
 use crate::repository::permission::types::PermissionId;
 use crate::repository::voting_config::model::VotingConfig;
 use crate::repository::voting_config::types::{
-    Fraction, FractionOf, QuantityOf, RoundSettings, Target, ThresholdValue,
+    Fraction, FractionOf, ProposalBond, ProposerLimits, QuantityOf, RoundSettings, Target,
+    ThresholdValue,
 };
 use crate::service::group::types::HAS_PROFILE_GROUP_ID;
 use crate::service::permission::types::{
@@ -35,6 +36,9 @@ impl VotingConfigService {
             ThresholdValue::QuantityOf(QuantityOf { quantity: Shares::from(1), target: Target::Group(HAS_PROFILE_GROUP_ID) }),
             ThresholdValue::FractionOf(FractionOf { fraction: Fraction::from(1), target: Target::Group(HAS_PROFILE_GROUP_ID) }),
             ThresholdValue::FractionOf(FractionOf { fraction: Fraction::from(1), target: Target::Group(HAS_PROFILE_GROUP_ID) }),
+            false,
+            None,
+            ProposerLimits::default(),
         ).unwrap();
 
         assert_eq!(emergency_voting_config_id, EMERGENCY_VOTING_CONFIG_ID);
@@ -73,6 +77,9 @@ impl VotingConfigService {
                 fraction: Fraction::from(0.2),
                 target: Target::Group(HAS_PROFILE_GROUP_ID),
             }),
+            true,
+            None,
+            ProposerLimits::default(),
         )
         .unwrap();
 
@@ -117,4 +124,12 @@ impl VotingConfigService {
             .map(|_| ())
             .ok_or(VotingConfigError::GroupDoesntExist(*group_id))
     }
+
+    fn assert_proposal_bond_valid(bond: &ProposalBond) -> Result<(), VotingConfigError> {
+        if bond.group_id == HAS_PROFILE_GROUP_ID {
+            return Err(VotingConfigError::InvalidProposalBondGroup(bond.group_id));
+        }
+
+        VotingConfigService::assert_group_exists(&bond.group_id)
+    }
 }
@@ -12,6 +12,7 @@ pub enum VotingConfigError {
     ValidationError(ValidationError),
     PermissionDoesntExist(PermissionId),
     GroupDoesntExist(GroupId),
+    InvalidProposalBondGroup(GroupId),
     UnableToEditDefaultVotingConfig,
     HasRelatedVotings,
     VotingConfigNotFound(VotingConfigId),
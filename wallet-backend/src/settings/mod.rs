@@ -1,12 +1,30 @@
 use candid::{CandidType, Deserialize, Principal};
+use shared::certification::{ic_root_key, is_root_key_valid};
 use shared::sorted_by_timestamp::SortedByTimestamp;
+use shared::types::Blob;
 use shared::validation::{validate_and_trim_str, ValidationError};
 
+// Max amount of cycles the union is able to attach to remote calls during each period
+#[derive(Clone, Copy, CandidType, Deserialize, Debug)]
+pub struct CyclesBudget {
+    pub amount: u64,
+    pub period: u64,
+}
+
+#[derive(Clone, Copy, CandidType, Deserialize, Default)]
+pub struct CyclesSpending {
+    pub period_start: u64,
+    pub spent: u64,
+}
+
 #[derive(Clone, CandidType, Deserialize)]
 pub struct Settings {
     history_ledgers: SortedByTimestamp<Principal>,
     name: String,
     description: String,
+    cycles_budget: Option<CyclesBudget>,
+    cycles_spending: CyclesSpending,
+    ic_root_key: Option<Blob>,
 }
 
 impl Settings {
@@ -14,12 +32,16 @@ impl Settings {
         history_ledger: Principal,
         name: String,
         description: String,
+        ic_root_key: Option<Blob>,
         timestamp: u64,
     ) -> Result<Self, ValidationError> {
         let mut it = Self {
             history_ledgers: SortedByTimestamp::default(),
             name: Self::process_name(name)?,
             description: Self::process_description(description)?,
+            cycles_budget: None,
+            cycles_spending: CyclesSpending::default(),
+            ic_root_key: Self::process_ic_root_key(ic_root_key)?,
         };
 
         it.add_history_ledger(history_ledger, timestamp);
@@ -31,6 +53,8 @@ impl Settings {
         &mut self,
         new_name: Option<String>,
         new_description: Option<String>,
+        new_cycles_budget: Option<Option<CyclesBudget>>,
+        new_ic_root_key: Option<Option<Blob>>,
     ) -> Result<(), ValidationError> {
         if let Some(name) = new_name {
             self.name = Self::process_name(name)?;
@@ -40,6 +64,14 @@ impl Settings {
             self.description = Self::process_description(description)?;
         }
 
+        if let Some(cycles_budget) = new_cycles_budget {
+            self.cycles_budget = Self::process_cycles_budget(cycles_budget)?;
+        }
+
+        if let Some(ic_root_key) = new_ic_root_key {
+            self.ic_root_key = Self::process_ic_root_key(ic_root_key)?;
+        }
+
         Ok(())
     }
 
@@ -61,10 +93,75 @@ impl Settings {
         self.history_ledgers.get_all()
     }
 
+    // certificates are checked against the mainnet root key, unless another one is set
+    pub fn get_ic_root_key(&self) -> Blob {
+        self.ic_root_key.clone().unwrap_or_else(ic_root_key)
+    }
+
+    pub fn get_cycles_budget(&self) -> Option<&CyclesBudget> {
+        self.cycles_budget.as_ref()
+    }
+
+    // cycles spent during the period the timestamp belongs to
+    pub fn get_cycles_spent(&self, timestamp: u64) -> u64 {
+        match &self.cycles_budget {
+            Some(budget) if self.cycles_spending.period_start == budget.period_start(timestamp) => {
+                self.cycles_spending.spent
+            }
+            _ => 0,
+        }
+    }
+
+    pub fn is_within_cycles_budget(&self, cycles: u64, timestamp: u64) -> bool {
+        match &self.cycles_budget {
+            Some(budget) => {
+                self.get_cycles_spent(timestamp).saturating_add(cycles) <= budget.amount
+            }
+            None => true,
+        }
+    }
+
+    pub fn spend_cycles(&mut self, cycles: u64, timestamp: u64) {
+        if let Some(budget) = &self.cycles_budget {
+            let spent = self.get_cycles_spent(timestamp);
+
+            self.cycles_spending = CyclesSpending {
+                period_start: budget.period_start(timestamp),
+                spent: spent.saturating_add(cycles),
+            };
+        }
+    }
+
     pub fn get() -> &'static mut Settings {
         get_settings()
     }
 
+    fn process_cycles_budget(
+        cycles_budget: Option<CyclesBudget>,
+    ) -> Result<Option<CyclesBudget>, ValidationError> {
+        if let Some(budget) = &cycles_budget {
+            if budget.period == 0 {
+                return Err(ValidationError(String::from(
+                    "Cycles budget period should be positive",
+                )));
+            }
+        }
+
+        Ok(cycles_budget)
+    }
+
+    fn process_ic_root_key(ic_root_key: Option<Blob>) -> Result<Option<Blob>, ValidationError> {
+        if let Some(key) = &ic_root_key {
+            if !is_root_key_valid(key) {
+                return Err(ValidationError(String::from(
+                    "IC root key should be a DER-encoded BLS public key",
+                )));
+            }
+        }
+
+        Ok(ic_root_key)
+    }
+
     fn process_name(name: String) -> Result<String, ValidationError> {
         validate_and_trim_str(name, 1, 200, "Union name")
     }
@@ -74,12 +171,24 @@ impl Settings {
     }
 }
 
+impl CyclesBudget {
+    pub fn period_start(&self, timestamp: u64) -> u64 {
+        timestamp - timestamp % self.period
+    }
+}
+
 static mut SETTINGS: Option<Settings> = None;
 
-pub fn init_settings(history_ledger: Principal, name: String, description: String, timestamp: u64) {
+pub fn init_settings(
+    history_ledger: Principal,
+    name: String,
+    description: String,
+    ic_root_key: Option<Blob>,
+    timestamp: u64,
+) {
     unsafe {
         SETTINGS = Some(
-            Settings::new(history_ledger, name, description, timestamp)
+            Settings::new(history_ledger, name, description, ic_root_key, timestamp)
                 .expect("Unable to init settings"),
         )
     }